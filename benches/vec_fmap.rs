@@ -0,0 +1,86 @@
+//! Counts the allocations made by `VecKind::fmap`, against mapping into a fresh `Vec` by hand.
+//!
+//! Run with `cargo bench --bench vec_fmap`.
+
+extern crate rust_hkt;
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::hint::black_box;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Instant;
+
+use rust_hkt::part3::{Functor, VecKind};
+
+struct Counting;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: Counting = Counting;
+
+const LEN: u32 = 100_000;
+const ITERATIONS: u32 = 200;
+
+/// Runs `map` over a fresh `Vec` `ITERATIONS` times, and reports the time and allocations taken
+/// by `map` alone.
+fn bench<B, F>(name: &str, mut map: F) -> usize
+where
+    F: FnMut(Vec<u32>) -> Vec<B>,
+{
+    let mut allocations = 0;
+    let mut nanos = 0;
+    for _ in 0..ITERATIONS {
+        let xs: Vec<u32> = (0..LEN).collect();
+        let before = ALLOCATIONS.load(Ordering::Relaxed);
+        let start = Instant::now();
+        let ys = black_box(map(black_box(xs)));
+        nanos += start.elapsed().as_nanos();
+        allocations += ALLOCATIONS.load(Ordering::Relaxed) - before;
+        drop(ys);
+    }
+    let per_iteration = allocations / ITERATIONS as usize;
+    println!(
+        "{:<32} {:>10} ns/iter {:>4} allocations/iter",
+        name,
+        nanos / u128::from(ITERATIONS),
+        per_iteration
+    );
+    per_iteration
+}
+
+fn main() {
+    let same_size = bench("fmap u32 -> i32", |xs| VecKind::fmap(xs, |x| x as i32 - 1));
+    let by_hand = bench("by hand u32 -> i32", |xs| {
+        let mut ys = Vec::with_capacity(xs.len());
+        for x in xs {
+            ys.push(x as i32 - 1);
+        }
+        ys
+    });
+    let larger = bench("fmap u32 -> u64", |xs| {
+        VecKind::fmap(xs, |x| u64::from(x) << 32)
+    });
+
+    assert_eq!(
+        same_size, 0,
+        "fmap between same-sized types should reuse the allocation"
+    );
+    assert_eq!(by_hand, 1);
+    assert_eq!(larger, 1);
+}
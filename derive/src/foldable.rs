@@ -0,0 +1,155 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{Result, Type};
+
+use shape::{Container, Shape};
+
+#[derive(Clone, Copy)]
+enum Direction {
+    Left,
+    Right,
+}
+
+pub fn derive(shape: &Shape) -> Result<TokenStream> {
+    let kind_type = shape.kind_type();
+    let params = &shape.params;
+    let where_clause = &shape.where_clause;
+    let fa = shape.applied(quote!(__A));
+
+    let left = arms(shape, Direction::Left)?;
+    let right = arms(shape, Direction::Right)?;
+
+    Ok(quote! {
+        impl<#(#params),*> #kind_type #where_clause {
+            fn __derive_fold_left<__A, __B, __F>(fa: #fa, __acc: __B, f: &mut __F) -> __B
+            where
+                __F: FnMut(__B, __A) -> __B,
+            {
+                match fa {
+                    #(#left,)*
+                }
+            }
+
+            fn __derive_fold_right<__A, __B, __F>(fa: #fa, __acc: __B, f: &mut __F) -> __B
+            where
+                __F: FnMut(__A, __B) -> __B,
+            {
+                match fa {
+                    #(#right,)*
+                }
+            }
+        }
+
+        impl<#(#params),*> ::rust_hkt::part3::Foldable for #kind_type #where_clause {
+            fn fold_left<__A, __B, __F>(fa: #fa, init: __B, mut f: __F) -> __B
+            where
+                __F: FnMut(__B, __A) -> __B,
+            {
+                Self::__derive_fold_left(fa, init, &mut f)
+            }
+
+            fn fold_right<__A, __B, __F>(fa: #fa, init: __B, mut f: __F) -> __B
+            where
+                __F: FnMut(__A, __B) -> __B,
+            {
+                Self::__derive_fold_right(fa, init, &mut f)
+            }
+        }
+    })
+}
+
+fn arms(shape: &Shape, direction: Direction) -> Result<Vec<TokenStream>> {
+    let mut arms = Vec::new();
+    for variant in &shape.variants {
+        let mut bindings = Vec::new();
+        let mut steps = Vec::new();
+        for (i, field) in variant.fields.iter().enumerate() {
+            let binding = &field.binding;
+            match fold(shape, &field.ty, quote!(#binding), i, direction)? {
+                Some(step) => {
+                    bindings.push(quote!(#binding));
+                    steps.push(quote!(let __acc = #step;));
+                }
+                None => bindings.push(quote!(_)),
+            }
+        }
+        if let Direction::Right = direction {
+            steps.reverse();
+        }
+        let pattern = variant.with(&bindings);
+        arms.push(quote!(#pattern => { #(#steps)* __acc }));
+    }
+    Ok(arms)
+}
+
+/// The expression which folds `expr` of type `ty` into `__acc`, or `None` if `ty` has nothing to
+/// fold.
+fn fold(
+    shape: &Shape,
+    ty: &Type,
+    expr: TokenStream,
+    depth: usize,
+    direction: Direction,
+) -> Result<Option<TokenStream>> {
+    if !shape.mentions_hole(ty) {
+        return Ok(None);
+    }
+    if shape.is_hole(ty) {
+        return Ok(Some(match direction {
+            Direction::Left => quote!(f(__acc, #expr)),
+            Direction::Right => quote!(f(#expr, __acc)),
+        }));
+    }
+    if shape.is_self(ty) {
+        return Ok(Some(match direction {
+            Direction::Left => quote!(Self::__derive_fold_left(#expr, __acc, &mut *f)),
+            Direction::Right => quote!(Self::__derive_fold_right(#expr, __acc, &mut *f)),
+        }));
+    }
+    let x = format_ident!("__x{}", depth);
+    if let Some((container, inner)) = shape.container(ty) {
+        let inner = match fold(shape, inner, quote!(#x), depth + 1, direction)? {
+            Some(inner) => inner,
+            None => return Ok(None),
+        };
+        return Ok(Some(match (container, direction) {
+            (Container::Box, _) => quote!({ let #x = *#expr; #inner }),
+            (Container::Option, _) => quote! {
+                match #expr {
+                    ::std::option::Option::Some(#x) => #inner,
+                    ::std::option::Option::None => __acc,
+                }
+            },
+            (Container::Vec, Direction::Left) => {
+                quote!(#expr.into_iter().fold(__acc, |__acc, #x| #inner))
+            }
+            (Container::Vec, Direction::Right) => {
+                quote!(#expr.into_iter().rev().fold(__acc, |__acc, #x| #inner))
+            }
+        }));
+    }
+    match *ty {
+        Type::Paren(ref paren) => fold(shape, &paren.elem, expr, depth, direction),
+        Type::Tuple(ref tuple) => {
+            let mut names = Vec::new();
+            let mut steps = Vec::new();
+            for (i, elem) in tuple.elems.iter().enumerate() {
+                let name = format_ident!("__x{}_{}", depth, i);
+                match fold(shape, elem, quote!(#name), depth + 1, direction)? {
+                    Some(step) => {
+                        names.push(quote!(#name));
+                        steps.push(quote!(let __acc = #step;));
+                    }
+                    None => names.push(quote!(_)),
+                }
+            }
+            if let Direction::Right = direction {
+                steps.reverse();
+            }
+            Ok(Some(
+                quote!({ let (#(#names,)*) = #expr; #(#steps)* __acc }),
+            ))
+        }
+        _ => Err(shape.unsupported(ty, "Foldable")),
+    }
+}
@@ -0,0 +1,113 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{Result, Type};
+
+use shape::{Container, Shape};
+
+pub fn derive(shape: &Shape) -> Result<TokenStream> {
+    let vis = &shape.vis;
+    let kind = &shape.kind;
+    let kind_type = shape.kind_type();
+    let params = &shape.params;
+    let param_names = &shape.param_names;
+    let where_clause = &shape.where_clause;
+    let fa = shape.applied(quote!(__A));
+    let fb = shape.applied(quote!(__B));
+    let apply = shape.applied(quote!(__T));
+
+    let doc = format!(
+        "The kind of `{}`, generated by `derive(Functor)`.",
+        shape.name
+    );
+    let kind_struct = if param_names.is_empty() {
+        quote!(#vis struct #kind;)
+    } else {
+        quote!(#vis struct #kind<#(#param_names),*>(::std::marker::PhantomData<(#(#param_names,)*)>);)
+    };
+
+    let mut arms = Vec::new();
+    for variant in &shape.variants {
+        let mut values = Vec::new();
+        for (i, field) in variant.fields.iter().enumerate() {
+            let binding = &field.binding;
+            let mapped = fmap(shape, &field.ty, quote!(#binding), i)?;
+            values.push(mapped.unwrap_or_else(|| quote!(#binding)));
+        }
+        let pattern = variant.pattern();
+        let result = variant.with(&values);
+        arms.push(quote!(#pattern => #result));
+    }
+
+    Ok(quote! {
+        #[doc = #doc]
+        #kind_struct
+
+        impl<#(#params),*> ::rust_hkt::part3::HKT for #kind_type #where_clause {
+            type Apply<__T> = #apply;
+        }
+
+        impl<#(#params),*> #kind_type #where_clause {
+            fn __derive_fmap<__A, __B, __F>(fa: #fa, f: &mut __F) -> #fb
+            where
+                __F: FnMut(__A) -> __B,
+            {
+                match fa {
+                    #(#arms,)*
+                }
+            }
+        }
+
+        impl<'__a, #(#params),*> ::rust_hkt::part3::Functor<'__a> for #kind_type #where_clause {
+            fn fmap<__A, __B, __F>(fa: #fa, mut f: __F) -> #fb
+            where
+                __A: '__a,
+                __B: '__a,
+                __F: FnMut(__A) -> __B + '__a,
+            {
+                Self::__derive_fmap(fa, &mut f)
+            }
+        }
+    })
+}
+
+/// The expression which maps `f` over `expr` of type `ty`, or `None` if `ty` has nothing to map.
+fn fmap(shape: &Shape, ty: &Type, expr: TokenStream, depth: usize) -> Result<Option<TokenStream>> {
+    if !shape.mentions_hole(ty) {
+        return Ok(None);
+    }
+    if shape.is_hole(ty) {
+        return Ok(Some(quote!(f(#expr))));
+    }
+    if shape.is_self(ty) {
+        return Ok(Some(quote!(Self::__derive_fmap(#expr, &mut *f))));
+    }
+    let x = format_ident!("__x{}", depth);
+    if let Some((container, inner)) = shape.container(ty) {
+        let inner = match fmap(shape, inner, quote!(#x), depth + 1)? {
+            Some(inner) => inner,
+            None => return Ok(None),
+        };
+        return Ok(Some(match container {
+            Container::Box => quote!({ let #x = *#expr; ::std::boxed::Box::new(#inner) }),
+            Container::Option => quote!(#expr.map(|#x| #inner)),
+            Container::Vec => quote! {
+                #expr.into_iter().map(|#x| #inner).collect::<::std::vec::Vec<_>>()
+            },
+        }));
+    }
+    match *ty {
+        Type::Paren(ref paren) => fmap(shape, &paren.elem, expr, depth),
+        Type::Tuple(ref tuple) => {
+            let names: Vec<_> = (0..tuple.elems.len())
+                .map(|i| format_ident!("__x{}_{}", depth, i))
+                .collect();
+            let mut values = Vec::new();
+            for (elem, name) in tuple.elems.iter().zip(&names) {
+                let mapped = fmap(shape, elem, quote!(#name), depth + 1)?;
+                values.push(mapped.unwrap_or_else(|| quote!(#name)));
+            }
+            Ok(Some(quote!({ let (#(#names,)*) = #expr; (#(#values,)*) })))
+        }
+        _ => Err(shape.unsupported(ty, "Functor")),
+    }
+}
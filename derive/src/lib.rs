@@ -0,0 +1,203 @@
+//! Derive macros for `rust_hkt`'s typeclasses, re-exported from `rust_hkt::part3` behind the
+//! `derive` feature.
+
+extern crate proc_macro;
+extern crate proc_macro2;
+extern crate quote;
+extern crate syn;
+
+mod foldable;
+mod functor;
+mod shape;
+mod traverse;
+
+use proc_macro::TokenStream;
+use syn::DeriveInput;
+
+use shape::Shape;
+
+/// Generates a kind for a type, its `HKT` impl, and a `Functor` impl which maps over the last
+/// type parameter.
+///
+/// The kind of `Name` is called `NameKind`, takes the same type parameters as `Name` except the
+/// last one, and has the same visibility. Fields are mapped if they're the last type parameter
+/// itself, the type being derived (so recursive types work), or a tuple, `Box`, `Option` or
+/// `Vec` of those. Fields which don't mention the parameter are moved across as they are.
+///
+/// ```rust
+/// # extern crate rust_hkt;
+/// # use rust_hkt::part3::Functor;
+/// #[derive(Debug, PartialEq, Functor)]
+/// enum Tree<A> {
+///     Leaf,
+///     Node(Box<Tree<A>>, A, Box<Tree<A>>),
+/// }
+///
+/// #[derive(Debug, PartialEq, Functor)]
+/// struct Labelled<L, A> {
+///     label: L,
+///     values: Vec<(A, Option<A>)>,
+/// }
+///
+/// # fn main() {
+/// let tree = Tree::Node(Box::new(Tree::Leaf), 1, Box::new(Tree::Node(Box::new(Tree::Leaf), 2, Box::new(Tree::Leaf))));
+/// let doubled = TreeKind::fmap(tree, |i| i * 2);
+/// assert_eq!(doubled, Tree::Node(Box::new(Tree::Leaf), 2, Box::new(Tree::Node(Box::new(Tree::Leaf), 4, Box::new(Tree::Leaf)))));
+///
+/// let labelled = Labelled { label: "xs", values: vec![(1, Some(2)), (3, None)] };
+/// let strings = LabelledKind::fmap(labelled, |i| i.to_string());
+/// assert_eq!(strings, Labelled { label: "xs", values: vec![("1".to_string(), Some("2".to_string())), ("3".to_string(), None)] });
+/// # }
+/// ```
+#[proc_macro_derive(Functor)]
+pub fn derive_functor(input: TokenStream) -> TokenStream {
+    let input = syn::parse_macro_input!(input as DeriveInput);
+    Shape::parse(input, "Functor")
+        .and_then(|shape| functor::derive(&shape))
+        .unwrap_or_else(|err| err.to_compile_error())
+        .into()
+}
+
+/// Generates a `Foldable` impl for a type's kind, folding over the last type parameter in the
+/// order the fields are declared.
+///
+/// This needs the kind from `derive(Functor)`, so the two are derived together. It looks inside
+/// the same fields `derive(Functor)` does, including containers of the type being derived:
+///
+/// ```rust
+/// # extern crate rust_hkt;
+/// # use rust_hkt::part3::{Foldable, Functor};
+/// #[derive(Functor, Foldable)]
+/// struct Tree<A> {
+///     value: A,
+///     children: Vec<Tree<A>>,
+/// }
+///
+/// #[derive(Functor, Foldable)]
+/// enum Entry<K, A> {
+///     Empty(K),
+///     Full(K, A, Option<(A, Box<Entry<K, A>>)>),
+/// }
+///
+/// fn leaf<A>(value: A) -> Tree<A> {
+///     Tree { value, children: vec![] }
+/// }
+///
+/// # fn main() {
+/// let tree = Tree { value: 1, children: vec![leaf(2), Tree { value: 3, children: vec![leaf(4)] }] };
+/// assert_eq!(TreeKind::fold_left(tree, Vec::new(), |mut acc, i| { acc.push(i); acc }), vec![1, 2, 3, 4]);
+///
+/// let tree = Tree { value: "a", children: vec![leaf("b"), leaf("c")] };
+/// assert_eq!(TreeKind::fold_right(tree, String::new(), |s, acc| acc + s), "cba");
+///
+/// let entry = Entry::Full("k", 1, Some((2, Box::new(Entry::Full("k", 3, None)))));
+/// assert_eq!(EntryKind::fold_map(entry, |i: i32| i.to_string()), "123");
+/// assert_eq!(EntryKind::fold_left(Entry::Empty("k"), 0, |acc, i: i32| acc + i), 0);
+/// # }
+/// ```
+#[proc_macro_derive(Foldable)]
+pub fn derive_foldable(input: TokenStream) -> TokenStream {
+    let input = syn::parse_macro_input!(input as DeriveInput);
+    Shape::parse(input, "Foldable")
+        .and_then(|shape| foldable::derive(&shape))
+        .unwrap_or_else(|err| err.to_compile_error())
+        .into()
+}
+
+/// Generates a `Traverse` impl for a type's kind, running the effects for the last type parameter
+/// in the order the fields are declared.
+///
+/// `Traverse` builds on `Functor` and `Foldable`, so this is derived alongside them. The fields
+/// which aren't traversed are put back with `pure`, and `map2` needs everything it combines to be
+/// `Clone`, so the impl requires the other type parameters to be `Clone`.
+///
+/// ```rust
+/// # extern crate rust_hkt;
+/// # use rust_hkt::part3::{Foldable, Functor, OptionKind, ResultKind, Traverse, VecKind};
+/// #[derive(Clone, Debug, PartialEq, Functor, Foldable, Traverse)]
+/// struct Tree<A> {
+///     value: A,
+///     children: Vec<Tree<A>>,
+/// }
+///
+/// #[derive(Clone, Debug, PartialEq, Functor, Foldable, Traverse)]
+/// enum Field<L, A> {
+///     Missing(L),
+///     Present(L, A, Option<Box<Field<L, A>>>),
+/// }
+///
+/// fn leaf<A>(value: A) -> Tree<A> {
+///     Tree { value, children: vec![] }
+/// }
+///
+/// # fn main() {
+/// let parse = |s: &str| s.parse::<i32>().map_err(|_| format!("{} isn't a number", s));
+///
+/// let tree = Tree { value: "1", children: vec![leaf("2"), Tree { value: "3", children: vec![leaf("4")] }] };
+/// let parsed = TreeKind::traverse::<ResultKind<String>, _, _, _>(tree, parse);
+/// assert_eq!(parsed, Ok(Tree { value: 1, children: vec![leaf(2), Tree { value: 3, children: vec![leaf(4)] }] }));
+///
+/// let tree = Tree { value: "1", children: vec![leaf("two"), leaf("three")] };
+/// let parsed = TreeKind::traverse::<ResultKind<String>, _, _, _>(tree, parse);
+/// assert_eq!(parsed, Err("two isn't a number".to_string()));
+///
+/// let field = Field::Present("a", Some(1), Some(Box::new(Field::Present("b", Some(2), None))));
+/// assert_eq!(
+///     FieldKind::sequence::<OptionKind, _>(field),
+///     Some(Field::Present("a", 1, Some(Box::new(Field::Present("b", 2, None))))),
+/// );
+///
+/// let choices = FieldKind::traverse::<VecKind, _, _, _>(Field::Present("a", 1, None), |i| vec![i, -i]);
+/// assert_eq!(choices, vec![Field::Present("a", 1, None), Field::Present("a", -1, None)]);
+/// assert_eq!(FieldKind::sequence::<OptionKind, i32>(Field::Missing("a")), Some(Field::Missing("a")));
+/// # }
+/// ```
+///
+/// Bounds on the other type parameters, inline or in a `where` clause, are carried over to every
+/// impl:
+///
+/// ```rust
+/// # extern crate rust_hkt;
+/// # use std::fmt::Display;
+/// # use rust_hkt::part3::{Foldable, Functor, OptionKind, Traverse};
+/// #[derive(Clone, Debug, PartialEq, Functor, Foldable, Traverse)]
+/// struct Labelled<L, A>
+/// where
+///     L: Display,
+/// {
+///     label: L,
+///     values: Vec<A>,
+/// }
+///
+/// # fn main() {
+/// let labelled = Labelled { label: 'x', values: vec![Some(1), Some(2)] };
+/// assert_eq!(
+///     LabelledKind::sequence::<OptionKind, _>(labelled),
+///     Some(Labelled { label: 'x', values: vec![1, 2] }),
+/// );
+/// # }
+/// ```
+///
+/// The last type parameter can't have any, since the impls are for every value of it:
+///
+/// ```compile_fail
+/// # extern crate rust_hkt;
+/// # use rust_hkt::part3::{Foldable, Functor, Traverse};
+/// #[derive(Clone, Functor, Foldable, Traverse)]
+/// struct Labelled<L, A>
+/// where
+///     A: Clone,
+/// {
+///     label: L,
+///     values: Vec<A>,
+/// }
+/// # fn main() {}
+/// ```
+#[proc_macro_derive(Traverse)]
+pub fn derive_traverse(input: TokenStream) -> TokenStream {
+    let input = syn::parse_macro_input!(input as DeriveInput);
+    Shape::parse(input, "Traverse")
+        .and_then(|shape| traverse::derive(&shape))
+        .unwrap_or_else(|err| err.to_compile_error())
+        .into()
+}
@@ -0,0 +1,252 @@
+use proc_macro2::{TokenStream, TokenTree};
+use quote::{format_ident, quote, ToTokens};
+use syn::{
+    Data, DeriveInput, Error, Fields, GenericParam, Ident, Member, Result, Type, Visibility,
+    WhereClause,
+};
+
+/// Everything the derives need to know about the type they're attached to.
+pub struct Shape {
+    pub vis: Visibility,
+    pub name: Ident,
+    pub kind: Ident,
+    /// The type parameters other than the last one, with their bounds.
+    pub params: Vec<GenericParam>,
+    pub param_names: Vec<Ident>,
+    /// The last type parameter, which the kind abstracts over.
+    pub hole: Ident,
+    pub where_clause: Option<WhereClause>,
+    pub variants: Vec<Variant>,
+}
+
+pub struct Variant {
+    /// `Name` for a struct, `Name::Variant` for an enum.
+    pub path: TokenStream,
+    pub fields: Vec<Field>,
+}
+
+pub struct Field {
+    pub member: Member,
+    pub ty: Type,
+    pub binding: Ident,
+}
+
+impl Shape {
+    pub fn parse(input: DeriveInput, derive: &str) -> Result<Shape> {
+        let mut params = Vec::new();
+        let mut param_names = Vec::new();
+        let mut hole = None;
+        let count = input.generics.params.len();
+        for (i, param) in input.generics.params.iter().enumerate() {
+            match *param {
+                GenericParam::Type(ref ty) if i + 1 == count => {
+                    if !ty.bounds.is_empty() {
+                        return Err(Error::new_spanned(
+                            ty,
+                            format!(
+                                "derive({}) can't put bounds on the last type parameter",
+                                derive
+                            ),
+                        ));
+                    }
+                    hole = Some(ty.ident.clone());
+                }
+                GenericParam::Type(ref ty) => {
+                    param_names.push(ty.ident.clone());
+                    params.push(param.clone());
+                }
+                _ => {
+                    return Err(Error::new_spanned(
+                        param,
+                        format!("derive({}) only supports type parameters", derive),
+                    ));
+                }
+            }
+        }
+        let hole = hole.ok_or_else(|| {
+            Error::new_spanned(
+                &input.ident,
+                format!("derive({}) needs a last type parameter to map over", derive),
+            )
+        })?;
+        // The kind's impls are for every value of the hole, so a `where` clause can only constrain
+        // the other parameters.
+        if let Some(ref where_clause) = input.generics.where_clause {
+            for predicate in &where_clause.predicates {
+                if mentions(predicate.to_token_stream(), &hole) {
+                    return Err(Error::new_spanned(
+                        predicate,
+                        format!(
+                            "derive({}) can't put bounds on the last type parameter",
+                            derive
+                        ),
+                    ));
+                }
+            }
+        }
+
+        let name = input.ident;
+        let variants = match input.data {
+            Data::Struct(data) => vec![Variant::new(quote!(#name), data.fields)],
+            Data::Enum(data) => data
+                .variants
+                .into_iter()
+                .map(|variant| {
+                    let ident = variant.ident;
+                    Variant::new(quote!(#name::#ident), variant.fields)
+                })
+                .collect(),
+            Data::Union(data) => {
+                return Err(Error::new_spanned(
+                    data.union_token,
+                    format!("derive({}) doesn't support unions", derive),
+                ));
+            }
+        };
+
+        Ok(Shape {
+            vis: input.vis,
+            kind: format_ident!("{}Kind", name),
+            name,
+            params,
+            param_names,
+            hole,
+            where_clause: input.generics.where_clause,
+            variants,
+        })
+    }
+
+    /// The type with `arg` in place of the last type parameter.
+    pub fn applied(&self, arg: TokenStream) -> TokenStream {
+        let name = &self.name;
+        let params = &self.param_names;
+        quote!(#name<#(#params,)* #arg>)
+    }
+
+    pub fn kind_type(&self) -> TokenStream {
+        let kind = &self.kind;
+        let params = &self.param_names;
+        quote!(#kind<#(#params),*>)
+    }
+
+    pub fn mentions_hole(&self, ty: &Type) -> bool {
+        mentions(ty.to_token_stream(), &self.hole)
+    }
+
+    /// Whether `ty` is the hole itself.
+    pub fn is_hole(&self, ty: &Type) -> bool {
+        match *ty {
+            Type::Path(ref path) if path.qself.is_none() => path.path.is_ident(&self.hole),
+            _ => false,
+        }
+    }
+
+    /// Whether `ty` is the type being derived, applied to exactly its own parameters.
+    pub fn is_self(&self, ty: &Type) -> bool {
+        let path = match *ty {
+            Type::Path(ref path) if path.qself.is_none() => &path.path,
+            _ => return false,
+        };
+        let segment = match path.segments.last() {
+            Some(segment) if path.segments.len() == 1 && segment.ident == self.name => segment,
+            _ => return false,
+        };
+        let args = match segment.arguments {
+            syn::PathArguments::AngleBracketed(ref args) => &args.args,
+            _ => return false,
+        };
+        let expected = self.param_names.iter().chain(Some(&self.hole));
+        args.len() == self.param_names.len() + 1
+            && args.iter().zip(expected).all(|(arg, expected)| match *arg {
+                syn::GenericArgument::Type(Type::Path(ref path)) => path.path.is_ident(expected),
+                _ => false,
+            })
+    }
+
+    /// One of the standard containers the derives know how to look inside, and its element type.
+    pub fn container<'t>(&self, ty: &'t Type) -> Option<(Container, &'t Type)> {
+        let path = match *ty {
+            Type::Path(ref path) if path.qself.is_none() => &path.path,
+            _ => return None,
+        };
+        let segment = path.segments.last()?;
+        let container = match &*segment.ident.to_string() {
+            "Box" => Container::Box,
+            "Option" => Container::Option,
+            "Vec" => Container::Vec,
+            _ => return None,
+        };
+        match segment.arguments {
+            syn::PathArguments::AngleBracketed(ref args) if args.args.len() == 1 => {
+                match args.args[0] {
+                    syn::GenericArgument::Type(ref inner) => Some((container, inner)),
+                    _ => None,
+                }
+            }
+            _ => None,
+        }
+    }
+
+    pub fn unsupported(&self, ty: &Type, derive: &str) -> Error {
+        Error::new_spanned(
+            ty,
+            format!(
+                "derive({}) doesn't know how to look inside this type for `{}`; it supports `{}` \
+                 itself, `{}`, tuples, `Box`, `Option` and `Vec`",
+                derive,
+                self.hole,
+                self.hole,
+                self.applied(self.hole.to_token_stream()),
+            ),
+        )
+    }
+}
+
+#[derive(Clone, Copy)]
+pub enum Container {
+    Box,
+    Option,
+    Vec,
+}
+
+impl Variant {
+    fn new(path: TokenStream, fields: Fields) -> Variant {
+        let fields = fields
+            .into_iter()
+            .enumerate()
+            .map(|(i, field)| Field {
+                member: match field.ident {
+                    Some(ident) => Member::Named(ident),
+                    None => Member::Unnamed(i.into()),
+                },
+                ty: field.ty,
+                binding: format_ident!("__field{}", i),
+            })
+            .collect();
+        Variant { path, fields }
+    }
+
+    /// A pattern (or constructor) with each field bound to the given expression.
+    pub fn with(&self, values: &[TokenStream]) -> TokenStream {
+        let path = &self.path;
+        let members = self.fields.iter().map(|field| &field.member);
+        quote!(#path { #(#members: #values),* })
+    }
+
+    pub fn pattern(&self) -> TokenStream {
+        let bindings: Vec<_> = self
+            .fields
+            .iter()
+            .map(|f| f.binding.to_token_stream())
+            .collect();
+        self.with(&bindings)
+    }
+}
+
+fn mentions(tokens: TokenStream, ident: &Ident) -> bool {
+    tokens.into_iter().any(|token| match token {
+        TokenTree::Ident(ref i) => i == ident,
+        TokenTree::Group(ref group) => mentions(group.stream(), ident),
+        _ => false,
+    })
+}
@@ -0,0 +1,160 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{Result, Type};
+
+use shape::{Container, Shape};
+
+pub fn derive(shape: &Shape) -> Result<TokenStream> {
+    let kind_type = shape.kind_type();
+    let params = &shape.params;
+    let param_names = &shape.param_names;
+    let fa = shape.applied(quote!(__A));
+    let fb = shape.applied(quote!(__B));
+
+    // Everything that isn't traversed is put back with `pure` and combined with `map2_lazy`, which
+    // needs the other parameters to be `Clone`.
+    let predicates = shape
+        .where_clause
+        .as_ref()
+        .map(|where_clause| where_clause.predicates.iter().collect::<Vec<_>>())
+        .unwrap_or_default();
+    let where_clause = quote! {
+        where
+            #(#param_names: Clone + '__a,)*
+            #(#predicates,)*
+    };
+
+    let mut arms = Vec::new();
+    for variant in &shape.variants {
+        let mut bindings = Vec::new();
+        let mut effects = Vec::new();
+        for (i, field) in variant.fields.iter().enumerate() {
+            let binding = &field.binding;
+            bindings.push(quote!(#binding));
+            effects.push(
+                traverse(shape, &field.ty, quote!(#binding), i)?
+                    .unwrap_or_else(|| quote!(__G::pure(#binding))),
+            );
+        }
+        let pattern = variant.pattern();
+        let result = variant.with(&bindings);
+        let body = combine(&bindings, &effects, result);
+        arms.push(quote!(#pattern => #body));
+    }
+
+    Ok(quote! {
+        impl<#(#params),*> #kind_type {
+            fn __derive_traverse<'__a, __G, __A, __B, __F>(
+                fa: #fa,
+                f: &mut __F,
+            ) -> <__G as ::rust_hkt::part3::HKT>::Apply<#fb>
+            #where_clause
+                __G: ::rust_hkt::part3::Applicative<'__a>,
+                __A: '__a,
+                __B: Clone + '__a,
+                #fb: Clone + '__a,
+                __F: FnMut(__A) -> <__G as ::rust_hkt::part3::HKT>::Apply<__B>,
+            {
+                match fa {
+                    #(#arms,)*
+                }
+            }
+        }
+
+        impl<'__a, #(#params),*> ::rust_hkt::part3::Traverse<'__a> for #kind_type #where_clause {
+            fn traverse<__G, __A, __B, __F>(
+                fa: #fa,
+                mut f: __F,
+            ) -> <__G as ::rust_hkt::part3::HKT>::Apply<#fb>
+            where
+                __G: ::rust_hkt::part3::Applicative<'__a>,
+                __A: '__a,
+                __B: Clone + '__a,
+                #fb: Clone + '__a,
+                __F: FnMut(__A) -> <__G as ::rust_hkt::part3::HKT>::Apply<__B> + '__a,
+            {
+                Self::__derive_traverse::<__G, _, _, _>(fa, &mut f)
+            }
+        }
+    })
+}
+
+/// Combines `effects` with `map2_lazy`, binding their results to `names` in `result`. The
+/// effects after a failure aren't run at all.
+fn combine(names: &[TokenStream], effects: &[TokenStream], result: TokenStream) -> TokenStream {
+    let mut combined = quote!(__G::pure(()));
+    let mut pattern = quote!(());
+    for (name, effect) in names.iter().zip(effects) {
+        combined = quote!(__G::map2_lazy(#combined, || #effect, |__acc, __x| (__acc, __x)));
+        pattern = quote!((#pattern, #name));
+    }
+    quote!(__G::fmap(#combined, |#pattern| #result))
+}
+
+/// The expression which traverses `expr` of type `ty` with `f`, or `None` if `ty` has nothing to
+/// traverse.
+fn traverse(
+    shape: &Shape,
+    ty: &Type,
+    expr: TokenStream,
+    depth: usize,
+) -> Result<Option<TokenStream>> {
+    if !shape.mentions_hole(ty) {
+        return Ok(None);
+    }
+    if shape.is_hole(ty) {
+        return Ok(Some(quote!(f(#expr))));
+    }
+    if shape.is_self(ty) {
+        return Ok(Some(
+            quote!(Self::__derive_traverse::<__G, _, _, _>(#expr, &mut *f)),
+        ));
+    }
+    let x = format_ident!("__x{}", depth);
+    if let Some((container, inner)) = shape.container(ty) {
+        let inner = match traverse(shape, inner, quote!(#x), depth + 1)? {
+            Some(inner) => inner,
+            None => return Ok(None),
+        };
+        return Ok(Some(match container {
+            Container::Box => quote! {
+                { let #x = *#expr; __G::fmap(#inner, ::std::boxed::Box::new) }
+            },
+            Container::Option => quote! {
+                match #expr {
+                    ::std::option::Option::Some(#x) => {
+                        __G::fmap(#inner, ::std::option::Option::Some)
+                    }
+                    ::std::option::Option::None => __G::pure(::std::option::Option::None),
+                }
+            },
+            Container::Vec => quote! {
+                #expr.into_iter().fold(
+                    __G::pure(::std::vec::Vec::new()),
+                    |__acc, #x| __G::map2_lazy(__acc, || #inner, |mut __xs, __x| {
+                        __xs.push(__x);
+                        __xs
+                    }),
+                )
+            },
+        }));
+    }
+    match *ty {
+        Type::Paren(ref paren) => traverse(shape, &paren.elem, expr, depth),
+        Type::Tuple(ref tuple) => {
+            let mut names = Vec::new();
+            let mut effects = Vec::new();
+            for (i, elem) in tuple.elems.iter().enumerate() {
+                let name = format_ident!("__x{}_{}", depth, i);
+                effects.push(
+                    traverse(shape, elem, quote!(#name), depth + 1)?
+                        .unwrap_or_else(|| quote!(__G::pure(#name))),
+                );
+                names.push(quote!(#name));
+            }
+            let body = combine(&names, &effects, quote!((#(#names,)*)));
+            Ok(Some(quote!({ let (#(#names,)*) = #expr; #body })))
+        }
+        _ => Err(shape.unsupported(ty, "Traverse")),
+    }
+}
@@ -0,0 +1,56 @@
+//! # Data types
+//!
+//! Types which don't exist in std, but which come up again and again once we can talk about type
+//! constructors generically. Each type lives alongside its kinds and its typeclass instances.
+
+mod codec;
+mod comparator;
+mod const_;
+mod coyoneda;
+mod dict;
+mod either;
+mod either_t;
+mod encoder;
+mod env;
+mod eval;
+mod free;
+mod identity;
+mod io;
+mod ior;
+mod iter;
+mod nested;
+mod non_empty_vec;
+mod predicate;
+mod reader_t;
+mod state;
+mod state_t;
+mod store;
+mod validated;
+mod writer_t;
+mod yoneda;
+
+pub use self::codec::{Codec, CodecKind};
+pub use self::comparator::{Comparator, ComparatorKind};
+pub use self::const_::{Const, ConstKind};
+pub use self::coyoneda::{Coyoneda, CoyonedaKind};
+pub use self::dict::{MonoidDict, MonoidDictKind, SemigroupDict, SemigroupDictKind};
+pub use self::either::{Either, EitherKind, EitherKind2};
+pub use self::either_t::{EitherT, EitherTKind};
+pub use self::encoder::{Encoder, EncoderKind};
+pub use self::env::{Env, EnvKind};
+pub use self::eval::{Eval, EvalKind};
+pub use self::free::Free;
+pub use self::identity::{Identity, IdentityKind};
+pub use self::io::{ApIO, ApIOKind, IOKind, IO};
+pub use self::ior::{Ior, IorKind2};
+pub use self::iter::{Iter, IterKind};
+pub use self::nested::{Nested, NestedKind};
+pub use self::non_empty_vec::{NonEmptyVec, NonEmptyVecKind};
+pub use self::predicate::{Predicate, PredicateKind};
+pub use self::reader_t::{Kleisli, KleisliKind, Reader, ReaderT, ReaderTKind};
+pub use self::state::{State, StateKind};
+pub use self::state_t::{StateT, StateTKind};
+pub use self::store::{Store, StoreKind};
+pub use self::validated::{Validated, ValidatedKind, ValidatedKind2, ValidatedNel};
+pub use self::writer_t::{Writer, WriterT, WriterTKind};
+pub use self::yoneda::{Yoneda, YonedaKind};
@@ -0,0 +1,85 @@
+use std::rc::Rc;
+use std::str::FromStr;
+
+use part3::{Invariant, HKT};
+
+/// A way to write an `A` out as a string and to read it back in.
+///
+/// A codec both consumes `A`s (`encode`) and produces them (`decode`), so it's `Invariant`:
+/// given a way there and back, a codec for one type becomes a codec for another.
+///
+/// ```rust
+/// # use rust_hkt::data::{Codec, CodecKind};
+/// # use rust_hkt::part3::Invariant;
+/// #[derive(Clone, Copy, Debug, PartialEq)]
+/// struct Port(u16);
+///
+/// let port = CodecKind::imap(Codec::<u16>::of(), Port, |port: Port| port.0);
+/// assert_eq!(port.encode(Port(8080)), "8080");
+/// assert_eq!(port.decode("443"), Some(Port(443)));
+/// assert_eq!(port.decode("http"), None);
+/// ```
+pub struct Codec<A> {
+    encode: Rc<dyn Fn(A) -> String>,
+    decode: Decode<A>,
+}
+
+type Decode<A> = Rc<dyn Fn(&str) -> Option<A>>;
+
+impl<A: 'static> Codec<A> {
+    pub fn new<E, D>(encode: E, decode: D) -> Codec<A>
+    where
+        E: Fn(A) -> String + 'static,
+        D: Fn(&str) -> Option<A> + 'static,
+    {
+        Codec {
+            encode: Rc::new(encode),
+            decode: Rc::new(decode),
+        }
+    }
+
+    /// The codec given by `A`'s `ToString` and `FromStr` implementations.
+    pub fn of() -> Codec<A>
+    where
+        A: ToString + FromStr,
+    {
+        Codec::new(|a: A| a.to_string(), |s| s.parse().ok())
+    }
+
+    pub fn encode(&self, a: A) -> String {
+        (self.encode)(a)
+    }
+
+    pub fn decode(&self, s: &str) -> Option<A> {
+        (self.decode)(s)
+    }
+}
+
+impl<A> Clone for Codec<A> {
+    fn clone(&self) -> Codec<A> {
+        Codec {
+            encode: self.encode.clone(),
+            decode: self.decode.clone(),
+        }
+    }
+}
+
+/// The kind of `Codec`.
+pub struct CodecKind;
+
+impl HKT for CodecKind {
+    type Apply<A> = Codec<A>;
+}
+
+impl Invariant<'static> for CodecKind {
+    fn imap<A, B, F, G>(fa: Codec<A>, f: F, g: G) -> Codec<B>
+    where
+        A: 'static,
+        B: 'static,
+        F: Fn(A) -> B + 'static,
+        G: Fn(B) -> A + 'static,
+    {
+        let (encode, decode) = (fa.encode, fa.decode);
+        Codec::new(move |b| encode(g(b)), move |s| decode(s).map(&f))
+    }
+}
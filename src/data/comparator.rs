@@ -0,0 +1,128 @@
+use std::cmp::Ordering;
+use std::convert::Infallible;
+use std::rc::Rc;
+
+use part3::{Contravariant, Decidable, Decide, Divide, Divisible, HKT};
+
+use super::Either;
+
+/// A total order on `A`s, as a value.
+///
+/// `contramap` compares by a key. `divide` compares lexicographically, by the first half and
+/// then, for a tie, by the second. `conquer` finds everything equal, and `choose` puts every
+/// `Left` before every `Right`, like the derived `Ord` for an enum:
+///
+/// ```rust
+/// # use rust_hkt::data::{Comparator, ComparatorKind};
+/// # use rust_hkt::part3::{Contravariant, Divide};
+/// let by_len = ComparatorKind::contramap(Comparator::<usize>::of(), |s: &str| s.len());
+/// let by_len_then_reversed = ComparatorKind::divide(by_len, Comparator::<&str>::of().reverse(), |s| (s, s));
+///
+/// let mut words = vec!["bb", "c", "aa", "ddd"];
+/// words.sort_by(|a, b| by_len_then_reversed.compare(*a, *b));
+/// assert_eq!(words, vec!["c", "bb", "aa", "ddd"]);
+/// ```
+pub struct Comparator<A> {
+    compare: Rc<dyn Fn(A, A) -> Ordering>,
+}
+
+impl<A: 'static> Comparator<A> {
+    pub fn new<F>(compare: F) -> Comparator<A>
+    where
+        F: Fn(A, A) -> Ordering + 'static,
+    {
+        Comparator {
+            compare: Rc::new(compare),
+        }
+    }
+
+    /// The comparator given by `A`'s `Ord` implementation.
+    pub fn of() -> Comparator<A>
+    where
+        A: Ord,
+    {
+        Comparator::new(|x: A, y| x.cmp(&y))
+    }
+
+    pub fn compare(&self, x: A, y: A) -> Ordering {
+        (self.compare)(x, y)
+    }
+
+    pub fn reverse(self) -> Comparator<A> {
+        Comparator::new(move |x, y| self.compare(y, x))
+    }
+}
+
+impl<A> Clone for Comparator<A> {
+    fn clone(&self) -> Comparator<A> {
+        Comparator {
+            compare: self.compare.clone(),
+        }
+    }
+}
+
+/// The kind of `Comparator`.
+pub struct ComparatorKind;
+
+impl HKT for ComparatorKind {
+    type Apply<A> = Comparator<A>;
+}
+
+impl Contravariant<'static> for ComparatorKind {
+    fn contramap<A, B, F>(fa: Comparator<A>, f: F) -> Comparator<B>
+    where
+        A: 'static,
+        B: 'static,
+        F: Fn(B) -> A + 'static,
+    {
+        Comparator::new(move |x, y| fa.compare(f(x), f(y)))
+    }
+}
+
+impl Divide<'static> for ComparatorKind {
+    fn divide<A, B, C, F>(fa: Comparator<A>, fb: Comparator<B>, f: F) -> Comparator<C>
+    where
+        A: 'static,
+        B: 'static,
+        C: 'static,
+        F: Fn(C) -> (A, B) + 'static,
+    {
+        Comparator::new(move |x, y| {
+            let ((xa, xb), (ya, yb)) = (f(x), f(y));
+            fa.compare(xa, ya).then_with(|| fb.compare(xb, yb))
+        })
+    }
+}
+
+impl Divisible<'static> for ComparatorKind {
+    fn conquer<A: 'static>() -> Comparator<A> {
+        Comparator::new(|_, _| Ordering::Equal)
+    }
+}
+
+impl Decide<'static> for ComparatorKind {
+    fn choose<A, B, C, F>(fa: Comparator<A>, fb: Comparator<B>, f: F) -> Comparator<C>
+    where
+        A: 'static,
+        B: 'static,
+        C: 'static,
+        F: Fn(C) -> Either<A, B> + 'static,
+    {
+        Comparator::new(move |x, y| match (f(x), f(y)) {
+            (Either::Left(x), Either::Left(y)) => fa.compare(x, y),
+            (Either::Right(x), Either::Right(y)) => fb.compare(x, y),
+            (Either::Left(_), Either::Right(_)) => Ordering::Less,
+            (Either::Right(_), Either::Left(_)) => Ordering::Greater,
+        })
+    }
+}
+
+impl Decidable<'static> for ComparatorKind {
+    fn lose<A, F>(f: F) -> Comparator<A>
+    where
+        A: 'static,
+        F: Fn(A) -> Infallible + 'static,
+    {
+        Comparator::new(move |x, _| match f(x) {})
+    }
+}
@@ -0,0 +1,139 @@
+use std::fmt;
+use std::marker::PhantomData;
+
+use kernel::{Monoid, Semigroup};
+use part3::{Applicative, Apply, Functor, Semigroupal, HKT};
+
+/// A functor which ignores its second type parameter, it always holds a `C` and never an `A`.
+///
+/// Mapping over a `Const` does nothing at all, but its `Applicative` combines the values with
+/// their `Monoid`. That makes it exactly what we need to get `fold_map` out of `traverse`:
+///
+/// ```rust
+/// # use rust_hkt::data::{Const, ConstKind};
+/// # use rust_hkt::kernel::Monoid;
+/// # use rust_hkt::part3::{Traverse, VecKind};
+/// fn fold_map<'a, T, A, M, F>(fa: T::Apply<A>, mut f: F) -> M
+/// where
+///     T: Traverse<'a>,
+///     T::Apply<()>: Clone + 'a,
+///     A: 'a,
+///     M: Monoid + 'a,
+///     F: FnMut(A) -> M + 'a,
+/// {
+///     T::traverse::<ConstKind<M>, _, (), _>(fa, move |a| Const::new(f(a))).get()
+/// }
+///
+/// assert_eq!(fold_map::<VecKind, _, _, _>(vec![1, 2, 3], |i| i.to_string()), "123");
+/// ```
+///
+/// Only `pure` needs the `empty` value. Combining needs nothing more than a `Semigroup`, so with
+/// a `C` like `NonEmptyVec`, `ConstKind<C>` is an `Apply` but not an `Applicative`:
+///
+/// ```rust
+/// # use rust_hkt::data::{Const, ConstKind, NonEmptyVec};
+/// # use rust_hkt::part3::Apply;
+/// let errors = ConstKind::map2(
+///     Const::<_, i32>::new(NonEmptyVec::of("a")),
+///     Const::<_, i32>::new(NonEmptyVec::of("b")),
+///     |a, b| a + b,
+/// );
+/// assert_eq!(errors.get(), NonEmptyVec::new("a", vec!["b"]));
+/// ```
+pub struct Const<C, A> {
+    value: C,
+    marker: PhantomData<A>,
+}
+
+impl<C, A> Const<C, A> {
+    pub fn new(value: C) -> Const<C, A> {
+        Const {
+            value,
+            marker: PhantomData,
+        }
+    }
+
+    pub fn get(self) -> C {
+        self.value
+    }
+
+    /// Changes the phantom type, which is always possible since there's no `A` to change.
+    pub fn retag<B>(self) -> Const<C, B> {
+        Const::new(self.value)
+    }
+}
+
+impl<C, A> Clone for Const<C, A>
+where
+    C: Clone,
+{
+    fn clone(&self) -> Const<C, A> {
+        Const::new(self.value.clone())
+    }
+}
+
+impl<C, A> fmt::Debug for Const<C, A>
+where
+    C: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("Const").field(&self.value).finish()
+    }
+}
+
+impl<C, A> PartialEq for Const<C, A>
+where
+    C: PartialEq,
+{
+    fn eq(&self, other: &Const<C, A>) -> bool {
+        self.value == other.value
+    }
+}
+
+impl<C, A> Eq for Const<C, A> where C: Eq {}
+
+/// The kind of `Const<C, _>`.
+pub struct ConstKind<C>(PhantomData<C>);
+
+impl<C> HKT for ConstKind<C> {
+    type Apply<A> = Const<C, A>;
+}
+
+impl<'a, C> Functor<'a> for ConstKind<C> {
+    fn fmap<A, B, F>(fa: Const<C, A>, _: F) -> Const<C, B>
+    where
+        F: FnMut(A) -> B,
+    {
+        fa.retag()
+    }
+}
+
+impl<'a, C> Semigroupal<'a> for ConstKind<C>
+where
+    C: Semigroup,
+{
+    fn product<A, B>(fa: Const<C, A>, fb: Const<C, B>) -> Const<C, (A, B)> {
+        Const::new(fa.value.combine(fb.value))
+    }
+}
+
+impl<'a, C> Apply<'a> for ConstKind<C>
+where
+    C: Semigroup,
+{
+    fn map2<A, B, D, F>(fa: Const<C, A>, fb: Const<C, B>, _: F) -> Const<C, D>
+    where
+        F: FnMut(A, B) -> D,
+    {
+        Const::new(fa.value.combine(fb.value))
+    }
+}
+
+impl<'a, C> Applicative<'a> for ConstKind<C>
+where
+    C: Monoid,
+{
+    fn pure<A>(_: A) -> Const<C, A> {
+        Const::new(C::empty())
+    }
+}
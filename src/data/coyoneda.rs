@@ -0,0 +1,96 @@
+use std::marker::PhantomData;
+
+use part3::{Functor, HKT};
+
+/// A value `F<X>` paired with a function `X -> A`: everything needed to produce an `F<A>`, except
+/// the actual `fmap`.
+///
+/// `map` just composes onto the function, so `CoyonedaKind` is a `Functor` whatever `F` is,
+/// even when `F` isn't one. Once `F` is a `Functor`, `lower` does all of the mapping in one go.
+/// That's also the answer to chaining `fmap` over something like a `Vec`, which otherwise builds
+/// (and throws away) a new `Vec` for every step:
+///
+/// ```rust
+/// # use rust_hkt::data::Coyoneda;
+/// # use rust_hkt::part3::VecKind;
+/// let mut calls = 0;
+/// let strings = Coyoneda::<VecKind, _, _>::lift(vec![1, 2, 3])
+///     .map(|i| i + 1)
+///     .map(|i| i * 2)
+///     .map(|i| {
+///         calls += 1;
+///         i.to_string()
+///     })
+///     .lower();
+/// assert_eq!(strings, vec!["4", "6", "8"]);
+/// assert_eq!(calls, 3);
+/// ```
+///
+/// Haskell hides the type `X` which the function starts from, but Rust has no existential types,
+/// so here it's a parameter. Every `map` keeps the same `X`, so `CoyonedaKind<F, X>` is still a
+/// kind of its own:
+///
+/// ```rust
+/// # use rust_hkt::data::{Coyoneda, CoyonedaKind};
+/// # use rust_hkt::part3::{Functor, OptionKind};
+/// fn describe<'a, F>(fa: F::Apply<i32>) -> F::Apply<String>
+/// where
+///     F: Functor<'a>,
+/// {
+///     F::fmap(fa, |i| format!("got {}", i))
+/// }
+///
+/// let described = describe::<CoyonedaKind<OptionKind, i32>>(Coyoneda::lift(Some(1)));
+/// assert_eq!(described.lower(), Some("got 1".to_string()));
+/// ```
+pub struct Coyoneda<'a, F: HKT, X, A> {
+    fx: F::Apply<X>,
+    f: Box<dyn FnMut(X) -> A + 'a>,
+}
+
+impl<'a, F: HKT, X: 'a> Coyoneda<'a, F, X, X> {
+    pub fn lift(fx: F::Apply<X>) -> Coyoneda<'a, F, X, X> {
+        Coyoneda {
+            fx,
+            f: Box::new(|x| x),
+        }
+    }
+}
+
+impl<'a, F: HKT, X: 'a, A: 'a> Coyoneda<'a, F, X, A> {
+    pub fn map<B, G>(self, mut g: G) -> Coyoneda<'a, F, X, B>
+    where
+        G: FnMut(A) -> B + 'a,
+    {
+        let mut f = self.f;
+        Coyoneda {
+            fx: self.fx,
+            f: Box::new(move |x| g(f(x))),
+        }
+    }
+
+    pub fn lower(self) -> F::Apply<A>
+    where
+        F: Functor<'a>,
+    {
+        F::fmap(self.fx, self.f)
+    }
+}
+
+/// The kind of `Coyoneda<'a, F, X, _>`.
+pub struct CoyonedaKind<'a, F, X>(PhantomData<(&'a (), F, X)>);
+
+impl<'a, F: HKT, X> HKT for CoyonedaKind<'a, F, X> {
+    type Apply<A> = Coyoneda<'a, F, X, A>;
+}
+
+impl<'a, F: HKT, X: 'a> Functor<'a> for CoyonedaKind<'a, F, X> {
+    fn fmap<A, B, G>(fa: Coyoneda<'a, F, X, A>, g: G) -> Coyoneda<'a, F, X, B>
+    where
+        A: 'a,
+        B: 'a,
+        G: FnMut(A) -> B + 'a,
+    {
+        fa.map(g)
+    }
+}
@@ -0,0 +1,161 @@
+use std::rc::Rc;
+
+use kernel::{Monoid, Semigroup};
+use part3::{Invariant, HKT};
+
+/// A `Semigroup` instance as a value: the `combine` function for `A`.
+///
+/// Traits can't be passed around or transformed, but a dictionary can. It's consumed as well as
+/// produced, so the best it can do is `Invariant`, see there for an example.
+///
+/// ```rust
+/// # use rust_hkt::data::SemigroupDict;
+/// let longest = SemigroupDict::new(|a: String, b: String| if b.len() > a.len() { b } else { a });
+/// assert_eq!(longest.combine("ab".to_string(), "c".to_string()), "ab");
+///
+/// let concat = SemigroupDict::<String>::of();
+/// assert_eq!(concat.combine("ab".to_string(), "c".to_string()), "abc");
+/// ```
+pub struct SemigroupDict<A> {
+    combine: Rc<dyn Fn(A, A) -> A>,
+}
+
+impl<A: 'static> SemigroupDict<A> {
+    pub fn new<F>(combine: F) -> SemigroupDict<A>
+    where
+        F: Fn(A, A) -> A + 'static,
+    {
+        SemigroupDict {
+            combine: Rc::new(combine),
+        }
+    }
+
+    /// The dictionary for `A`'s own `Semigroup`.
+    pub fn of() -> SemigroupDict<A>
+    where
+        A: Semigroup,
+    {
+        SemigroupDict::new(A::combine)
+    }
+
+    pub fn combine(&self, x: A, y: A) -> A {
+        (self.combine)(x, y)
+    }
+}
+
+impl<A> Clone for SemigroupDict<A> {
+    fn clone(&self) -> SemigroupDict<A> {
+        SemigroupDict {
+            combine: self.combine.clone(),
+        }
+    }
+}
+
+/// A `Monoid` instance as a value: `empty` and `combine` for `A`.
+///
+/// ```rust
+/// # use rust_hkt::data::{MonoidDict, MonoidDictKind};
+/// # use rust_hkt::part3::Invariant;
+/// let product = MonoidDict::new(1, |a: i64, b| a * b);
+/// assert_eq!(product.combine_all(vec![2, 3, 4]), 24);
+///
+/// let product_of_lengths = MonoidDictKind::imap(product, |i| i as usize, |u| u as i64);
+/// assert_eq!(product_of_lengths.combine_all(vec![2, 3]), 6);
+/// ```
+pub struct MonoidDict<A> {
+    empty: Rc<dyn Fn() -> A>,
+    semigroup: SemigroupDict<A>,
+}
+
+impl<A: 'static> MonoidDict<A> {
+    pub fn new<F>(empty: A, combine: F) -> MonoidDict<A>
+    where
+        A: Clone,
+        F: Fn(A, A) -> A + 'static,
+    {
+        MonoidDict {
+            empty: Rc::new(move || empty.clone()),
+            semigroup: SemigroupDict::new(combine),
+        }
+    }
+
+    /// The dictionary for `A`'s own `Monoid`.
+    pub fn of() -> MonoidDict<A>
+    where
+        A: Monoid,
+    {
+        MonoidDict {
+            empty: Rc::new(A::empty),
+            semigroup: SemigroupDict::of(),
+        }
+    }
+
+    pub fn empty(&self) -> A {
+        (self.empty)()
+    }
+
+    pub fn combine(&self, x: A, y: A) -> A {
+        self.semigroup.combine(x, y)
+    }
+
+    pub fn combine_all<I>(&self, iter: I) -> A
+    where
+        I: IntoIterator<Item = A>,
+    {
+        iter.into_iter()
+            .fold(self.empty(), |acc, a| self.combine(acc, a))
+    }
+}
+
+impl<A> Clone for MonoidDict<A> {
+    fn clone(&self) -> MonoidDict<A> {
+        MonoidDict {
+            empty: self.empty.clone(),
+            semigroup: self.semigroup.clone(),
+        }
+    }
+}
+
+/// The kind of `SemigroupDict`.
+pub struct SemigroupDictKind;
+
+impl HKT for SemigroupDictKind {
+    type Apply<A> = SemigroupDict<A>;
+}
+
+impl Invariant<'static> for SemigroupDictKind {
+    fn imap<A, B, F, G>(fa: SemigroupDict<A>, f: F, g: G) -> SemigroupDict<B>
+    where
+        A: 'static,
+        B: 'static,
+        F: Fn(A) -> B + 'static,
+        G: Fn(B) -> A + 'static,
+    {
+        SemigroupDict::new(move |x, y| f(fa.combine(g(x), g(y))))
+    }
+}
+
+/// The kind of `MonoidDict`.
+pub struct MonoidDictKind;
+
+impl HKT for MonoidDictKind {
+    type Apply<A> = MonoidDict<A>;
+}
+
+impl Invariant<'static> for MonoidDictKind {
+    fn imap<A, B, F, G>(fa: MonoidDict<A>, f: F, g: G) -> MonoidDict<B>
+    where
+        A: 'static,
+        B: 'static,
+        F: Fn(A) -> B + 'static,
+        G: Fn(B) -> A + 'static,
+    {
+        let f = Rc::new(f);
+        let empty = {
+            let (f, empty) = (f.clone(), fa.empty.clone());
+            Rc::new(move || f(empty())) as Rc<dyn Fn() -> B>
+        };
+        let semigroup = SemigroupDictKind::imap(fa.semigroup, move |a| f(a), g);
+        MonoidDict { empty, semigroup }
+    }
+}
@@ -0,0 +1,341 @@
+use std::marker::PhantomData;
+
+use kernel::{Monoid, Semigroup};
+use part3::{
+    Applicative, ApplicativeError, Apply, Bifoldable, Bifunctor, Bitraverse, FlatMap, FlatMapOnce,
+    Foldable, Functor, Monad, MonadError, Parallel, Selective, Semigroupal, Traverse, HKT, HKT2,
+};
+
+use super::{Validated, ValidatedKind};
+
+/// A value which is one of two types.
+///
+/// This is the same shape as `Result`, but without the connotation that one side is an error.
+/// Like `Result` it's right biased: `Functor`, `Monad` and friends, via `EitherKind<L>`, work on
+/// the `Right` side and pass a `Left` through untouched.
+///
+/// ```rust
+/// # use rust_hkt::data::{Either, EitherKind};
+/// # use rust_hkt::part3::{FlatMap, OptionKind, Traverse};
+/// let parse = |s: &str| match s.parse::<i32>() {
+///     Ok(i) => Either::Right(i),
+///     Err(_) => Either::Left(format!("{} isn't a number", s)),
+/// };
+///
+/// let sum = EitherKind::flat_map(parse("1"), |a| EitherKind::flat_map(parse("2"), move |b| Either::Right(a + b)));
+/// assert_eq!(sum, Either::Right(3));
+/// assert_eq!(EitherKind::flat_map(parse("x"), |a| Either::Right(a + 1)), Either::Left("x isn't a number".to_string()));
+///
+/// let right: Either<(), Option<i32>> = Either::Right(Some(1));
+/// assert_eq!(EitherKind::sequence::<OptionKind, _>(right), Some(Either::Right(1)));
+///
+/// assert_eq!(Either::<i32, &str>::Left(1).swap(), Either::Right(1));
+/// assert_eq!(Either::<i32, i32>::Left(1).merge(), 1);
+///
+/// let result: Result<i32, String> = Either::Right(1).into();
+/// assert_eq!(result, Ok(1));
+/// assert_eq!(Either::from(Err::<i32, _>("nope")), Either::Left("nope"));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Either<L, R> {
+    Left(L),
+    Right(R),
+}
+
+impl<L, R> Either<L, R> {
+    pub fn swap(self) -> Either<R, L> {
+        match self {
+            Either::Left(l) => Either::Right(l),
+            Either::Right(r) => Either::Left(r),
+        }
+    }
+
+    pub fn is_left(&self) -> bool {
+        match *self {
+            Either::Left(_) => true,
+            Either::Right(_) => false,
+        }
+    }
+
+    pub fn is_right(&self) -> bool {
+        !self.is_left()
+    }
+}
+
+impl<A> Either<A, A> {
+    /// Extracts the value, whichever side it's on.
+    pub fn merge(self) -> A {
+        match self {
+            Either::Left(a) | Either::Right(a) => a,
+        }
+    }
+}
+
+impl<L, R> From<Result<R, L>> for Either<L, R> {
+    fn from(result: Result<R, L>) -> Either<L, R> {
+        match result {
+            Ok(r) => Either::Right(r),
+            Err(l) => Either::Left(l),
+        }
+    }
+}
+
+impl<L, R> From<Either<L, R>> for Result<R, L> {
+    fn from(either: Either<L, R>) -> Result<R, L> {
+        match either {
+            Either::Right(r) => Ok(r),
+            Either::Left(l) => Err(l),
+        }
+    }
+}
+
+/// The kind of `Either<L, _>`, with the left type fixed.
+pub struct EitherKind<L>(PhantomData<L>);
+
+impl<L> HKT for EitherKind<L> {
+    type Apply<A> = Either<L, A>;
+}
+
+/// The kind of `Either`, with both type parameters free.
+pub struct EitherKind2;
+
+impl HKT2 for EitherKind2 {
+    type Apply2<A, B> = Either<A, B>;
+}
+
+impl<'a, L> Functor<'a> for EitherKind<L> {
+    fn fmap<A, B, F>(fa: Either<L, A>, mut f: F) -> Either<L, B>
+    where
+        F: FnMut(A) -> B,
+    {
+        match fa {
+            Either::Left(l) => Either::Left(l),
+            Either::Right(a) => Either::Right(f(a)),
+        }
+    }
+}
+
+impl<'a, L> Semigroupal<'a> for EitherKind<L> {
+    fn product<A, B>(fa: Either<L, A>, fb: Either<L, B>) -> Either<L, (A, B)> {
+        match (fa, fb) {
+            (Either::Right(a), Either::Right(b)) => Either::Right((a, b)),
+            (Either::Left(l), _) | (_, Either::Left(l)) => Either::Left(l),
+        }
+    }
+}
+
+impl<'a, L> Apply<'a> for EitherKind<L> {
+    fn map2<A, B, C, F>(fa: Either<L, A>, fb: Either<L, B>, mut f: F) -> Either<L, C>
+    where
+        F: FnMut(A, B) -> C,
+    {
+        match (fa, fb) {
+            (Either::Right(a), Either::Right(b)) => Either::Right(f(a, b)),
+            (Either::Left(l), _) | (_, Either::Left(l)) => Either::Left(l),
+        }
+    }
+
+    fn map2_lazy<A, B, C, F, G>(fa: Either<L, A>, fb: G, mut f: F) -> Either<L, C>
+    where
+        F: FnMut(A, B) -> C,
+        G: FnOnce() -> Either<L, B>,
+    {
+        match fa {
+            Either::Left(l) => Either::Left(l),
+            Either::Right(a) => match fb() {
+                Either::Left(l) => Either::Left(l),
+                Either::Right(b) => Either::Right(f(a, b)),
+            },
+        }
+    }
+}
+
+impl<'a, L> Applicative<'a> for EitherKind<L> {
+    fn pure<A>(a: A) -> Either<L, A> {
+        Either::Right(a)
+    }
+}
+
+impl<'a, L> FlatMap<'a> for EitherKind<L> {
+    fn flat_map<A, B, F>(fa: Either<L, A>, mut f: F) -> Either<L, B>
+    where
+        F: FnMut(A) -> Either<L, B>,
+    {
+        match fa {
+            Either::Left(l) => Either::Left(l),
+            Either::Right(a) => f(a),
+        }
+    }
+}
+
+impl<'a, L> FlatMapOnce<'a> for EitherKind<L> {
+    fn flat_map_once<A, B, F>(fa: Either<L, A>, f: F) -> Either<L, B>
+    where
+        F: FnOnce(A) -> Either<L, B>,
+    {
+        match fa {
+            Either::Left(l) => Either::Left(l),
+            Either::Right(a) => f(a),
+        }
+    }
+}
+
+impl<'a, L> Monad<'a> for EitherKind<L> {
+    fn tail_rec_m<A, B, F>(mut a: A, mut f: F) -> Either<L, B>
+    where
+        F: FnMut(A) -> Either<L, Either<A, B>>,
+    {
+        loop {
+            match f(a) {
+                Either::Left(l) => return Either::Left(l),
+                Either::Right(Either::Left(next)) => a = next,
+                Either::Right(Either::Right(b)) => return Either::Right(b),
+            }
+        }
+    }
+}
+
+impl<'a, L> ApplicativeError<'a, L> for EitherKind<L> {
+    fn raise_error<A>(l: L) -> Either<L, A> {
+        Either::Left(l)
+    }
+
+    fn handle_error_with<A, F>(fa: Either<L, A>, mut f: F) -> Either<L, A>
+    where
+        F: FnMut(L) -> Either<L, A>,
+    {
+        match fa {
+            Either::Left(l) => f(l),
+            Either::Right(a) => Either::Right(a),
+        }
+    }
+}
+
+impl<'a, L> MonadError<'a, L> for EitherKind<L> {}
+
+/// Accumulates `Left`s with `Validated`, see `Parallel`.
+impl<'a, L> Parallel<'a> for EitherKind<L>
+where
+    L: Semigroup + 'a,
+{
+    type Par = ValidatedKind<L>;
+
+    fn parallel<A>(ma: Either<L, A>) -> Validated<L, A> {
+        Validated::from(ma)
+    }
+
+    fn sequential<A>(fa: Validated<L, A>) -> Either<L, A> {
+        Either::from(fa)
+    }
+}
+
+impl<L> Foldable for EitherKind<L> {
+    fn fold_left<A, B, F>(fa: Either<L, A>, init: B, mut f: F) -> B
+    where
+        F: FnMut(B, A) -> B,
+    {
+        match fa {
+            Either::Left(_) => init,
+            Either::Right(a) => f(init, a),
+        }
+    }
+
+    fn fold_right<A, B, F>(fa: Either<L, A>, init: B, mut f: F) -> B
+    where
+        F: FnMut(A, B) -> B,
+    {
+        match fa {
+            Either::Left(_) => init,
+            Either::Right(a) => f(a, init),
+        }
+    }
+
+    fn fold_map<A, M, F>(fa: Either<L, A>, mut f: F) -> M
+    where
+        M: Monoid,
+        F: FnMut(A) -> M,
+    {
+        match fa {
+            Either::Left(_) => M::empty(),
+            Either::Right(a) => f(a),
+        }
+    }
+}
+
+impl<'a, L: 'a> Traverse<'a> for EitherKind<L> {
+    fn traverse<G, A, B, F>(fa: Either<L, A>, mut f: F) -> G::Apply<Either<L, B>>
+    where
+        G: Applicative<'a>,
+        B: 'a,
+        F: FnMut(A) -> G::Apply<B>,
+    {
+        match fa {
+            Either::Left(l) => G::pure(Either::Left(l)),
+            Either::Right(a) => G::fmap(f(a), Either::Right),
+        }
+    }
+}
+
+impl<'a> Bifunctor<'a> for EitherKind2 {
+    fn bimap<A, B, C, D, F, G>(fab: Either<A, B>, mut f: F, mut g: G) -> Either<C, D>
+    where
+        F: FnMut(A) -> C,
+        G: FnMut(B) -> D,
+    {
+        match fab {
+            Either::Left(a) => Either::Left(f(a)),
+            Either::Right(b) => Either::Right(g(b)),
+        }
+    }
+}
+
+impl Bifoldable for EitherKind2 {
+    fn bifold_left<A, B, C, F, G>(fab: Either<A, B>, init: C, mut f: F, mut g: G) -> C
+    where
+        F: FnMut(C, A) -> C,
+        G: FnMut(C, B) -> C,
+    {
+        match fab {
+            Either::Left(a) => f(init, a),
+            Either::Right(b) => g(init, b),
+        }
+    }
+}
+
+impl<'a> Bitraverse<'a> for EitherKind2 {
+    fn bitraverse<G, A, B, C, D, F, H>(
+        fab: Either<A, B>,
+        mut f: F,
+        mut g: H,
+    ) -> G::Apply<Either<C, D>>
+    where
+        G: Applicative<'a>,
+        C: Clone + 'a,
+        D: Clone + 'a,
+        F: FnMut(A) -> G::Apply<C>,
+        H: FnMut(B) -> G::Apply<D>,
+    {
+        match fab {
+            Either::Left(a) => G::fmap(f(a), Either::Left),
+            Either::Right(b) => G::fmap(g(b), Either::Right),
+        }
+    }
+}
+
+impl<'a, L> Selective<'a> for EitherKind<L> {
+    fn select<A, B, F>(fab: Either<L, Either<A, B>>, ff: Either<L, F>) -> Either<L, B>
+    where
+        A: Clone + 'a,
+        B: Clone + 'a,
+        F: FnOnce(A) -> B + Clone + 'a,
+    {
+        match fab {
+            Either::Left(l) => Either::Left(l),
+            Either::Right(Either::Left(a)) => match ff {
+                Either::Left(l) => Either::Left(l),
+                Either::Right(f) => Either::Right(f(a)),
+            },
+            Either::Right(Either::Right(b)) => Either::Right(b),
+        }
+    }
+}
@@ -0,0 +1,248 @@
+use std::marker::PhantomData;
+
+use part3::{
+    Applicative, ApplicativeError, Apply, Bracket, FlatMap, FlatMapOnce, Functor, Monad,
+    MonadError, MonadTrans, Semigroupal, HKT,
+};
+
+use super::Either;
+
+/// A computation in `F` which can fail with an `E`: an `F<Either<E, A>>`.
+///
+/// This is the Either monad transformer. It adds short-circuiting errors to a monad which has
+/// none of its own, so that once a step has failed, the steps after it don't run:
+///
+/// ```rust
+/// # use std::cell::RefCell;
+/// # use std::rc::Rc;
+/// # use rust_hkt::data::{Either, EitherT, IO, IOKind};
+/// let log = Rc::new(RefCell::new(Vec::new()));
+/// let step = |log: &Rc<RefCell<Vec<i32>>>, i: i32| {
+///     let log = log.clone();
+///     EitherT::<String, IOKind, _>::lift(IO::delay(move || log.borrow_mut().push(i)))
+/// };
+///
+/// let program = step(&log, 1)
+///     .flat_map(|()| EitherT::<_, IOKind, ()>::left("failed".to_string()))
+///     .flat_map({
+///         let log = log.clone();
+///         move |()| step(&log, 2)
+///     });
+///
+/// assert_eq!(program.run().run(), Either::Left("failed".to_string()));
+/// assert_eq!(*log.borrow(), vec![1]);
+/// ```
+pub struct EitherT<E, F: HKT, A> {
+    run: F::Apply<Either<E, A>>,
+}
+
+impl<E, F: HKT, A> EitherT<E, F, A> {
+    pub fn new(run: F::Apply<Either<E, A>>) -> EitherT<E, F, A> {
+        EitherT { run }
+    }
+
+    pub fn run(self) -> F::Apply<Either<E, A>> {
+        self.run
+    }
+
+    pub fn right<'a>(a: A) -> EitherT<E, F, A>
+    where
+        F: Applicative<'a>,
+        E: 'a,
+        A: 'a,
+    {
+        EitherT::new(F::pure(Either::Right(a)))
+    }
+
+    pub fn left<'a>(e: E) -> EitherT<E, F, A>
+    where
+        F: Applicative<'a>,
+        E: 'a,
+        A: 'a,
+    {
+        EitherT::new(F::pure(Either::Left(e)))
+    }
+
+    /// Lifts a computation which doesn't fail.
+    pub fn lift<'a>(fa: F::Apply<A>) -> EitherT<E, F, A>
+    where
+        F: Functor<'a>,
+        E: 'a,
+        A: 'a,
+    {
+        EitherT::new(F::fmap(fa, Either::Right))
+    }
+
+    pub fn map<'a, B, K>(self, mut f: K) -> EitherT<E, F, B>
+    where
+        F: Functor<'a>,
+        E: 'a,
+        A: 'a,
+        B: 'a,
+        K: FnMut(A) -> B + 'a,
+    {
+        EitherT::new(F::fmap(self.run, move |ea| match ea {
+            Either::Left(e) => Either::Left(e),
+            Either::Right(a) => Either::Right(f(a)),
+        }))
+    }
+
+    pub fn flat_map<'a, B, K>(self, mut f: K) -> EitherT<E, F, B>
+    where
+        F: Monad<'a>,
+        E: 'a,
+        A: 'a,
+        B: 'a,
+        K: FnMut(A) -> EitherT<E, F, B> + 'a,
+    {
+        EitherT::new(F::flat_map(self.run, move |ea| match ea {
+            Either::Left(e) => F::pure(Either::Left(e)),
+            Either::Right(a) => f(a).run,
+        }))
+    }
+}
+
+/// The kind of `EitherT<E, F, _>`.
+pub struct EitherTKind<E, F>(PhantomData<(E, F)>);
+
+impl<E, F: HKT> HKT for EitherTKind<E, F> {
+    type Apply<A> = EitherT<E, F, A>;
+}
+
+impl<'a, E: 'a, F: Functor<'a>> Functor<'a> for EitherTKind<E, F> {
+    fn fmap<A, B, K>(fa: EitherT<E, F, A>, f: K) -> EitherT<E, F, B>
+    where
+        A: 'a,
+        B: 'a,
+        K: FnMut(A) -> B + 'a,
+    {
+        fa.map(f)
+    }
+}
+
+impl<'a, E: 'a, F: FlatMapOnce<'a> + Monad<'a> + 'a> Semigroupal<'a> for EitherTKind<E, F> {
+    fn product<A, B>(fa: EitherT<E, F, A>, fb: EitherT<E, F, B>) -> EitherT<E, F, (A, B)>
+    where
+        A: Clone + 'a,
+        B: Clone + 'a,
+    {
+        EitherT::new(F::flat_map_once(fa.run, move |ea| match ea {
+            Either::Left(e) => F::pure(Either::Left(e)),
+            Either::Right(a) => fb.map(move |b| (a.clone(), b)).run,
+        }))
+    }
+}
+
+impl<'a, E: 'a, F: FlatMapOnce<'a> + Monad<'a> + 'a> Apply<'a> for EitherTKind<E, F> {
+    fn map2<A, B, C, K>(fa: EitherT<E, F, A>, fb: EitherT<E, F, B>, mut f: K) -> EitherT<E, F, C>
+    where
+        A: Clone + 'a,
+        B: Clone + 'a,
+        C: 'a,
+        K: FnMut(A, B) -> C + 'a,
+    {
+        EitherT::new(F::flat_map_once(fa.run, move |ea| match ea {
+            Either::Left(e) => F::pure(Either::Left(e)),
+            Either::Right(a) => fb.map(move |b| f(a.clone(), b)).run,
+        }))
+    }
+}
+
+impl<'a, E: 'a, F: FlatMapOnce<'a> + Monad<'a> + 'a> Applicative<'a> for EitherTKind<E, F> {
+    fn pure<A: 'a>(a: A) -> EitherT<E, F, A> {
+        EitherT::right(a)
+    }
+}
+
+impl<'a, E: 'a, F: FlatMapOnce<'a> + Monad<'a> + 'a> FlatMap<'a> for EitherTKind<E, F> {
+    fn flat_map<A, B, K>(fa: EitherT<E, F, A>, f: K) -> EitherT<E, F, B>
+    where
+        A: 'a,
+        B: 'a,
+        K: FnMut(A) -> EitherT<E, F, B> + 'a,
+    {
+        fa.flat_map(f)
+    }
+}
+
+impl<'a, E: 'a, F: FlatMapOnce<'a> + Monad<'a> + 'a> FlatMapOnce<'a> for EitherTKind<E, F> {
+    fn flat_map_once<A, B, K>(fa: EitherT<E, F, A>, f: K) -> EitherT<E, F, B>
+    where
+        A: 'a,
+        B: 'a,
+        K: FnOnce(A) -> EitherT<E, F, B> + 'a,
+    {
+        EitherT::new(F::flat_map_once(fa.run, move |ea| match ea {
+            Either::Left(e) => F::pure(Either::Left(e)),
+            Either::Right(a) => f(a).run,
+        }))
+    }
+}
+
+/// Stops the `tail_rec_m` of the monad underneath at the first `Left`, so it's as stack-safe as
+/// that one is:
+///
+/// ```rust
+/// # use rust_hkt::data::{Either, EitherT, EitherTKind};
+/// # use rust_hkt::part3::{Monad, OptionKind};
+/// let count = |fail_at: u32| EitherTKind::<u32, OptionKind>::tail_rec_m(1_000_000, move |i: u32| {
+///     EitherT::new(Some(match i {
+///         0 => Either::Right(Either::Right("done")),
+///         i if i == fail_at => Either::Left(i),
+///         i => Either::Right(Either::Left(i - 1)),
+///     }))
+/// });
+/// assert_eq!(count(u32::MAX).run(), Some(Either::Right("done")));
+/// assert_eq!(count(7).run(), Some(Either::Left(7)));
+/// ```
+impl<'a, E: 'a, F: FlatMapOnce<'a> + Monad<'a> + 'a> Monad<'a> for EitherTKind<E, F> {
+    fn tail_rec_m<A, B, K>(a: A, mut f: K) -> EitherT<E, F, B>
+    where
+        A: 'a,
+        B: 'a,
+        K: FnMut(A) -> EitherT<E, F, Either<A, B>> + 'a,
+    {
+        EitherT::new(F::tail_rec_m(a, move |a| {
+            F::fmap(f(a).run, |step| match step {
+                Either::Left(e) => Either::Right(Either::Left(e)),
+                Either::Right(Either::Left(a)) => Either::Left(a),
+                Either::Right(Either::Right(b)) => Either::Right(Either::Right(b)),
+            })
+        }))
+    }
+}
+
+impl<'a, E: 'a, F: Monad<'a> + 'a> MonadTrans<'a> for EitherTKind<E, F> {
+    type Inner = F;
+
+    fn lift<A: 'a>(fa: F::Apply<A>) -> EitherT<E, F, A> {
+        EitherT::lift(fa)
+    }
+}
+
+impl<'a, E: 'a, F: FlatMapOnce<'a> + Monad<'a> + 'a> ApplicativeError<'a, E> for EitherTKind<E, F> {
+    fn raise_error<A: 'a>(e: E) -> EitherT<E, F, A> {
+        EitherT::left(e)
+    }
+
+    fn handle_error_with<A, K>(fa: EitherT<E, F, A>, mut f: K) -> EitherT<E, F, A>
+    where
+        A: 'a,
+        K: FnMut(E) -> EitherT<E, F, A> + 'a,
+    {
+        EitherT::new(F::flat_map(fa.run, move |ea| match ea {
+            Either::Left(e) => f(e).run,
+            Either::Right(a) => F::pure(Either::Right(a)),
+        }))
+    }
+}
+
+impl<'a, E: 'a, F: FlatMapOnce<'a> + Monad<'a> + 'a> MonadError<'a, E> for EitherTKind<E, F> {}
+
+/// The finalizer runs whether `fa` succeeds, fails with an `E` or panics. If the finalizer itself
+/// fails, its error is dropped and `fa`'s result is kept.
+impl<'a, E: 'a, F: Bracket<'a> + FlatMapOnce<'a> + 'a> Bracket<'a> for EitherTKind<E, F> {
+    fn guarantee<A: 'a>(fa: EitherT<E, F, A>, finalizer: EitherT<E, F, ()>) -> EitherT<E, F, A> {
+        EitherT::new(F::guarantee(fa.run, F::fmap(finalizer.run, |_| ())))
+    }
+}
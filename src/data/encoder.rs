@@ -0,0 +1,118 @@
+use std::convert::Infallible;
+use std::rc::Rc;
+
+use part3::{Contravariant, Decidable, Decide, Divide, Divisible, HKT};
+
+use super::Either;
+
+/// A way to write an `A` out as a string: the encoding half of a `Codec`.
+///
+/// Without the decoding half, an encoder only consumes `A`s, so where a `Codec` is merely
+/// `Invariant`, an `Encoder` is `Contravariant`. `divide` writes both halves one after the
+/// other, `conquer` writes nothing, and `choose` writes whichever side it's given. See `Divide`
+/// and `Decide` for examples.
+///
+/// ```rust
+/// # use rust_hkt::data::{Encoder, EncoderKind};
+/// # use rust_hkt::part3::Contravariant;
+/// let hex = EncoderKind::contramap(Encoder::new(|i: u32| format!("{:x}", i)), |c: char| c as u32);
+/// assert_eq!(hex.encode('A'), "41");
+/// ```
+pub struct Encoder<A> {
+    encode: Rc<dyn Fn(A) -> String>,
+}
+
+impl<A: 'static> Encoder<A> {
+    pub fn new<F>(encode: F) -> Encoder<A>
+    where
+        F: Fn(A) -> String + 'static,
+    {
+        Encoder {
+            encode: Rc::new(encode),
+        }
+    }
+
+    /// The encoder given by `A`'s `ToString` implementation.
+    pub fn of() -> Encoder<A>
+    where
+        A: ToString,
+    {
+        Encoder::new(|a: A| a.to_string())
+    }
+
+    pub fn encode(&self, a: A) -> String {
+        (self.encode)(a)
+    }
+}
+
+impl<A> Clone for Encoder<A> {
+    fn clone(&self) -> Encoder<A> {
+        Encoder {
+            encode: self.encode.clone(),
+        }
+    }
+}
+
+/// The kind of `Encoder`.
+pub struct EncoderKind;
+
+impl HKT for EncoderKind {
+    type Apply<A> = Encoder<A>;
+}
+
+impl Contravariant<'static> for EncoderKind {
+    fn contramap<A, B, F>(fa: Encoder<A>, f: F) -> Encoder<B>
+    where
+        A: 'static,
+        B: 'static,
+        F: Fn(B) -> A + 'static,
+    {
+        Encoder::new(move |b| fa.encode(f(b)))
+    }
+}
+
+impl Divide<'static> for EncoderKind {
+    fn divide<A, B, C, F>(fa: Encoder<A>, fb: Encoder<B>, f: F) -> Encoder<C>
+    where
+        A: 'static,
+        B: 'static,
+        C: 'static,
+        F: Fn(C) -> (A, B) + 'static,
+    {
+        Encoder::new(move |c| {
+            let (a, b) = f(c);
+            fa.encode(a) + &fb.encode(b)
+        })
+    }
+}
+
+impl Divisible<'static> for EncoderKind {
+    fn conquer<A: 'static>() -> Encoder<A> {
+        Encoder::new(|_| String::new())
+    }
+}
+
+impl Decide<'static> for EncoderKind {
+    fn choose<A, B, C, F>(fa: Encoder<A>, fb: Encoder<B>, f: F) -> Encoder<C>
+    where
+        A: 'static,
+        B: 'static,
+        C: 'static,
+        F: Fn(C) -> Either<A, B> + 'static,
+    {
+        Encoder::new(move |c| match f(c) {
+            Either::Left(a) => fa.encode(a),
+            Either::Right(b) => fb.encode(b),
+        })
+    }
+}
+
+impl Decidable<'static> for EncoderKind {
+    fn lose<A, F>(f: F) -> Encoder<A>
+    where
+        A: 'static,
+        F: Fn(A) -> Infallible + 'static,
+    {
+        Encoder::new(move |a| match f(a) {})
+    }
+}
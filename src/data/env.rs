@@ -0,0 +1,75 @@
+use std::marker::PhantomData;
+
+use part3::{Comonad, Functor, HKT};
+
+/// A value `A` along with an environment `E` it was computed in.
+///
+/// This is the comonad dual to `Reader`: rather than a computation which is waiting for an
+/// environment, it's a value which carries one around, and `coflat_map` lets every step look at
+/// it:
+///
+/// ```rust
+/// # use rust_hkt::data::{Env, EnvKind};
+/// # use rust_hkt::part3::{Comonad, Functor};
+/// let price = Env::new("EUR", 12);
+/// let formatted = EnvKind::coflat_map(price, |price| format!("{} {}", price.value, price.ask()));
+/// assert_eq!(formatted.ask(), &"EUR");
+/// assert_eq!(EnvKind::extract(formatted), "12 EUR");
+///
+/// let doubled = EnvKind::fmap(Env::new("EUR", 12), |i| i * 2);
+/// assert_eq!(doubled.local(|currency| currency.len()), Env::new(3, 24));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Env<E, A> {
+    pub env: E,
+    pub value: A,
+}
+
+impl<E, A> Env<E, A> {
+    pub fn new(env: E, value: A) -> Env<E, A> {
+        Env { env, value }
+    }
+
+    /// The environment.
+    pub fn ask(&self) -> &E {
+        &self.env
+    }
+
+    /// Replaces the environment with one derived from it.
+    pub fn local<F, K>(self, f: K) -> Env<F, A>
+    where
+        K: FnOnce(E) -> F,
+    {
+        Env::new(f(self.env), self.value)
+    }
+}
+
+/// The kind of `Env<E, _>`.
+pub struct EnvKind<E>(PhantomData<E>);
+
+impl<E> HKT for EnvKind<E> {
+    type Apply<A> = Env<E, A>;
+}
+
+impl<'a, E> Functor<'a> for EnvKind<E> {
+    fn fmap<A, B, F>(fa: Env<E, A>, mut f: F) -> Env<E, B>
+    where
+        F: FnMut(A) -> B,
+    {
+        Env::new(fa.env, f(fa.value))
+    }
+}
+
+impl<'a, E: Clone> Comonad<'a> for EnvKind<E> {
+    fn extract<A>(wa: Env<E, A>) -> A {
+        wa.value
+    }
+
+    fn coflat_map<A, B, F>(wa: Env<E, A>, f: F) -> Env<E, B>
+    where
+        F: Fn(Env<E, A>) -> B,
+    {
+        let env = wa.env.clone();
+        Env::new(env, f(wa))
+    }
+}
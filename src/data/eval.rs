@@ -0,0 +1,346 @@
+use std::any::Any;
+use std::cell::RefCell;
+use std::mem;
+use std::rc::Rc;
+
+use part3::{Applicative, Apply, Defer, FlatMap, Functor, Monad, Semigroupal, HKT};
+
+/// A lazily evaluated value, which can be chained with `flat_map` to any depth without growing
+/// the stack.
+///
+/// There are three ways to make one:
+///
+/// - `Eval::now` wraps a value which has already been computed.
+/// - `Eval::later` computes its value the first time it's needed, and remembers it.
+/// - `Eval::always` computes its value every time it's needed.
+///
+/// Cloning an `Eval` shares it, so the difference between the last two shows up once there's
+/// more than one copy:
+///
+/// ```rust
+/// # use std::cell::Cell;
+/// # use std::rc::Rc;
+/// # use rust_hkt::data::Eval;
+/// let calls = Rc::new(Cell::new(0));
+/// let counted = |calls: &Rc<Cell<i32>>| {
+///     let calls = calls.clone();
+///     move || {
+///         calls.set(calls.get() + 1);
+///         42
+///     }
+/// };
+///
+/// let later = Eval::later(counted(&calls));
+/// assert_eq!(later.clone().value() + later.value(), 84);
+/// assert_eq!(calls.get(), 1);
+///
+/// let always = Eval::always(counted(&calls));
+/// assert_eq!(always.clone().value() + always.value(), 84);
+/// assert_eq!(calls.get(), 3);
+/// ```
+///
+/// `flat_map` doesn't run anything, it only records what to do next. `value` then runs the
+/// recorded steps in a loop with its own stack of continuations, so neither long chains nor deep
+/// recursion through `Eval::defer` overflow the real one:
+///
+/// ```rust
+/// # use rust_hkt::data::{Eval, EvalKind};
+/// # use rust_hkt::part3::FlatMap;
+/// let mut sum = Eval::now(0u64);
+/// for i in 1..=100_000 {
+///     sum = EvalKind::flat_map(sum, move |acc| Eval::now(acc + i));
+/// }
+/// assert_eq!(sum.value(), 5_000_050_000);
+///
+/// fn is_even(n: u32) -> Eval<bool> {
+///     if n == 0 { Eval::now(true) } else { Eval::defer(move || is_odd(n - 1)) }
+/// }
+/// fn is_odd(n: u32) -> Eval<bool> {
+///     if n == 0 { Eval::now(false) } else { Eval::defer(move || is_even(n - 1)) }
+/// }
+/// assert!(is_even(1_000_000).value());
+/// ```
+///
+/// The steps are type-erased with `Any`, so everything inside an `Eval` has to be `'static`, and
+/// `EvalKind` only implements the typeclasses for `'static`.
+pub struct Eval<A> {
+    repr: Repr<A>,
+}
+
+enum Repr<A> {
+    Always(Rc<dyn Fn() -> A>),
+    Memo(Rc<RefCell<Memo<A>>>),
+}
+
+struct Memo<A> {
+    state: State<A>,
+    // Set by `Clone`, which is the only place that knows `A: Clone`, and the only way a memo can
+    // end up shared.
+    clone: Option<fn(&A) -> A>,
+}
+
+enum State<A> {
+    Pending(Step),
+    Running,
+    // The step panicked, and took itself with it.
+    Poisoned,
+    Done(A),
+}
+
+/// Poisons a memo whose step is unwinding, so that a clone doesn't find it still `Running`.
+struct Poison<'a, A: 'a>(&'a RefCell<Memo<A>>);
+
+impl<'a, A> Drop for Poison<'a, A> {
+    fn drop(&mut self) {
+        self.0.borrow_mut().state = State::Poisoned;
+    }
+}
+
+fn poisoned() -> ! {
+    panic!("Eval panicked while it was being evaluated")
+}
+
+type Value = Box<dyn Any>;
+
+enum Step {
+    Done(Value),
+    Thunk(Box<dyn FnOnce() -> Value>),
+    Defer(Box<dyn FnOnce() -> Step>),
+    FlatMap(Box<Step>, Box<dyn FnOnce(Value) -> Step>),
+}
+
+fn run(mut step: Step) -> Value {
+    let mut continuations: Vec<Box<dyn FnOnce(Value) -> Step>> = Vec::new();
+    loop {
+        let value = match step {
+            Step::Done(value) => value,
+            Step::Thunk(f) => f(),
+            Step::Defer(f) => {
+                step = f();
+                continue;
+            }
+            Step::FlatMap(inner, k) => {
+                continuations.push(k);
+                step = *inner;
+                continue;
+            }
+        };
+        match continuations.pop() {
+            Some(k) => step = k(value),
+            None => return value,
+        }
+    }
+}
+
+fn downcast<A: 'static>(value: Value) -> A {
+    *value
+        .downcast()
+        .unwrap_or_else(|_| panic!("Eval step produced a value of the wrong type"))
+}
+
+impl<A: 'static> Eval<A> {
+    pub fn now(a: A) -> Eval<A> {
+        Eval::memo(State::Done(a))
+    }
+
+    pub fn later<F>(f: F) -> Eval<A>
+    where
+        F: FnOnce() -> A + 'static,
+    {
+        Eval::memo(State::Pending(Step::Thunk(Box::new(move || {
+            Box::new(f()) as Value
+        }))))
+    }
+
+    pub fn always<F>(f: F) -> Eval<A>
+    where
+        F: Fn() -> A + 'static,
+    {
+        Eval {
+            repr: Repr::Always(Rc::new(f)),
+        }
+    }
+
+    /// Suspends the construction of an `Eval`, which is what makes recursive definitions safe.
+    pub fn defer<F>(f: F) -> Eval<A>
+    where
+        F: FnOnce() -> Eval<A> + 'static,
+    {
+        Eval::memo(State::Pending(Step::Defer(Box::new(move || {
+            f().into_step()
+        }))))
+    }
+
+    pub fn flat_map<B, F>(self, f: F) -> Eval<B>
+    where
+        B: 'static,
+        F: FnOnce(A) -> Eval<B> + 'static,
+    {
+        let k = move |a: Value| f(downcast(a)).into_step();
+        Eval::memo(State::Pending(Step::FlatMap(
+            Box::new(self.into_step()),
+            Box::new(k),
+        )))
+    }
+
+    pub fn map<B, F>(self, f: F) -> Eval<B>
+    where
+        B: 'static,
+        F: FnOnce(A) -> B + 'static,
+    {
+        self.flat_map(move |a| Eval::now(f(a)))
+    }
+
+    /// Runs the computation. A shared `Later` or chained `Eval` only does so once; `Always` runs
+    /// every time.
+    ///
+    /// If the computation panics, there's nothing left to run, so every clone panics too:
+    ///
+    /// ```rust
+    /// # use std::panic::{self, AssertUnwindSafe};
+    /// # use rust_hkt::data::Eval;
+    /// let eval = Eval::later(|| -> i32 { panic!("no value") });
+    /// let copy = eval.clone();
+    /// assert!(panic::catch_unwind(AssertUnwindSafe(|| eval.value())).is_err());
+    ///
+    /// let payload = panic::catch_unwind(AssertUnwindSafe(|| copy.value())).unwrap_err();
+    /// let message = payload.downcast_ref::<&str>();
+    /// assert_eq!(message, Some(&"Eval panicked while it was being evaluated"));
+    /// ```
+    pub fn value(self) -> A {
+        let memo = match self.repr {
+            Repr::Always(f) => return f(),
+            Repr::Memo(memo) => memo,
+        };
+        let state = mem::replace(&mut memo.borrow_mut().state, State::Running);
+        let a = match state {
+            State::Pending(step) => {
+                let poison = Poison(&memo);
+                let a = downcast(run(step));
+                mem::forget(poison);
+                a
+            }
+            State::Running => panic!("Eval depends on its own value"),
+            State::Poisoned => poisoned(),
+            State::Done(a) => a,
+        };
+        match Rc::try_unwrap(memo) {
+            Ok(_) => a,
+            Err(memo) => {
+                let mut memo = memo.borrow_mut();
+                let clone = memo.clone.expect("a shared Eval has been cloned");
+                let copy = clone(&a);
+                memo.state = State::Done(a);
+                copy
+            }
+        }
+    }
+
+    fn memo(state: State<A>) -> Eval<A> {
+        Eval {
+            repr: Repr::Memo(Rc::new(RefCell::new(Memo { state, clone: None }))),
+        }
+    }
+
+    fn into_step(self) -> Step {
+        match self.repr {
+            Repr::Always(f) => Step::Thunk(Box::new(move || Box::new(f()) as Value)),
+            Repr::Memo(memo) => match Rc::try_unwrap(memo) {
+                Ok(memo) => match memo.into_inner().state {
+                    State::Pending(step) => step,
+                    State::Running => unreachable!("only a shared Eval can be running"),
+                    State::Poisoned => Step::Thunk(Box::new(|| poisoned())),
+                    State::Done(a) => Step::Done(Box::new(a)),
+                },
+                Err(memo) => Step::Thunk(Box::new(move || {
+                    let shared = Eval {
+                        repr: Repr::Memo(memo),
+                    };
+                    Box::new(shared.value()) as Value
+                })),
+            },
+        }
+    }
+}
+
+impl<A: Clone + 'static> Clone for Eval<A> {
+    fn clone(&self) -> Self {
+        let repr = match self.repr {
+            Repr::Always(ref f) => Repr::Always(f.clone()),
+            Repr::Memo(ref memo) => {
+                memo.borrow_mut().clone = Some(A::clone);
+                Repr::Memo(memo.clone())
+            }
+        };
+        Eval { repr }
+    }
+}
+
+/// The kind of `Eval`.
+pub struct EvalKind;
+
+impl HKT for EvalKind {
+    type Apply<A> = Eval<A>;
+}
+
+impl Functor<'static> for EvalKind {
+    fn fmap<A, B, F>(fa: Eval<A>, f: F) -> Eval<B>
+    where
+        A: 'static,
+        B: 'static,
+        F: FnMut(A) -> B + 'static,
+    {
+        fa.map(f)
+    }
+}
+
+impl Semigroupal<'static> for EvalKind {
+    fn product<A, B>(fa: Eval<A>, fb: Eval<B>) -> Eval<(A, B)>
+    where
+        A: Clone + 'static,
+        B: Clone + 'static,
+    {
+        fa.flat_map(move |a| fb.map(move |b| (a, b)))
+    }
+}
+
+impl Apply<'static> for EvalKind {
+    fn map2<A, B, C, F>(fa: Eval<A>, fb: Eval<B>, mut f: F) -> Eval<C>
+    where
+        A: Clone + 'static,
+        B: Clone + 'static,
+        C: 'static,
+        F: FnMut(A, B) -> C + 'static,
+    {
+        fa.flat_map(move |a| fb.map(move |b| f(a, b)))
+    }
+}
+
+impl Applicative<'static> for EvalKind {
+    fn pure<A: 'static>(a: A) -> Eval<A> {
+        Eval::now(a)
+    }
+}
+
+impl FlatMap<'static> for EvalKind {
+    fn flat_map<A, B, F>(fa: Eval<A>, f: F) -> Eval<B>
+    where
+        A: 'static,
+        B: 'static,
+        F: FnMut(A) -> Eval<B> + 'static,
+    {
+        Eval::flat_map(fa, f)
+    }
+}
+
+impl Monad<'static> for EvalKind {}
+
+impl Defer<'static> for EvalKind {
+    fn defer<A, F>(f: F) -> Eval<A>
+    where
+        A: 'static,
+        F: FnOnce() -> Eval<A> + 'static,
+    {
+        Eval::defer(f)
+    }
+}
@@ -0,0 +1,248 @@
+use std::any::Any;
+use std::cell::RefCell;
+use std::marker::PhantomData;
+use std::rc::Rc;
+
+use part3::{FunctionK, Functor, Monad, HKT};
+
+use super::Either;
+
+/// The free monad over a functor `F`: a program made of `F`-shaped instructions, with nothing
+/// deciding yet what those instructions mean.
+///
+/// A program is either finished (`Pure`), or an instruction whose "rest of the program" is the
+/// functor's contents (`Suspend`). `flat_map` only appends to the program; running it is up to
+/// `fold_map`, which translates each instruction into some monad `M` with a natural
+/// transformation `F ~> M`. The same program can then be run against different interpreters.
+///
+/// A small key-value store language, with one interpreter which mutates a store on the side and
+/// returns `Identity`, and a pure one which threads the store through `State`:
+///
+/// ```rust
+/// # use std::cell::RefCell;
+/// # use std::collections::HashMap;
+/// # use rust_hkt::data::{Free, Identity, IdentityKind, State, StateKind};
+/// # use rust_hkt::part3::{FunctionK, Functor, HKT};
+/// enum KvStore<Next> {
+///     Put(String, i32, Next),
+///     Get(String, Box<dyn FnOnce(Option<i32>) -> Next>),
+/// }
+///
+/// struct KvStoreKind;
+///
+/// impl HKT for KvStoreKind {
+///     type Apply<A> = KvStore<A>;
+/// }
+///
+/// impl Functor<'static> for KvStoreKind {
+///     fn fmap<A, B, F>(fa: KvStore<A>, mut f: F) -> KvStore<B>
+///     where
+///         A: 'static,
+///         B: 'static,
+///         F: FnMut(A) -> B + 'static,
+///     {
+///         match fa {
+///             KvStore::Put(key, value, next) => KvStore::Put(key, value, f(next)),
+///             KvStore::Get(key, next) => KvStore::Get(key, Box::new(move |value| f(next(value)))),
+///         }
+///     }
+/// }
+///
+/// fn put(key: &str, value: i32) -> Free<KvStoreKind, ()> {
+///     Free::lift(KvStore::Put(key.to_string(), value, ()))
+/// }
+///
+/// fn get(key: &str) -> Free<KvStoreKind, Option<i32>> {
+///     Free::lift(KvStore::Get(key.to_string(), Box::new(|value| value)))
+/// }
+///
+/// let fruit = || {
+///     put("apples", 3)
+///         .flat_map(|()| put("pears", 4))
+///         .flat_map(|()| get("apples"))
+///         .flat_map(|apples| get("pears").map(move |pears| apples.unwrap_or(0) + pears.unwrap_or(0)))
+/// };
+///
+/// thread_local!(static STORE: RefCell<HashMap<String, i32>> = RefCell::new(HashMap::new()));
+///
+/// struct Impure;
+///
+/// impl FunctionK<'static, KvStoreKind, IdentityKind> for Impure {
+///     fn apply<A: 'static>(fa: KvStore<A>) -> Identity<A> {
+///         match fa {
+///             KvStore::Put(key, value, next) => {
+///                 STORE.with(|store| store.borrow_mut().insert(key, value));
+///                 Identity(next)
+///             }
+///             KvStore::Get(key, next) => Identity(next(STORE.with(|store| store.borrow().get(&key).cloned()))),
+///         }
+///     }
+/// }
+///
+/// assert_eq!(fruit().fold_map::<IdentityKind, Impure>(), Identity(7));
+/// assert_eq!(STORE.with(|store| store.borrow().len()), 2);
+///
+/// type Store = HashMap<String, i32>;
+///
+/// struct Pure;
+///
+/// impl FunctionK<'static, KvStoreKind, StateKind<Store>> for Pure {
+///     fn apply<A: 'static>(fa: KvStore<A>) -> State<Store, A> {
+///         match fa {
+///             KvStore::Put(key, value, next) => State::new(move |mut store: Store| {
+///                 store.insert(key, value);
+///                 (store, next)
+///             }),
+///             KvStore::Get(key, next) => State::new(move |store: Store| {
+///                 let value = store.get(&key).cloned();
+///                 (store, next(value))
+///             }),
+///         }
+///     }
+/// }
+///
+/// let (store, total) = fruit().fold_map::<StateKind<Store>, Pure>().run(HashMap::new());
+/// assert_eq!(total, 7);
+/// assert_eq!(store.get("pears"), Some(&4));
+/// ```
+///
+/// `flat_map` only records the step, and `fold_map` runs the program in a loop which
+/// reassociates nested `flat_map`s as it goes and hands each instruction to `M::tail_rec_m`. So
+/// neither a long chain of `flat_map`s nor a long program grows the stack, as long as `M`'s
+/// `tail_rec_m` doesn't. None of the monads in this crate do, `State` included:
+///
+/// ```rust
+/// # use rust_hkt::data::{Free, Identity, IdentityKind, State, StateKind};
+/// # use rust_hkt::part3::{FunctionK, IdentityK};
+/// let count = || {
+///     let mut count = Free::<IdentityKind, u64>::pure(0);
+///     for _ in 0..100_000 {
+///         count = count.flat_map(|n| Free::lift(Identity(n + 1)));
+///     }
+///     count
+/// };
+/// assert_eq!(count().fold_map::<IdentityKind, IdentityK>(), Identity(100_000));
+///
+/// // Counts the instructions as it runs them.
+/// struct Tick;
+///
+/// impl FunctionK<'static, IdentityKind, StateKind<u32>> for Tick {
+///     fn apply<A: 'static>(fa: Identity<A>) -> State<u32, A> {
+///         State::new(move |ticks| (ticks + 1, fa.0))
+///     }
+/// }
+///
+/// assert_eq!(count().fold_map::<StateKind<u32>, Tick>().run(0), (100_000, 100_000));
+/// ```
+///
+/// Like `Eval`, the steps are type-erased with `Any`, so a program and everything in it has to
+/// be `'static`.
+///
+/// There's no `Monad` instance for `Free` itself: the `Applicative` half would have to reuse the
+/// second program once for every way the first one can finish, and a program full of boxed
+/// continuations can't be cloned.
+pub struct Free<F: HKT, A> {
+    step: Step<F>,
+    marker: PhantomData<A>,
+}
+
+type Value = Box<dyn Any>;
+
+// `F` might hold more than one rest-of-the-program (think `Vec`), and each of them needs the
+// continuation, so it's shared.
+type Cont<F> = Rc<RefCell<dyn FnMut(Value) -> Step<F>>>;
+
+enum Step<F: HKT> {
+    Pure(Value),
+    Suspend(Box<F::Apply<Step<F>>>),
+    FlatMap(Box<Step<F>>, Cont<F>),
+}
+
+fn downcast<A: 'static>(value: Value) -> A {
+    *value
+        .downcast()
+        .unwrap_or_else(|_| panic!("Free step produced a value of the wrong type"))
+}
+
+impl<F, A> Free<F, A>
+where
+    F: Functor<'static> + 'static,
+    A: 'static,
+{
+    fn from_step(step: Step<F>) -> Free<F, A> {
+        Free {
+            step,
+            marker: PhantomData,
+        }
+    }
+
+    /// A program which does nothing and finishes with `a`.
+    pub fn pure(a: A) -> Free<F, A> {
+        Free::from_step(Step::Pure(Box::new(a)))
+    }
+
+    /// Turns a single instruction into a program which runs it and finishes with its result.
+    pub fn lift(fa: F::Apply<A>) -> Free<F, A> {
+        let next = F::fmap(fa, |a: A| Step::Pure(Box::new(a) as Value));
+        Free::from_step(Step::Suspend(Box::new(next)))
+    }
+
+    pub fn flat_map<B, K>(self, mut f: K) -> Free<F, B>
+    where
+        B: 'static,
+        K: FnMut(A) -> Free<F, B> + 'static,
+    {
+        let k: Cont<F> = Rc::new(RefCell::new(move |a: Value| f(downcast(a)).step));
+        Free::from_step(Step::FlatMap(Box::new(self.step), k))
+    }
+
+    pub fn map<B, K>(self, mut f: K) -> Free<F, B>
+    where
+        B: 'static,
+        K: FnMut(A) -> B + 'static,
+    {
+        self.flat_map(move |a| Free::pure(f(a)))
+    }
+
+    /// Runs the program in `M`, translating each instruction with `N`.
+    pub fn fold_map<M, N>(self) -> M::Apply<A>
+    where
+        M: Monad<'static>,
+        N: FunctionK<'static, F, M>,
+    {
+        let done = M::tail_rec_m(self.step, |step: Step<F>| match resume(step) {
+            Either::Left(next) => M::fmap(N::apply(next), Either::Left),
+            Either::Right(value) => M::pure(Either::Right(value)),
+        });
+        M::fmap(done, downcast::<A>)
+    }
+}
+
+/// Runs the program up to its next instruction, or to its end.
+fn resume<F>(mut step: Step<F>) -> Either<F::Apply<Step<F>>, Value>
+where
+    F: Functor<'static> + 'static,
+{
+    loop {
+        step = match step {
+            Step::Pure(value) => return Either::Right(value),
+            Step::Suspend(next) => return Either::Left(*next),
+            Step::FlatMap(inner, k) => match *inner {
+                Step::Pure(value) => (k.borrow_mut())(value),
+                Step::Suspend(next) => {
+                    return Either::Left(F::fmap(*next, move |step: Step<F>| {
+                        Step::FlatMap(Box::new(step), k.clone())
+                    }));
+                }
+                // `(m >>= g) >>= k` is `m >>= (g >=> k)`, which keeps the loop going without
+                // recursing into `m >>= g` first.
+                Step::FlatMap(m, g) => {
+                    let gk: Cont<F> = Rc::new(RefCell::new(move |value: Value| {
+                        Step::FlatMap(Box::new((g.borrow_mut())(value)), k.clone())
+                    }));
+                    Step::FlatMap(m, gk)
+                }
+            },
+        }
+    }
+}
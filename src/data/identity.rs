@@ -0,0 +1,197 @@
+use kernel::{Monoid, Semigroup};
+use part3::{
+    Applicative, Apply, CommutativeApplicative, Comonad, FlatMap, FlatMapOnce, Foldable, Functor,
+    Monad, Reducible, Representable, Semigroupal, Traverse, HKT,
+};
+
+use super::Either;
+
+/// The simplest possible type constructor, which just wraps a value.
+///
+/// `Identity` doesn't add any effect at all, which makes it the base case for monad transformers,
+/// and a handy way to run effectful code in a pure setting, e.g. to get `fmap` out of `traverse`:
+///
+/// ```rust
+/// # use rust_hkt::data::{Identity, IdentityKind};
+/// # use rust_hkt::part3::{Comonad, FlatMap, Traverse, VecKind};
+/// let doubled = VecKind::traverse::<IdentityKind, _, _, _>(vec![1, 2], |i| Identity(i * 2));
+/// assert_eq!(doubled, Identity(vec![2, 4]));
+///
+/// let sum = IdentityKind::flat_map(Identity(1), |i| Identity(i + 1));
+/// assert_eq!(IdentityKind::extract(sum), 2);
+///
+/// let described = IdentityKind::coflat_map(Identity(1), |Identity(i)| format!("Identity({})", i));
+/// assert_eq!(described, Identity("Identity(1)".to_string()));
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Identity<A>(pub A);
+
+impl<A> Identity<A> {
+    pub fn into_inner(self) -> A {
+        self.0
+    }
+}
+
+/// The kind of `Identity`.
+pub struct IdentityKind;
+
+impl HKT for IdentityKind {
+    type Apply<A> = Identity<A>;
+}
+
+impl<'a> Functor<'a> for IdentityKind {
+    fn fmap<A, B, F>(fa: Identity<A>, mut f: F) -> Identity<B>
+    where
+        F: FnMut(A) -> B,
+    {
+        Identity(f(fa.0))
+    }
+}
+
+impl<'a> Semigroupal<'a> for IdentityKind {
+    fn product<A, B>(fa: Identity<A>, fb: Identity<B>) -> Identity<(A, B)> {
+        Identity((fa.0, fb.0))
+    }
+}
+
+impl<'a> Apply<'a> for IdentityKind {
+    fn map2<A, B, C, F>(fa: Identity<A>, fb: Identity<B>, mut f: F) -> Identity<C>
+    where
+        F: FnMut(A, B) -> C,
+    {
+        Identity(f(fa.0, fb.0))
+    }
+}
+
+impl<'a> Applicative<'a> for IdentityKind {
+    fn pure<A>(a: A) -> Identity<A> {
+        Identity(a)
+    }
+}
+
+impl<'a> CommutativeApplicative<'a> for IdentityKind {}
+
+impl<'a> FlatMap<'a> for IdentityKind {
+    fn flat_map<A, B, F>(fa: Identity<A>, mut f: F) -> Identity<B>
+    where
+        F: FnMut(A) -> Identity<B>,
+    {
+        f(fa.0)
+    }
+}
+
+impl<'a> FlatMapOnce<'a> for IdentityKind {
+    fn flat_map_once<A, B, F>(fa: Identity<A>, f: F) -> Identity<B>
+    where
+        F: FnOnce(A) -> Identity<B>,
+    {
+        f(fa.0)
+    }
+}
+
+impl<'a> Monad<'a> for IdentityKind {
+    fn tail_rec_m<A, B, F>(mut a: A, mut f: F) -> Identity<B>
+    where
+        F: FnMut(A) -> Identity<Either<A, B>>,
+    {
+        loop {
+            match f(a).0 {
+                Either::Left(next) => a = next,
+                Either::Right(b) => return Identity(b),
+            }
+        }
+    }
+}
+
+impl<'a> Comonad<'a> for IdentityKind {
+    fn extract<A>(wa: Identity<A>) -> A {
+        wa.0
+    }
+
+    fn coflat_map<A, B, F>(wa: Identity<A>, f: F) -> Identity<B>
+    where
+        F: Fn(Identity<A>) -> B,
+    {
+        Identity(f(wa))
+    }
+}
+
+impl Foldable for IdentityKind {
+    fn fold_left<A, B, F>(fa: Identity<A>, init: B, mut f: F) -> B
+    where
+        F: FnMut(B, A) -> B,
+    {
+        f(init, fa.0)
+    }
+
+    fn fold_right<A, B, F>(fa: Identity<A>, init: B, mut f: F) -> B
+    where
+        F: FnMut(A, B) -> B,
+    {
+        f(fa.0, init)
+    }
+
+    fn fold_map<A, M, F>(fa: Identity<A>, mut f: F) -> M
+    where
+        M: Monoid,
+        F: FnMut(A) -> M,
+    {
+        f(fa.0)
+    }
+}
+
+impl Reducible for IdentityKind {
+    fn reduce_left_to<A, B, F, G>(fa: Identity<A>, f: F, _: G) -> B
+    where
+        F: FnOnce(A) -> B,
+        G: FnMut(B, A) -> B,
+    {
+        f(fa.0)
+    }
+
+    fn reduce_right_to<A, B, F, G>(fa: Identity<A>, f: F, _: G) -> B
+    where
+        F: FnOnce(A) -> B,
+        G: FnMut(A, B) -> B,
+    {
+        f(fa.0)
+    }
+
+    fn reduce_map<A, S, F>(fa: Identity<A>, mut f: F) -> S
+    where
+        S: Semigroup,
+        F: FnMut(A) -> S,
+    {
+        f(fa.0)
+    }
+}
+
+impl<'a> Traverse<'a> for IdentityKind {
+    fn traverse<G, A, B, F>(fa: Identity<A>, mut f: F) -> G::Apply<Identity<B>>
+    where
+        G: Applicative<'a>,
+        B: 'a,
+        F: FnMut(A) -> G::Apply<B>,
+    {
+        G::fmap(f(fa.0), Identity)
+    }
+}
+
+impl<'a> Representable<'a> for IdentityKind {
+    type Rep = ();
+
+    fn tabulate<A, F>(mut f: F) -> Identity<A>
+    where
+        F: FnMut(()) -> A,
+    {
+        Identity(f(()))
+    }
+
+    fn index<A>(fa: Identity<A>, _: ()) -> A {
+        fa.0
+    }
+
+    fn index_ref<A: Clone>(fa: &Identity<A>, _: ()) -> A {
+        fa.0.clone()
+    }
+}
@@ -0,0 +1,282 @@
+use std::panic::{self, AssertUnwindSafe};
+
+use part3::{
+    Applicative, Apply, Bracket, Defer, FlatMap, FlatMapOnce, Functor, Monad, Parallel, Selective,
+    Semigroupal, HKT,
+};
+
+use super::{Either, Eval};
+
+/// A description of a side effect, which doesn't happen until the `IO` is `run`.
+///
+/// Building an `IO` is pure: nothing is printed, written or read until `run` is called, at which
+/// point everything chained together with `flat_map` happens in order. It's built on `Eval`, so
+/// long chains don't overflow the stack either.
+///
+/// ```rust
+/// # use std::cell::RefCell;
+/// # use std::rc::Rc;
+/// # use rust_hkt::data::{IO, IOKind};
+/// # use rust_hkt::part3::FlatMap;
+/// let log = Rc::new(RefCell::new(Vec::new()));
+/// let say = |log: &Rc<RefCell<Vec<String>>>, s: &str| {
+///     let (log, s) = (log.clone(), s.to_string());
+///     IO::delay(move || log.borrow_mut().push(s))
+/// };
+///
+/// let program = IOKind::flat_map(say(&log, "hello"), {
+///     let log = log.clone();
+///     move |()| say(&log, "world")
+/// });
+/// assert!(log.borrow().is_empty());
+///
+/// program.run();
+/// assert_eq!(*log.borrow(), vec!["hello", "world"]);
+/// ```
+pub struct IO<A> {
+    eval: Eval<A>,
+}
+
+impl<A: 'static> IO<A> {
+    pub fn pure(a: A) -> IO<A> {
+        IO { eval: Eval::now(a) }
+    }
+
+    /// Suspends a side effect.
+    pub fn delay<F>(f: F) -> IO<A>
+    where
+        F: FnOnce() -> A + 'static,
+    {
+        IO {
+            eval: Eval::later(f),
+        }
+    }
+
+    pub fn flat_map<B, F>(self, f: F) -> IO<B>
+    where
+        B: 'static,
+        F: FnOnce(A) -> IO<B> + 'static,
+    {
+        IO {
+            eval: self.eval.flat_map(move |a| f(a).eval),
+        }
+    }
+
+    pub fn map<B, F>(self, f: F) -> IO<B>
+    where
+        B: 'static,
+        F: FnOnce(A) -> B + 'static,
+    {
+        IO {
+            eval: self.eval.map(f),
+        }
+    }
+
+    /// Performs the side effects, in order, and returns the result.
+    pub fn run(self) -> A {
+        self.eval.value()
+    }
+}
+
+/// The kind of `IO`.
+pub struct IOKind;
+
+impl HKT for IOKind {
+    type Apply<A> = IO<A>;
+}
+
+impl Functor<'static> for IOKind {
+    fn fmap<A, B, F>(fa: IO<A>, f: F) -> IO<B>
+    where
+        A: 'static,
+        B: 'static,
+        F: FnMut(A) -> B + 'static,
+    {
+        fa.map(f)
+    }
+}
+
+impl Semigroupal<'static> for IOKind {
+    fn product<A, B>(fa: IO<A>, fb: IO<B>) -> IO<(A, B)>
+    where
+        A: Clone + 'static,
+        B: Clone + 'static,
+    {
+        fa.flat_map(move |a| fb.map(move |b| (a, b)))
+    }
+}
+
+impl Apply<'static> for IOKind {
+    fn map2<A, B, C, F>(fa: IO<A>, fb: IO<B>, mut f: F) -> IO<C>
+    where
+        A: Clone + 'static,
+        B: Clone + 'static,
+        C: 'static,
+        F: FnMut(A, B) -> C + 'static,
+    {
+        fa.flat_map(move |a| fb.map(move |b| f(a, b)))
+    }
+}
+
+impl Applicative<'static> for IOKind {
+    fn pure<A: 'static>(a: A) -> IO<A> {
+        IO::pure(a)
+    }
+}
+
+impl FlatMap<'static> for IOKind {
+    fn flat_map<A, B, F>(fa: IO<A>, f: F) -> IO<B>
+    where
+        A: 'static,
+        B: 'static,
+        F: FnMut(A) -> IO<B> + 'static,
+    {
+        IO::flat_map(fa, f)
+    }
+}
+
+impl FlatMapOnce<'static> for IOKind {
+    fn flat_map_once<A, B, F>(fa: IO<A>, f: F) -> IO<B>
+    where
+        A: 'static,
+        B: 'static,
+        F: FnOnce(A) -> IO<B> + 'static,
+    {
+        IO::flat_map(fa, f)
+    }
+}
+
+impl Monad<'static> for IOKind {}
+
+impl Defer<'static> for IOKind {
+    fn defer<A, F>(f: F) -> IO<A>
+    where
+        A: 'static,
+        F: FnOnce() -> IO<A> + 'static,
+    {
+        IO {
+            eval: Eval::defer(move || f().eval),
+        }
+    }
+}
+
+/// An `IO` which can only be combined with `Applicative`, the `Par` side of `IOKind`'s `Parallel`.
+///
+/// Despite `Parallel`, nothing here runs concurrently. An `IO` is built on `Eval`, which isn't
+/// `Send`, so an `ApIO` can't hand its steps to other threads: they run one after the other, in
+/// order, on the thread that calls `run`, and `par_map2` does exactly what `map2` does. The
+/// instance is here so that code written against `Parallel` works with `IO` as it does with
+/// `Result`:
+///
+/// ```rust
+/// # use std::cell::RefCell;
+/// # use std::rc::Rc;
+/// # use rust_hkt::data::{IO, IOKind};
+/// # use rust_hkt::part3::{Parallel, VecKind};
+/// let order = Rc::new(RefCell::new(Vec::new()));
+/// let reads = (1..=3)
+///     .map(|i| {
+///         let order = order.clone();
+///         IO::delay(move || {
+///             order.borrow_mut().push(i);
+///             i * 10
+///         })
+///     })
+///     .collect();
+/// assert_eq!(IOKind::par_sequence::<VecKind, _>(reads).run(), vec![10, 20, 30]);
+/// assert_eq!(*order.borrow(), vec![1, 2, 3]);
+/// ```
+pub struct ApIO<A> {
+    io: IO<A>,
+}
+
+/// The kind of `ApIO`.
+pub struct ApIOKind;
+
+impl HKT for ApIOKind {
+    type Apply<A> = ApIO<A>;
+}
+
+impl Functor<'static> for ApIOKind {
+    fn fmap<A, B, F>(fa: ApIO<A>, f: F) -> ApIO<B>
+    where
+        A: 'static,
+        B: 'static,
+        F: FnMut(A) -> B + 'static,
+    {
+        ApIO { io: fa.io.map(f) }
+    }
+}
+
+impl Semigroupal<'static> for ApIOKind {
+    fn product<A, B>(fa: ApIO<A>, fb: ApIO<B>) -> ApIO<(A, B)>
+    where
+        A: Clone + 'static,
+        B: Clone + 'static,
+    {
+        ApIO {
+            io: IOKind::product(fa.io, fb.io),
+        }
+    }
+}
+
+impl Apply<'static> for ApIOKind {
+    fn map2<A, B, C, F>(fa: ApIO<A>, fb: ApIO<B>, f: F) -> ApIO<C>
+    where
+        A: Clone + 'static,
+        B: Clone + 'static,
+        C: 'static,
+        F: FnMut(A, B) -> C + 'static,
+    {
+        ApIO {
+            io: IOKind::map2(fa.io, fb.io, f),
+        }
+    }
+}
+
+impl Applicative<'static> for ApIOKind {
+    fn pure<A: 'static>(a: A) -> ApIO<A> {
+        ApIO { io: IO::pure(a) }
+    }
+}
+
+impl Parallel<'static> for IOKind {
+    type Par = ApIOKind;
+
+    fn parallel<A>(ma: IO<A>) -> ApIO<A> {
+        ApIO { io: ma }
+    }
+
+    fn sequential<A>(fa: ApIO<A>) -> IO<A> {
+        fa.io
+    }
+}
+
+/// Runs the finalizer even if `fa` panics, then carries on panicking.
+impl Bracket<'static> for IOKind {
+    fn guarantee<A: 'static>(fa: IO<A>, finalizer: IO<()>) -> IO<A> {
+        IO::delay(move || {
+            let result = panic::catch_unwind(AssertUnwindSafe(move || fa.run()));
+            finalizer.run();
+            match result {
+                Ok(a) => a,
+                Err(payload) => panic::resume_unwind(payload),
+            }
+        })
+    }
+}
+
+/// Only runs the function's effect for a `Left`.
+impl Selective<'static> for IOKind {
+    fn select<A, B, F>(fab: IO<Either<A, B>>, ff: IO<F>) -> IO<B>
+    where
+        A: Clone + 'static,
+        B: Clone + 'static,
+        F: FnOnce(A) -> B + Clone + 'static,
+    {
+        fab.flat_map(move |ab| match ab {
+            Either::Left(a) => ff.map(move |f| f(a)),
+            Either::Right(b) => IO::pure(b),
+        })
+    }
+}
@@ -0,0 +1,140 @@
+use kernel::Semigroup;
+use part3::{Applicative, Bifoldable, Bifunctor, Bitraverse, HKT2};
+
+/// An inclusive or: a left value, a right value, or both.
+///
+/// Where `Either` is one side or the other, `Ior` can also be both at once. That's the shape of
+/// zipping two things which may not line up, like two lists of different lengths or two maps
+/// with different keys, and it's what `part3::Align` produces.
+///
+/// ```rust
+/// # use rust_hkt::data::Ior;
+/// assert_eq!(Ior::<i32, &str>::Left(1).pad(), (Some(1), None));
+/// assert_eq!(Ior::Both(1, "a").pad(), (Some(1), Some("a")));
+/// assert_eq!(Ior::<i32, &str>::Right("a").swap(), Ior::Left("a"));
+///
+/// assert_eq!(Ior::Both(vec![1], vec![2]).merge(), vec![1, 2]);
+/// assert_eq!(Ior::<i32, i32>::Right(2).merge_with(|a, b| a * b), 2);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Ior<A, B> {
+    Left(A),
+    Right(B),
+    Both(A, B),
+}
+
+impl<A, B> Ior<A, B> {
+    pub fn swap(self) -> Ior<B, A> {
+        match self {
+            Ior::Left(a) => Ior::Right(a),
+            Ior::Right(b) => Ior::Left(b),
+            Ior::Both(a, b) => Ior::Both(b, a),
+        }
+    }
+
+    pub fn is_left(&self) -> bool {
+        matches!(*self, Ior::Left(_))
+    }
+
+    pub fn is_right(&self) -> bool {
+        matches!(*self, Ior::Right(_))
+    }
+
+    pub fn is_both(&self) -> bool {
+        matches!(*self, Ior::Both(_, _))
+    }
+
+    pub fn left(self) -> Option<A> {
+        self.pad().0
+    }
+
+    pub fn right(self) -> Option<B> {
+        self.pad().1
+    }
+
+    /// Both sides as options, padding whichever is missing with `None`.
+    pub fn pad(self) -> (Option<A>, Option<B>) {
+        match self {
+            Ior::Left(a) => (Some(a), None),
+            Ior::Right(b) => (None, Some(b)),
+            Ior::Both(a, b) => (Some(a), Some(b)),
+        }
+    }
+}
+
+impl<A> Ior<A, A> {
+    /// Extracts the value, combining the two with `f` if there are both.
+    pub fn merge_with<F>(self, f: F) -> A
+    where
+        F: FnOnce(A, A) -> A,
+    {
+        match self {
+            Ior::Left(a) | Ior::Right(a) => a,
+            Ior::Both(a, b) => f(a, b),
+        }
+    }
+
+    /// Extracts the value, combining the two with `Semigroup` if there are both.
+    pub fn merge(self) -> A
+    where
+        A: Semigroup,
+    {
+        self.merge_with(Semigroup::combine)
+    }
+}
+
+/// The kind of `Ior`, with both type parameters free.
+pub struct IorKind2;
+
+impl HKT2 for IorKind2 {
+    type Apply2<A, B> = Ior<A, B>;
+}
+
+impl<'a> Bifunctor<'a> for IorKind2 {
+    fn bimap<A, B, C, D, F, G>(fab: Ior<A, B>, mut f: F, mut g: G) -> Ior<C, D>
+    where
+        F: FnMut(A) -> C,
+        G: FnMut(B) -> D,
+    {
+        match fab {
+            Ior::Left(a) => Ior::Left(f(a)),
+            Ior::Right(b) => Ior::Right(g(b)),
+            Ior::Both(a, b) => Ior::Both(f(a), g(b)),
+        }
+    }
+}
+
+/// Folds the left value before the right one when there are both.
+impl Bifoldable for IorKind2 {
+    fn bifold_left<A, B, C, F, G>(fab: Ior<A, B>, init: C, mut f: F, mut g: G) -> C
+    where
+        F: FnMut(C, A) -> C,
+        G: FnMut(C, B) -> C,
+    {
+        match fab {
+            Ior::Left(a) => f(init, a),
+            Ior::Right(b) => g(init, b),
+            Ior::Both(a, b) => {
+                let acc = f(init, a);
+                g(acc, b)
+            }
+        }
+    }
+}
+
+impl<'a> Bitraverse<'a> for IorKind2 {
+    fn bitraverse<G, A, B, C, D, F, H>(fab: Ior<A, B>, mut f: F, mut g: H) -> G::Apply<Ior<C, D>>
+    where
+        G: Applicative<'a>,
+        C: Clone + 'a,
+        D: Clone + 'a,
+        F: FnMut(A) -> G::Apply<C>,
+        H: FnMut(B) -> G::Apply<D>,
+    {
+        match fab {
+            Ior::Left(a) => G::fmap(f(a), Ior::Left),
+            Ior::Right(b) => G::fmap(g(b), Ior::Right),
+            Ior::Both(a, b) => G::map2(f(a), g(b), Ior::Both),
+        }
+    }
+}
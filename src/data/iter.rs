@@ -0,0 +1,105 @@
+use std::marker::PhantomData;
+
+use part3::{Foldable, Functor, FunctorFilter, HKT};
+
+/// A lazy sequence: any iterator, boxed so that its type only depends on its items.
+///
+/// `Vec`'s `fmap` collects into a new `Vec` every time. `Iter`'s only adds an adapter, so a
+/// chain of `fmap`s and `map_filter`s does nothing until the result is folded or iterated, and
+/// then runs in a single pass, without any intermediate collections:
+///
+/// ```rust
+/// # use std::cell::Cell;
+/// # use rust_hkt::data::{Iter, IterKind};
+/// # use rust_hkt::part3::{Foldable, Functor, FunctorFilter};
+/// let calls = Cell::new(0);
+/// let squares = IterKind::fmap(Iter::new(1..), |i: u64| {
+///     calls.set(calls.get() + 1);
+///     i * i
+/// });
+/// let odd_squares = IterKind::filter(squares, |i| i % 2 == 1);
+/// assert_eq!(calls.get(), 0);
+///
+/// assert_eq!(odd_squares.take(3).collect::<Vec<_>>(), vec![1, 9, 25]);
+/// assert_eq!(calls.get(), 5);
+///
+/// assert_eq!(IterKind::fold_left(Iter::new(vec!["a", "b"]), String::new(), |acc, s| acc + s), "ab");
+/// ```
+///
+/// The iterator can't be kept unboxed, as `Map<I, F>`, since then every `fmap` would produce a
+/// different type, and `Functor` needs `F<B>` to be the same constructor as `F<A>`. The price is
+/// one dynamic call per element per adapter.
+pub struct Iter<'a, A> {
+    iter: Box<dyn Iterator<Item = A> + 'a>,
+}
+
+impl<'a, A> Iter<'a, A> {
+    pub fn new<I>(iter: I) -> Iter<'a, A>
+    where
+        I: IntoIterator<Item = A>,
+        I::IntoIter: 'a,
+    {
+        Iter {
+            iter: Box::new(iter.into_iter()),
+        }
+    }
+}
+
+impl<'a, A> Iterator for Iter<'a, A> {
+    type Item = A;
+
+    fn next(&mut self) -> Option<A> {
+        self.iter.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+/// The kind of `Iter<'a, _>`, for iterators which live for `'a`.
+pub struct IterKind<'a>(PhantomData<&'a ()>);
+
+impl<'a> HKT for IterKind<'a> {
+    type Apply<A> = Iter<'a, A>;
+}
+
+impl<'a> Functor<'a> for IterKind<'a> {
+    fn fmap<A, B, F>(fa: Iter<'a, A>, f: F) -> Iter<'a, B>
+    where
+        A: 'a,
+        B: 'a,
+        F: FnMut(A) -> B + 'a,
+    {
+        Iter::new(fa.map(f))
+    }
+}
+
+impl<'a> FunctorFilter<'a> for IterKind<'a> {
+    fn map_filter<A, B, F>(fa: Iter<'a, A>, f: F) -> Iter<'a, B>
+    where
+        A: 'a,
+        B: 'a,
+        F: FnMut(A) -> Option<B> + 'a,
+    {
+        Iter::new(fa.filter_map(f))
+    }
+}
+
+impl<'a> Foldable for IterKind<'a> {
+    fn fold_left<A, B, F>(fa: Iter<'a, A>, init: B, f: F) -> B
+    where
+        F: FnMut(B, A) -> B,
+    {
+        fa.fold(init, f)
+    }
+
+    /// Collects the elements first, since a boxed iterator can't be run backwards.
+    fn fold_right<A, B, F>(fa: Iter<'a, A>, init: B, mut f: F) -> B
+    where
+        F: FnMut(A, B) -> B,
+    {
+        let all: Vec<A> = fa.collect();
+        all.into_iter().rev().fold(init, |acc, a| f(a, acc))
+    }
+}
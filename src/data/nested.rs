@@ -0,0 +1,130 @@
+use std::cell::RefCell;
+use std::fmt;
+use std::marker::PhantomData;
+use std::rc::Rc;
+
+use part3::{Applicative, Apply, Functor, HKT};
+
+/// Two type constructors composed into one: an `F<G<A>>`, seen as a single context around `A`.
+///
+/// Functors compose: if `F` and `G` are both functors, so is `F<G<_>>`, and `fmap` on the
+/// composition reaches straight through both layers:
+///
+/// ```rust
+/// # use rust_hkt::data::{Nested, NestedKind};
+/// # use rust_hkt::part3::{Functor, OptionKind, VecKind};
+/// let nested = Nested::<VecKind, OptionKind, _>::new(vec![Some(1), None, Some(3)]);
+/// let doubled = NestedKind::fmap(nested, |i| i * 2);
+/// assert_eq!(doubled.value(), vec![Some(2), None, Some(6)]);
+/// ```
+///
+/// So do applicatives, but only through `Nested::pure` and `Nested::map2`. `F`'s `map2` may
+/// need to reuse a `G<A>`, so the composition needs `G<A>: Clone`, and there's no way for
+/// `NestedKind`'s `Applicative` instance to demand that for every `A`. Every `G` shares the
+/// function, so `map2` takes an `Fn` rather than an `FnMut`:
+///
+/// ```rust
+/// # use rust_hkt::data::Nested;
+/// # use rust_hkt::part3::{OptionKind, VecKind};
+/// let xs = Nested::<VecKind, OptionKind, _>::new(vec![Some(1), Some(2)]);
+/// let ys = Nested::new(vec![Some(10), None]);
+/// assert_eq!(xs.map2(ys, |x, y| x + y).value(), vec![Some(11), None, Some(12), None]);
+///
+/// assert_eq!(Nested::<OptionKind, VecKind, _>::pure(1).value(), Some(vec![1]));
+/// ```
+pub struct Nested<F: HKT, G: HKT, A> {
+    value: F::Apply<G::Apply<A>>,
+}
+
+impl<F: HKT, G: HKT, A> Nested<F, G, A> {
+    pub fn new(value: F::Apply<G::Apply<A>>) -> Nested<F, G, A> {
+        Nested { value }
+    }
+
+    pub fn value(self) -> F::Apply<G::Apply<A>> {
+        self.value
+    }
+
+    pub fn pure<'a>(a: A) -> Nested<F, G, A>
+    where
+        F: Applicative<'a>,
+        G: Applicative<'a>,
+        A: 'a,
+        G::Apply<A>: 'a,
+    {
+        Nested::new(F::pure(G::pure(a)))
+    }
+
+    pub fn map2<'a, B, C, K>(self, fb: Nested<F, G, B>, f: K) -> Nested<F, G, C>
+    where
+        F: Apply<'a>,
+        G: Apply<'a>,
+        A: Clone + 'a,
+        B: Clone + 'a,
+        C: 'a,
+        G::Apply<A>: Clone + 'a,
+        G::Apply<B>: Clone + 'a,
+        G::Apply<C>: 'a,
+        K: Fn(A, B) -> C + 'a,
+    {
+        let f = Rc::new(f);
+        Nested::new(F::map2(self.value, fb.value, move |ga, gb| {
+            let f = f.clone();
+            G::map2(ga, gb, move |a, b| f(a, b))
+        }))
+    }
+}
+
+impl<F: HKT, G: HKT, A> Clone for Nested<F, G, A>
+where
+    F::Apply<G::Apply<A>>: Clone,
+{
+    fn clone(&self) -> Nested<F, G, A> {
+        Nested::new(self.value.clone())
+    }
+}
+
+impl<F: HKT, G: HKT, A> fmt::Debug for Nested<F, G, A>
+where
+    F::Apply<G::Apply<A>>: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("Nested").field(&self.value).finish()
+    }
+}
+
+impl<F: HKT, G: HKT, A> PartialEq for Nested<F, G, A>
+where
+    F::Apply<G::Apply<A>>: PartialEq,
+{
+    fn eq(&self, other: &Nested<F, G, A>) -> bool {
+        self.value == other.value
+    }
+}
+
+/// The kind of `Nested<F, G, _>`.
+pub struct NestedKind<F, G>(PhantomData<(F, G)>);
+
+impl<F: HKT, G: HKT> HKT for NestedKind<F, G> {
+    type Apply<A> = Nested<F, G, A>;
+}
+
+impl<'a, F, G> Functor<'a> for NestedKind<F, G>
+where
+    F: Functor<'a>,
+    G: Functor<'a> + 'a,
+{
+    fn fmap<A, B, K>(fa: Nested<F, G, A>, f: K) -> Nested<F, G, B>
+    where
+        A: 'a,
+        B: 'a,
+        K: FnMut(A) -> B + 'a,
+    {
+        // Every `G<A>` inside `F` needs the same `f`, and `Functor` hands over an `FnMut`.
+        let f = Rc::new(RefCell::new(f));
+        Nested::new(F::fmap(fa.value, move |ga| {
+            let f = f.clone();
+            G::fmap(ga, move |a| (f.borrow_mut())(a))
+        }))
+    }
+}
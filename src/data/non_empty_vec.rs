@@ -0,0 +1,242 @@
+use std::iter::{once, Chain, Once};
+use std::vec;
+
+use kernel::Semigroup;
+use part3::{
+    Applicative, Apply, Comonad, Foldable, Functor, FunctorWithIndex, NonEmptyTraverse, Reducible,
+    Traverse, TraverseWithIndex, HKT,
+};
+
+/// A `Vec` with at least one element.
+///
+/// ```rust
+/// # use rust_hkt::data::NonEmptyVec;
+/// # use rust_hkt::kernel::Semigroup;
+/// let nev = NonEmptyVec::new(1, vec![2, 3]);
+/// assert_eq!(nev.head, 1);
+/// assert_eq!(nev.len(), 3);
+/// assert_eq!(nev.combine(NonEmptyVec::of(4)).into_vec(), vec![1, 2, 3, 4]);
+///
+/// assert_eq!(NonEmptyVec::from_vec(Vec::<i32>::new()), None);
+/// assert_eq!(NonEmptyVec::from_vec(vec![1]), Some(NonEmptyVec::of(1)));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NonEmptyVec<A> {
+    pub head: A,
+    pub tail: Vec<A>,
+}
+
+impl<A> NonEmptyVec<A> {
+    pub fn new(head: A, tail: Vec<A>) -> NonEmptyVec<A> {
+        NonEmptyVec { head, tail }
+    }
+
+    /// A `NonEmptyVec` holding just `head`.
+    pub fn of(head: A) -> NonEmptyVec<A> {
+        NonEmptyVec::new(head, Vec::new())
+    }
+
+    /// Returns `None` if `vec` is empty.
+    pub fn from_vec(vec: Vec<A>) -> Option<NonEmptyVec<A>> {
+        let mut iter = vec.into_iter();
+        iter.next().map(|head| NonEmptyVec::new(head, iter.collect()))
+    }
+
+    pub fn len(&self) -> usize {
+        self.tail.len() + 1
+    }
+
+    /// Always `false`, a `NonEmptyVec` can't be empty.
+    pub fn is_empty(&self) -> bool {
+        false
+    }
+
+    pub fn push(&mut self, a: A) {
+        self.tail.push(a);
+    }
+
+    pub fn iter(&self) -> Chain<Once<&A>, ::std::slice::Iter<'_, A>> {
+        once(&self.head).chain(self.tail.iter())
+    }
+
+    pub fn into_vec(self) -> Vec<A> {
+        let mut vec = Vec::with_capacity(self.len());
+        vec.push(self.head);
+        vec.extend(self.tail);
+        vec
+    }
+}
+
+impl<A> IntoIterator for NonEmptyVec<A> {
+    type Item = A;
+    type IntoIter = Chain<Once<A>, vec::IntoIter<A>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        once(self.head).chain(self.tail)
+    }
+}
+
+impl<A> From<NonEmptyVec<A>> for Vec<A> {
+    fn from(nev: NonEmptyVec<A>) -> Vec<A> {
+        nev.into_vec()
+    }
+}
+
+impl<A> Semigroup for NonEmptyVec<A> {
+    fn combine(mut self, other: NonEmptyVec<A>) -> NonEmptyVec<A> {
+        self.tail.extend(other);
+        self
+    }
+}
+
+/// The kind of `NonEmptyVec`.
+///
+/// A `NonEmptyVec` always has a head to `extract`, which makes it a `Comonad`. `coflat_map`
+/// hands `f` every suffix in turn, the vector seen from each of its positions, which is all a
+/// sliding window needs:
+///
+/// ```rust
+/// # use rust_hkt::data::{NonEmptyVec, NonEmptyVecKind};
+/// # use rust_hkt::part3::{Comonad, Functor};
+/// let prices = NonEmptyVec::new(1.0, vec![2.0, 6.0, 4.0]);
+/// let moving_average = NonEmptyVecKind::coflat_map(prices, |window| {
+///     let window: Vec<f64> = window.into_iter().take(2).collect();
+///     window.iter().sum::<f64>() / window.len() as f64
+/// });
+/// assert_eq!(moving_average, NonEmptyVec::new(1.5, vec![4.0, 5.0, 4.0]));
+///
+/// assert_eq!(NonEmptyVecKind::extract(NonEmptyVecKind::fmap(NonEmptyVec::of(1), |i| i + 1)), 2);
+/// ```
+pub struct NonEmptyVecKind;
+
+impl HKT for NonEmptyVecKind {
+    type Apply<A> = NonEmptyVec<A>;
+}
+
+impl<'a> Functor<'a> for NonEmptyVecKind {
+    fn fmap<A, B, F>(fa: NonEmptyVec<A>, mut f: F) -> NonEmptyVec<B>
+    where
+        F: FnMut(A) -> B,
+    {
+        let head = f(fa.head);
+        NonEmptyVec::new(head, fa.tail.into_iter().map(f).collect())
+    }
+}
+
+/// The head is at index 0.
+impl<'a> FunctorWithIndex<'a> for NonEmptyVecKind {
+    type Index = usize;
+
+    fn map_with_index<A, B, F>(fa: NonEmptyVec<A>, mut f: F) -> NonEmptyVec<B>
+    where
+        F: FnMut(usize, A) -> B,
+    {
+        let head = f(0, fa.head);
+        let tail = fa.tail.into_iter().enumerate().map(|(i, a)| f(i + 1, a));
+        NonEmptyVec::new(head, tail.collect())
+    }
+}
+
+impl<'a> Comonad<'a> for NonEmptyVecKind {
+    fn extract<A>(wa: NonEmptyVec<A>) -> A {
+        wa.head
+    }
+
+    fn coflat_map<A, B, F>(wa: NonEmptyVec<A>, f: F) -> NonEmptyVec<B>
+    where
+        A: Clone,
+        F: Fn(NonEmptyVec<A>) -> B,
+    {
+        let NonEmptyVec { head, tail } = wa;
+        let first = f(NonEmptyVec::new(head, tail.clone()));
+        let mut rest = Vec::with_capacity(tail.len());
+        for (i, a) in tail.iter().enumerate() {
+            rest.push(f(NonEmptyVec::new(a.clone(), tail[i + 1..].to_vec())));
+        }
+        NonEmptyVec::new(first, rest)
+    }
+}
+
+impl Foldable for NonEmptyVecKind {
+    fn fold_left<A, B, F>(fa: NonEmptyVec<A>, init: B, f: F) -> B
+    where
+        F: FnMut(B, A) -> B,
+    {
+        fa.into_iter().fold(init, f)
+    }
+
+    fn fold_right<A, B, F>(fa: NonEmptyVec<A>, init: B, mut f: F) -> B
+    where
+        F: FnMut(A, B) -> B,
+    {
+        fa.into_iter().rev().fold(init, |acc, a| f(a, acc))
+    }
+}
+
+impl Reducible for NonEmptyVecKind {
+    fn reduce_left_to<A, B, F, G>(fa: NonEmptyVec<A>, f: F, g: G) -> B
+    where
+        F: FnOnce(A) -> B,
+        G: FnMut(B, A) -> B,
+    {
+        fa.tail.into_iter().fold(f(fa.head), g)
+    }
+
+    fn reduce_right_to<A, B, F, G>(fa: NonEmptyVec<A>, f: F, mut g: G) -> B
+    where
+        F: FnOnce(A) -> B,
+        G: FnMut(A, B) -> B,
+    {
+        let mut rest = fa.into_vec();
+        let last = rest.pop().expect("a NonEmptyVec can't be empty");
+        rest.into_iter().rev().fold(f(last), |acc, a| g(a, acc))
+    }
+
+    fn reduce_map<A, S, F>(fa: NonEmptyVec<A>, mut f: F) -> S
+    where
+        S: Semigroup,
+        F: FnMut(A) -> S,
+    {
+        let head = f(fa.head);
+        fa.tail.into_iter().fold(head, |acc, a| acc.combine(f(a)))
+    }
+}
+
+impl<'a> Traverse<'a> for NonEmptyVecKind {
+    fn traverse<G, A, B, F>(fa: NonEmptyVec<A>, f: F) -> G::Apply<NonEmptyVec<B>>
+    where
+        G: Applicative<'a>,
+        A: 'a,
+        B: Clone + 'a,
+        F: FnMut(A) -> G::Apply<B> + 'a,
+    {
+        NonEmptyVecKind::non_empty_traverse::<G, _, _, _>(fa, f)
+    }
+}
+
+impl<'a> NonEmptyTraverse<'a> for NonEmptyVecKind {
+    fn non_empty_traverse<G, A, B, F>(fa: NonEmptyVec<A>, mut f: F) -> G::Apply<NonEmptyVec<B>>
+    where
+        G: Apply<'a>,
+        B: Clone + 'a,
+        F: FnMut(A) -> G::Apply<B>,
+    {
+        let NonEmptyVec { head, tail } = fa;
+        let len = tail.len();
+        let init = G::fmap(f(head), move |b| {
+            NonEmptyVec::new(b, Vec::with_capacity(len))
+        });
+        tail.into_iter().fold(init, |acc, a| {
+            G::map2_lazy(
+                acc,
+                || f(a),
+                |mut bs, b| {
+                    bs.push(b);
+                    bs
+                },
+            )
+        })
+    }
+}
+
+impl<'a> TraverseWithIndex<'a> for NonEmptyVecKind {}
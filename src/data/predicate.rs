@@ -0,0 +1,119 @@
+use std::convert::Infallible;
+use std::rc::Rc;
+
+use part3::{Contravariant, Decidable, Decide, Divide, Divisible, HKT};
+
+use super::Either;
+
+/// A test on `A`s.
+///
+/// A predicate only consumes `A`s, so it's `Contravariant`. `divide` is "both halves pass",
+/// `conquer` always passes, and `choose` tests whichever side it's given:
+///
+/// ```rust
+/// # use rust_hkt::data::{Either, Predicate, PredicateKind};
+/// # use rust_hkt::part3::{Decide, Divide};
+/// let positive = Predicate::new(|i: i32| i > 0);
+/// let short = Predicate::new(|s: String| s.len() < 4);
+///
+/// let both = PredicateKind::divide(positive.clone(), short.clone(), |(i, s)| (i, s));
+/// assert!(both.test((1, "abc".to_string())));
+/// assert!(!both.test((1, "abcd".to_string())));
+///
+/// let either = PredicateKind::choose(positive, short, |r: Result<i32, String>| match r {
+///     Ok(i) => Either::Left(i),
+///     Err(s) => Either::Right(s),
+/// });
+/// assert!(either.test(Ok(1)));
+/// assert!(!either.test(Err("too long".to_string())));
+/// ```
+pub struct Predicate<A> {
+    test: Rc<dyn Fn(A) -> bool>,
+}
+
+impl<A: 'static> Predicate<A> {
+    pub fn new<F>(test: F) -> Predicate<A>
+    where
+        F: Fn(A) -> bool + 'static,
+    {
+        Predicate {
+            test: Rc::new(test),
+        }
+    }
+
+    pub fn test(&self, a: A) -> bool {
+        (self.test)(a)
+    }
+}
+
+impl<A> Clone for Predicate<A> {
+    fn clone(&self) -> Predicate<A> {
+        Predicate {
+            test: self.test.clone(),
+        }
+    }
+}
+
+/// The kind of `Predicate`.
+pub struct PredicateKind;
+
+impl HKT for PredicateKind {
+    type Apply<A> = Predicate<A>;
+}
+
+impl Contravariant<'static> for PredicateKind {
+    fn contramap<A, B, F>(fa: Predicate<A>, f: F) -> Predicate<B>
+    where
+        A: 'static,
+        B: 'static,
+        F: Fn(B) -> A + 'static,
+    {
+        Predicate::new(move |b| fa.test(f(b)))
+    }
+}
+
+impl Divide<'static> for PredicateKind {
+    fn divide<A, B, C, F>(fa: Predicate<A>, fb: Predicate<B>, f: F) -> Predicate<C>
+    where
+        A: 'static,
+        B: 'static,
+        C: 'static,
+        F: Fn(C) -> (A, B) + 'static,
+    {
+        Predicate::new(move |c| {
+            let (a, b) = f(c);
+            fa.test(a) && fb.test(b)
+        })
+    }
+}
+
+impl Divisible<'static> for PredicateKind {
+    fn conquer<A: 'static>() -> Predicate<A> {
+        Predicate::new(|_| true)
+    }
+}
+
+impl Decide<'static> for PredicateKind {
+    fn choose<A, B, C, F>(fa: Predicate<A>, fb: Predicate<B>, f: F) -> Predicate<C>
+    where
+        A: 'static,
+        B: 'static,
+        C: 'static,
+        F: Fn(C) -> Either<A, B> + 'static,
+    {
+        Predicate::new(move |c| match f(c) {
+            Either::Left(a) => fa.test(a),
+            Either::Right(b) => fb.test(b),
+        })
+    }
+}
+
+impl Decidable<'static> for PredicateKind {
+    fn lose<A, F>(f: F) -> Predicate<A>
+    where
+        A: 'static,
+        F: Fn(A) -> Infallible + 'static,
+    {
+        Predicate::new(move |a| match f(a) {})
+    }
+}
@@ -0,0 +1,416 @@
+use std::marker::PhantomData;
+
+use part3::{
+    Applicative, Apply, Ask, Defer, FlatMap, FlatMapOnce, Functor, Monad, MonadReader, MonadState,
+    MonadTrans, MonadWriter, Profunctor, Representable, Semigroupal, Tell, HKT, HKT2,
+};
+
+use super::{Either, Identity, IdentityKind};
+
+/// A computation in `F` which needs an environment `R` to run: a function `R -> F<A>`.
+///
+/// This is the Reader monad transformer, also known as `Kleisli`. It lets a whole program get at
+/// some shared configuration without passing it to every function by hand:
+///
+/// ```rust
+/// # use std::cell::RefCell;
+/// # use std::rc::Rc;
+/// # use rust_hkt::data::{IO, IOKind, ReaderT};
+/// #[derive(Clone)]
+/// struct Config {
+///     name: String,
+///     log: Rc<RefCell<Vec<String>>>,
+/// }
+///
+/// type App<A> = ReaderT<Config, IOKind, A>;
+///
+/// fn log(message: String) -> App<()> {
+///     ReaderT::new(|config: Config| IO::delay(move || config.log.borrow_mut().push(message)))
+/// }
+///
+/// fn greet() -> App<usize> {
+///     ReaderT::ask()
+///         .map(|config: Config| format!("hello, {}", config.name))
+///         .flat_map(|greeting| {
+///             let length = greeting.len();
+///             log(greeting).map(move |()| length)
+///         })
+/// }
+///
+/// let log = Rc::new(RefCell::new(Vec::new()));
+/// let config = Config { name: "world".to_string(), log: log.clone() };
+///
+/// let app = greet().flat_map(|length| {
+///     greet()
+///         .local(|config: Config| Config { name: "everyone".to_string(), ..config })
+///         .map(move |other| length + other)
+/// });
+///
+/// let io = app.run(config);
+/// assert!(log.borrow().is_empty());
+/// assert_eq!(io.run(), 27);
+/// assert_eq!(*log.borrow(), vec!["hello, world", "hello, everyone"]);
+/// ```
+///
+/// As a `Kleisli`, `and_then` composes it with another function into `F`:
+///
+/// ```rust
+/// # use rust_hkt::data::Kleisli;
+/// # use rust_hkt::part3::OptionKind;
+/// let parse_half = || {
+///     Kleisli::<&str, OptionKind, i32>::new(|s| s.parse().ok())
+///         .and_then(|i| if i % 2 == 0 { Some(i / 2) } else { None })
+/// };
+/// assert_eq!(parse_half().run("42"), Some(21));
+/// assert_eq!(parse_half().run("21"), None);
+/// assert_eq!(parse_half().run("x"), None);
+/// ```
+///
+/// Like `State`, a `ReaderT` is a stored closure, so `ReaderTKind` only implements the typeclasses
+/// for `'static`. It's run at most once, which is why there's no `Clone` bound on `F<A>`, but
+/// `flat_map` does have to hand the environment to both sides, so that needs `R: Clone`.
+pub struct ReaderT<R, F: HKT, A> {
+    run: Box<dyn FnOnce(R) -> F::Apply<A>>,
+}
+
+/// `ReaderT`'s other name, which is more common when it's used as a function `A -> F<B>` to be
+/// composed.
+pub type Kleisli<R, F, A> = ReaderT<R, F, A>;
+
+/// `ReaderT` without any other effect.
+pub type Reader<R, A> = ReaderT<R, IdentityKind, A>;
+
+impl<R: 'static, F: HKT + 'static, A: 'static> ReaderT<R, F, A> {
+    pub fn new<K>(f: K) -> ReaderT<R, F, A>
+    where
+        K: FnOnce(R) -> F::Apply<A> + 'static,
+    {
+        ReaderT { run: Box::new(f) }
+    }
+
+    pub fn run(self, r: R) -> F::Apply<A> {
+        (self.run)(r)
+    }
+
+    /// Lifts a computation which doesn't need the environment.
+    pub fn lift(fa: F::Apply<A>) -> ReaderT<R, F, A> {
+        ReaderT::new(move |_| fa)
+    }
+
+    /// Runs this computation in an environment derived from the outer one.
+    pub fn local<Q, K>(self, f: K) -> ReaderT<Q, F, A>
+    where
+        Q: 'static,
+        K: FnOnce(Q) -> R + 'static,
+    {
+        ReaderT::new(move |q| self.run(f(q)))
+    }
+
+    pub fn map<B, K>(self, f: K) -> ReaderT<R, F, B>
+    where
+        F: Functor<'static>,
+        B: 'static,
+        K: FnMut(A) -> B + 'static,
+    {
+        ReaderT::new(move |r| F::fmap(self.run(r), f))
+    }
+
+    pub fn flat_map<B, K>(self, mut f: K) -> ReaderT<R, F, B>
+    where
+        R: Clone,
+        F: Monad<'static>,
+        B: 'static,
+        K: FnMut(A) -> ReaderT<R, F, B> + 'static,
+    {
+        ReaderT::new(move |r: R| {
+            let fa = self.run(r.clone());
+            F::flat_map(fa, move |a| f(a).run(r.clone()))
+        })
+    }
+
+    /// Kleisli composition: feeds the result of this computation to `k`.
+    pub fn and_then<B, K>(self, k: K) -> ReaderT<R, F, B>
+    where
+        F: Monad<'static>,
+        B: 'static,
+        K: FnMut(A) -> F::Apply<B> + 'static,
+    {
+        ReaderT::new(move |r| F::flat_map(self.run(r), k))
+    }
+}
+
+impl<R: 'static, F: Applicative<'static> + 'static> ReaderT<R, F, R> {
+    /// Reads the environment.
+    pub fn ask() -> ReaderT<R, F, R> {
+        ReaderT::new(F::pure)
+    }
+}
+
+/// The kind of `ReaderT<R, F, _>`.
+pub struct ReaderTKind<R, F>(PhantomData<(R, F)>);
+
+impl<R, F: HKT> HKT for ReaderTKind<R, F> {
+    type Apply<A> = ReaderT<R, F, A>;
+}
+
+impl<R, F> Defer<'static> for ReaderTKind<R, F>
+where
+    R: 'static,
+    F: HKT + 'static,
+{
+    fn defer<A, K>(f: K) -> ReaderT<R, F, A>
+    where
+        A: 'static,
+        K: FnOnce() -> ReaderT<R, F, A> + 'static,
+    {
+        ReaderT::new(move |r| f().run(r))
+    }
+}
+
+impl<R, F> Functor<'static> for ReaderTKind<R, F>
+where
+    R: 'static,
+    F: Functor<'static> + 'static,
+{
+    fn fmap<A, B, K>(fa: ReaderT<R, F, A>, f: K) -> ReaderT<R, F, B>
+    where
+        A: 'static,
+        B: 'static,
+        K: FnMut(A) -> B + 'static,
+    {
+        fa.map(f)
+    }
+}
+
+impl<R, F> Semigroupal<'static> for ReaderTKind<R, F>
+where
+    R: Clone + 'static,
+    F: Applicative<'static> + 'static,
+{
+    fn product<A, B>(fa: ReaderT<R, F, A>, fb: ReaderT<R, F, B>) -> ReaderT<R, F, (A, B)>
+    where
+        A: Clone + 'static,
+        B: Clone + 'static,
+    {
+        ReaderT::new(move |r: R| F::product(fa.run(r.clone()), fb.run(r)))
+    }
+}
+
+impl<R, F> Apply<'static> for ReaderTKind<R, F>
+where
+    R: Clone + 'static,
+    F: Applicative<'static> + 'static,
+{
+    fn map2<A, B, C, K>(fa: ReaderT<R, F, A>, fb: ReaderT<R, F, B>, f: K) -> ReaderT<R, F, C>
+    where
+        A: Clone + 'static,
+        B: Clone + 'static,
+        C: 'static,
+        K: FnMut(A, B) -> C + 'static,
+    {
+        ReaderT::new(move |r: R| F::map2(fa.run(r.clone()), fb.run(r), f))
+    }
+}
+
+impl<R, F> Applicative<'static> for ReaderTKind<R, F>
+where
+    R: Clone + 'static,
+    F: Applicative<'static> + 'static,
+{
+    fn pure<A: 'static>(a: A) -> ReaderT<R, F, A> {
+        ReaderT::lift(F::pure(a))
+    }
+}
+
+impl<R, F> FlatMap<'static> for ReaderTKind<R, F>
+where
+    R: Clone + 'static,
+    F: Monad<'static> + 'static,
+{
+    fn flat_map<A, B, K>(fa: ReaderT<R, F, A>, f: K) -> ReaderT<R, F, B>
+    where
+        A: 'static,
+        B: 'static,
+        K: FnMut(A) -> ReaderT<R, F, B> + 'static,
+    {
+        fa.flat_map(f)
+    }
+}
+
+impl<R, F> FlatMapOnce<'static> for ReaderTKind<R, F>
+where
+    R: Clone + 'static,
+    F: FlatMapOnce<'static> + Monad<'static> + 'static,
+{
+    fn flat_map_once<A, B, K>(fa: ReaderT<R, F, A>, f: K) -> ReaderT<R, F, B>
+    where
+        A: 'static,
+        B: 'static,
+        K: FnOnce(A) -> ReaderT<R, F, B> + 'static,
+    {
+        ReaderT::new(move |r: R| {
+            let fa = fa.run(r.clone());
+            F::flat_map_once(fa, move |a| f(a).run(r))
+        })
+    }
+}
+
+/// Hands the environment to every step of the `tail_rec_m` of the monad underneath, so it's as
+/// stack-safe as that one is:
+///
+/// ```rust
+/// # use rust_hkt::data::{Either, ReaderT, ReaderTKind};
+/// # use rust_hkt::part3::{Monad, OptionKind};
+/// let count = ReaderTKind::<u32, OptionKind>::tail_rec_m((1_000_000, 0), |(i, total): (u32, u32)| {
+///     ReaderT::new(move |step: u32| Some(if i == 0 { Either::Right(total) } else { Either::Left((i - 1, total + step)) }))
+/// });
+/// assert_eq!(count.run(2), Some(2_000_000));
+/// ```
+impl<R, F> Monad<'static> for ReaderTKind<R, F>
+where
+    R: Clone + 'static,
+    F: Monad<'static> + 'static,
+{
+    fn tail_rec_m<A, B, K>(a: A, mut f: K) -> ReaderT<R, F, B>
+    where
+        A: 'static,
+        B: 'static,
+        K: FnMut(A) -> ReaderT<R, F, Either<A, B>> + 'static,
+    {
+        ReaderT::new(move |r: R| F::tail_rec_m(a, move |a| f(a).run(r.clone())))
+    }
+}
+
+impl<R, F> MonadTrans<'static> for ReaderTKind<R, F>
+where
+    R: 'static,
+    F: Monad<'static> + 'static,
+{
+    type Inner = F;
+
+    fn lift<A: 'static>(fa: F::Apply<A>) -> ReaderT<R, F, A> {
+        ReaderT::lift(fa)
+    }
+}
+
+/// A `Reader` is a function from its environment, so it's represented by it.
+impl<R: 'static> Representable<'static> for ReaderTKind<R, IdentityKind> {
+    type Rep = R;
+
+    fn tabulate<A, F>(mut f: F) -> Reader<R, A>
+    where
+        A: 'static,
+        F: FnMut(R) -> A + 'static,
+    {
+        Reader::new(move |r| Identity(f(r)))
+    }
+
+    fn index<A: 'static>(fa: Reader<R, A>, r: R) -> A {
+        fa.run(r).0
+    }
+}
+
+/// The kind of `Kleisli<_, F, _>`, a function `A -> F<B>` with both ends free.
+///
+/// This is the kind to use to adapt a `Kleisli` as a `Profunctor`:
+///
+/// ```rust
+/// # use rust_hkt::data::{Kleisli, KleisliKind};
+/// # use rust_hkt::part3::{OptionKind, Profunctor};
+/// let parse = Kleisli::<&str, OptionKind, i32>::new(|s| s.parse().ok());
+/// let parse_trimmed = KleisliKind::dimap(parse, |s: &'static str| s.trim(), |i| i * 2);
+/// assert_eq!(parse_trimmed.run(" 21 "), Some(42));
+/// ```
+pub struct KleisliKind<F>(PhantomData<F>);
+
+impl<F: HKT> HKT2 for KleisliKind<F> {
+    type Apply2<A, B> = Kleisli<A, F, B>;
+}
+
+impl<F> Profunctor<'static> for KleisliKind<F>
+where
+    F: Functor<'static> + 'static,
+{
+    fn dimap<A, B, C, D, G, H>(fab: Kleisli<A, F, B>, f: G, g: H) -> Kleisli<C, F, D>
+    where
+        A: 'static,
+        B: 'static,
+        C: 'static,
+        D: 'static,
+        G: FnMut(C) -> A + 'static,
+        H: FnMut(B) -> D + 'static,
+    {
+        fab.local(f).map(g)
+    }
+}
+
+/// Lifts the state of the monad underneath.
+impl<R, F, S> MonadState<'static, S> for ReaderTKind<R, F>
+where
+    R: Clone + 'static,
+    F: MonadState<'static, S> + 'static,
+    S: 'static,
+{
+    fn get() -> ReaderT<R, F, S> {
+        ReaderT::lift(F::get())
+    }
+
+    fn set(s: S) -> ReaderT<R, F, ()> {
+        ReaderT::lift(F::set(s))
+    }
+}
+
+impl<R, F> Ask<'static, R> for ReaderTKind<R, F>
+where
+    R: Clone + 'static,
+    F: Applicative<'static> + 'static,
+{
+    fn ask() -> ReaderT<R, F, R> {
+        ReaderT::ask()
+    }
+}
+
+impl<R, F> MonadReader<'static, R> for ReaderTKind<R, F>
+where
+    R: Clone + 'static,
+    F: Monad<'static> + 'static,
+{
+    fn local<A, K>(fa: ReaderT<R, F, A>, f: K) -> ReaderT<R, F, A>
+    where
+        A: 'static,
+        K: FnMut(R) -> R + 'static,
+    {
+        fa.local(f)
+    }
+}
+
+/// Lifts the log of the monad underneath.
+impl<R, F, W> Tell<'static, W> for ReaderTKind<R, F>
+where
+    R: Clone + 'static,
+    F: Tell<'static, W> + 'static,
+    W: 'static,
+{
+    fn tell(w: W) -> ReaderT<R, F, ()> {
+        ReaderT::lift(F::tell(w))
+    }
+}
+
+impl<R, F, W> MonadWriter<'static, W> for ReaderTKind<R, F>
+where
+    R: Clone + 'static,
+    F: MonadWriter<'static, W> + 'static,
+    W: 'static,
+{
+    fn listen<A: 'static>(fa: ReaderT<R, F, A>) -> ReaderT<R, F, (W, A)> {
+        ReaderT::new(move |r| F::listen(fa.run(r)))
+    }
+
+    fn censor<A, K>(fa: ReaderT<R, F, A>, f: K) -> ReaderT<R, F, A>
+    where
+        A: 'static,
+        K: FnMut(W) -> W + 'static,
+    {
+        ReaderT::new(move |r| F::censor(fa.run(r), f))
+    }
+}
@@ -0,0 +1,200 @@
+use std::marker::PhantomData;
+
+use part3::{
+    Applicative, Apply, Defer, FlatMap, FlatMapOnce, Functor, Monad, MonadState, Semigroupal, HKT,
+};
+
+use super::Either;
+
+/// A computation which threads a state `S` through, producing an `A` along the way.
+///
+/// ```rust
+/// # use rust_hkt::data::{State, StateKind};
+/// # use rust_hkt::part3::FlatMap;
+/// let next_id = || State::new(|id: u32| (id + 1, id));
+/// let two_ids = StateKind::flat_map(next_id(), move |a| next_id().map(move |b| (a, b)));
+/// assert_eq!(two_ids.run(7), (9, (7, 8)));
+///
+/// let doubled = State::modify(|s: i32| s * 2).flat_map(|()| State::get());
+/// assert_eq!(doubled.run(21), (42, 42));
+/// ```
+///
+/// Like `Eval`, a `State` is a stored closure, so `StateKind` only implements the typeclasses
+/// for `'static`.
+pub struct State<S, A> {
+    run: Box<dyn FnOnce(S) -> (S, A)>,
+}
+
+impl<S: 'static, A: 'static> State<S, A> {
+    pub fn new<F>(f: F) -> State<S, A>
+    where
+        F: FnOnce(S) -> (S, A) + 'static,
+    {
+        State { run: Box::new(f) }
+    }
+
+    /// Runs the computation from an initial state, giving back the final state and the result.
+    pub fn run(self, s: S) -> (S, A) {
+        (self.run)(s)
+    }
+
+    pub fn flat_map<B, F>(self, f: F) -> State<S, B>
+    where
+        B: 'static,
+        F: FnOnce(A) -> State<S, B> + 'static,
+    {
+        State::new(move |s| {
+            let (s, a) = self.run(s);
+            f(a).run(s)
+        })
+    }
+
+    pub fn map<B, F>(self, f: F) -> State<S, B>
+    where
+        B: 'static,
+        F: FnOnce(A) -> B + 'static,
+    {
+        State::new(move |s| {
+            let (s, a) = self.run(s);
+            (s, f(a))
+        })
+    }
+}
+
+impl<S: Clone + 'static> State<S, S> {
+    pub fn get() -> State<S, S> {
+        State::new(|s: S| (s.clone(), s))
+    }
+}
+
+impl<S: 'static> State<S, ()> {
+    pub fn set(s: S) -> State<S, ()> {
+        State::new(move |_| (s, ()))
+    }
+
+    pub fn modify<F>(f: F) -> State<S, ()>
+    where
+        F: FnOnce(S) -> S + 'static,
+    {
+        State::new(move |s| (f(s), ()))
+    }
+}
+
+/// The kind of `State<S, _>`.
+pub struct StateKind<S>(PhantomData<S>);
+
+impl<S> HKT for StateKind<S> {
+    type Apply<A> = State<S, A>;
+}
+
+impl<S: 'static> Functor<'static> for StateKind<S> {
+    fn fmap<A, B, F>(fa: State<S, A>, f: F) -> State<S, B>
+    where
+        A: 'static,
+        B: 'static,
+        F: FnMut(A) -> B + 'static,
+    {
+        fa.map(f)
+    }
+}
+
+impl<S: 'static> Semigroupal<'static> for StateKind<S> {
+    fn product<A, B>(fa: State<S, A>, fb: State<S, B>) -> State<S, (A, B)>
+    where
+        A: Clone + 'static,
+        B: Clone + 'static,
+    {
+        fa.flat_map(move |a| fb.map(move |b| (a, b)))
+    }
+}
+
+impl<S: 'static> Apply<'static> for StateKind<S> {
+    fn map2<A, B, C, F>(fa: State<S, A>, fb: State<S, B>, mut f: F) -> State<S, C>
+    where
+        A: Clone + 'static,
+        B: Clone + 'static,
+        C: 'static,
+        F: FnMut(A, B) -> C + 'static,
+    {
+        fa.flat_map(move |a| fb.map(move |b| f(a, b)))
+    }
+}
+
+impl<S: 'static> Applicative<'static> for StateKind<S> {
+    fn pure<A: 'static>(a: A) -> State<S, A> {
+        State::new(move |s| (s, a))
+    }
+}
+
+impl<S: 'static> FlatMap<'static> for StateKind<S> {
+    fn flat_map<A, B, F>(fa: State<S, A>, f: F) -> State<S, B>
+    where
+        A: 'static,
+        B: 'static,
+        F: FnMut(A) -> State<S, B> + 'static,
+    {
+        State::flat_map(fa, f)
+    }
+}
+
+impl<S: 'static> FlatMapOnce<'static> for StateKind<S> {
+    fn flat_map_once<A, B, F>(fa: State<S, A>, f: F) -> State<S, B>
+    where
+        A: 'static,
+        B: 'static,
+        F: FnOnce(A) -> State<S, B> + 'static,
+    {
+        State::flat_map(fa, f)
+    }
+}
+
+/// Runs the steps in a loop, so a long one doesn't grow the stack:
+///
+/// ```rust
+/// # use rust_hkt::data::{Either, State, StateKind};
+/// # use rust_hkt::part3::Monad;
+/// let count = StateKind::tail_rec_m(1_000_000, |i: u32| {
+///     State::new(move |s: u32| (s + 1, if i == 0 { Either::Right("done") } else { Either::Left(i - 1) }))
+/// });
+/// assert_eq!(count.run(0), (1_000_001, "done"));
+/// ```
+impl<S: 'static> Monad<'static> for StateKind<S> {
+    fn tail_rec_m<A, B, F>(a: A, mut f: F) -> State<S, B>
+    where
+        A: 'static,
+        B: 'static,
+        F: FnMut(A) -> State<S, Either<A, B>> + 'static,
+    {
+        State::new(move |mut s| {
+            let mut a = a;
+            loop {
+                let (next, step) = f(a).run(s);
+                s = next;
+                match step {
+                    Either::Left(next) => a = next,
+                    Either::Right(b) => return (s, b),
+                }
+            }
+        })
+    }
+}
+
+impl<S: 'static> Defer<'static> for StateKind<S> {
+    fn defer<A, F>(f: F) -> State<S, A>
+    where
+        A: 'static,
+        F: FnOnce() -> State<S, A> + 'static,
+    {
+        State::new(move |s| f().run(s))
+    }
+}
+
+impl<S: Clone + 'static> MonadState<'static, S> for StateKind<S> {
+    fn get() -> State<S, S> {
+        State::get()
+    }
+
+    fn set(s: S) -> State<S, ()> {
+        State::set(s)
+    }
+}
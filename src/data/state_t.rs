@@ -0,0 +1,337 @@
+use std::marker::PhantomData;
+
+use part3::{
+    Applicative, Apply, Ask, Defer, FlatMap, FlatMapOnce, Functor, Monad, MonadReader, MonadState,
+    MonadTrans, MonadWriter, Semigroupal, Tell, HKT,
+};
+
+use super::Either;
+
+/// A computation in `F` which threads a state `S` through: a function `S -> F<(S, A)>`.
+///
+/// This is the State monad transformer. Where `State` is pure, `StateT` can have the effects of
+/// the monad underneath it along the way, like failing with `Option`:
+///
+/// ```rust
+/// # use rust_hkt::data::StateT;
+/// # use rust_hkt::part3::OptionKind;
+/// // Pops the top of a stack, failing if there isn't one.
+/// fn pop() -> StateT<Vec<i32>, OptionKind, i32> {
+///     StateT::new(|mut stack: Vec<i32>| stack.pop().map(|top| (stack, top)))
+/// }
+///
+/// fn add() -> StateT<Vec<i32>, OptionKind, i32> {
+///     pop().flat_map(|a| pop().map(move |b| a + b))
+/// }
+/// assert_eq!(add().run(vec![1, 2, 3]), Some((vec![1], 5)));
+/// assert_eq!(add().run(vec![1]), None);
+///
+/// let pushed = StateT::modify(|mut stack: Vec<i32>| { stack.push(4); stack }).flat_map(|()| add());
+/// assert_eq!(pushed.run(vec![1]), Some((vec![], 5)));
+/// ```
+///
+/// Like `State`, a `StateT` is a stored closure, so `StateTKind` only implements the typeclasses
+/// for `'static`. It's also run at most once, so sequencing two of them with `map2` needs a monad
+/// underneath which calls a `flat_map` function at most once, a `FlatMapOnce`. Over one which
+/// calls it several times, like `Vec`, a `StateT` still has `map` and `flat_map`, but not the
+/// `Applicative` and `Monad` instances:
+///
+/// ```rust
+/// # use rust_hkt::data::StateT;
+/// # use rust_hkt::part3::VecKind;
+/// // Either leaves the counter alone or bumps it.
+/// fn maybe_bump() -> StateT<u8, VecKind, u8> {
+///     StateT::new(|n: u8| vec![(n, 0), (n + 1, 1)])
+/// }
+///
+/// let both = maybe_bump().flat_map(|a| maybe_bump().map(move |b| a + b));
+/// assert_eq!(both.run(0), vec![(0, 0), (1, 1), (1, 1), (2, 2)]);
+/// ```
+pub struct StateT<S, F: HKT, A> {
+    run: Run<S, F, A>,
+}
+
+type Run<S, F, A> = Box<dyn FnOnce(S) -> <F as HKT>::Apply<(S, A)>>;
+
+impl<S: 'static, F: HKT + 'static, A: 'static> StateT<S, F, A> {
+    pub fn new<K>(f: K) -> StateT<S, F, A>
+    where
+        K: FnOnce(S) -> F::Apply<(S, A)> + 'static,
+    {
+        StateT { run: Box::new(f) }
+    }
+
+    /// Runs the computation from an initial state, giving back the final state and the result.
+    pub fn run(self, s: S) -> F::Apply<(S, A)> {
+        (self.run)(s)
+    }
+
+    /// Lifts a computation which leaves the state alone.
+    pub fn lift(fa: F::Apply<A>) -> StateT<S, F, A>
+    where
+        S: Clone,
+        F: Functor<'static>,
+    {
+        StateT::new(move |s: S| F::fmap(fa, move |a| (s.clone(), a)))
+    }
+
+    pub fn map<B, K>(self, mut f: K) -> StateT<S, F, B>
+    where
+        F: Functor<'static>,
+        B: 'static,
+        K: FnMut(A) -> B + 'static,
+    {
+        StateT::new(move |s| F::fmap(self.run(s), move |(s, a)| (s, f(a))))
+    }
+
+    pub fn flat_map<B, K>(self, mut f: K) -> StateT<S, F, B>
+    where
+        F: Monad<'static>,
+        B: 'static,
+        K: FnMut(A) -> StateT<S, F, B> + 'static,
+    {
+        StateT::new(move |s| F::flat_map(self.run(s), move |(s, a)| f(a).run(s)))
+    }
+}
+
+impl<S: Clone + 'static, F: Applicative<'static> + 'static> StateT<S, F, S> {
+    pub fn get() -> StateT<S, F, S> {
+        StateT::new(|s: S| F::pure((s.clone(), s)))
+    }
+}
+
+impl<S: 'static, F: Applicative<'static> + 'static> StateT<S, F, ()> {
+    pub fn set(s: S) -> StateT<S, F, ()> {
+        StateT::new(move |_| F::pure((s, ())))
+    }
+
+    pub fn modify<K>(f: K) -> StateT<S, F, ()>
+    where
+        K: FnOnce(S) -> S + 'static,
+    {
+        StateT::new(move |s| F::pure((f(s), ())))
+    }
+}
+
+/// The kind of `StateT<S, F, _>`.
+pub struct StateTKind<S, F>(PhantomData<(S, F)>);
+
+impl<S, F: HKT> HKT for StateTKind<S, F> {
+    type Apply<A> = StateT<S, F, A>;
+}
+
+impl<S, F> Functor<'static> for StateTKind<S, F>
+where
+    S: 'static,
+    F: Functor<'static> + 'static,
+{
+    fn fmap<A, B, K>(fa: StateT<S, F, A>, f: K) -> StateT<S, F, B>
+    where
+        A: 'static,
+        B: 'static,
+        K: FnMut(A) -> B + 'static,
+    {
+        fa.map(f)
+    }
+}
+
+impl<S, F> Semigroupal<'static> for StateTKind<S, F>
+where
+    S: 'static,
+    F: FlatMapOnce<'static> + Monad<'static> + 'static,
+{
+    fn product<A, B>(fa: StateT<S, F, A>, fb: StateT<S, F, B>) -> StateT<S, F, (A, B)>
+    where
+        A: Clone + 'static,
+        B: Clone + 'static,
+    {
+        Self::flat_map_once(fa, move |a| fb.map(move |b| (a.clone(), b)))
+    }
+}
+
+impl<S, F> Apply<'static> for StateTKind<S, F>
+where
+    S: 'static,
+    F: FlatMapOnce<'static> + Monad<'static> + 'static,
+{
+    fn map2<A, B, C, K>(fa: StateT<S, F, A>, fb: StateT<S, F, B>, mut f: K) -> StateT<S, F, C>
+    where
+        A: Clone + 'static,
+        B: Clone + 'static,
+        C: 'static,
+        K: FnMut(A, B) -> C + 'static,
+    {
+        Self::flat_map_once(fa, move |a| fb.map(move |b| f(a.clone(), b)))
+    }
+}
+
+impl<S, F> Applicative<'static> for StateTKind<S, F>
+where
+    S: 'static,
+    F: FlatMapOnce<'static> + Monad<'static> + 'static,
+{
+    fn pure<A: 'static>(a: A) -> StateT<S, F, A> {
+        StateT::new(move |s| F::pure((s, a)))
+    }
+}
+
+impl<S, F> FlatMap<'static> for StateTKind<S, F>
+where
+    S: 'static,
+    F: FlatMapOnce<'static> + Monad<'static> + 'static,
+{
+    fn flat_map<A, B, K>(fa: StateT<S, F, A>, f: K) -> StateT<S, F, B>
+    where
+        A: 'static,
+        B: 'static,
+        K: FnMut(A) -> StateT<S, F, B> + 'static,
+    {
+        fa.flat_map(f)
+    }
+}
+
+impl<S, F> FlatMapOnce<'static> for StateTKind<S, F>
+where
+    S: 'static,
+    F: FlatMapOnce<'static> + Monad<'static> + 'static,
+{
+    fn flat_map_once<A, B, K>(fa: StateT<S, F, A>, f: K) -> StateT<S, F, B>
+    where
+        A: 'static,
+        B: 'static,
+        K: FnOnce(A) -> StateT<S, F, B> + 'static,
+    {
+        StateT::new(move |s| F::flat_map_once(fa.run(s), move |(s, a)| f(a).run(s)))
+    }
+}
+
+/// Threads the state through the `tail_rec_m` of the monad underneath, so it's as stack-safe as
+/// that one is:
+///
+/// ```rust
+/// # use rust_hkt::data::{Either, StateT, StateTKind};
+/// # use rust_hkt::part3::{Monad, OptionKind};
+/// let count = StateTKind::<u8, OptionKind>::tail_rec_m(1_000_000, |i: u32| {
+///     StateT::new(move |s: u8| Some((s.wrapping_add(1), if i == 0 { Either::Right(i) } else { Either::Left(i - 1) })))
+/// });
+/// assert_eq!(count.run(0), Some((65, 0)));
+/// ```
+impl<S, F> Monad<'static> for StateTKind<S, F>
+where
+    S: 'static,
+    F: FlatMapOnce<'static> + Monad<'static> + 'static,
+{
+    fn tail_rec_m<A, B, K>(a: A, mut f: K) -> StateT<S, F, B>
+    where
+        A: 'static,
+        B: 'static,
+        K: FnMut(A) -> StateT<S, F, Either<A, B>> + 'static,
+    {
+        StateT::new(move |s| {
+            F::tail_rec_m((s, a), move |(s, a)| {
+                F::fmap(f(a).run(s), |(s, step)| match step {
+                    Either::Left(a) => Either::Left((s, a)),
+                    Either::Right(b) => Either::Right((s, b)),
+                })
+            })
+        })
+    }
+}
+
+impl<S, F> MonadTrans<'static> for StateTKind<S, F>
+where
+    S: Clone + 'static,
+    F: Monad<'static> + 'static,
+{
+    type Inner = F;
+
+    fn lift<A: 'static>(fa: F::Apply<A>) -> StateT<S, F, A> {
+        StateT::lift(fa)
+    }
+}
+
+impl<S, F> Defer<'static> for StateTKind<S, F>
+where
+    S: 'static,
+    F: HKT + 'static,
+{
+    fn defer<A, K>(f: K) -> StateT<S, F, A>
+    where
+        A: 'static,
+        K: FnOnce() -> StateT<S, F, A> + 'static,
+    {
+        StateT::new(move |s| f().run(s))
+    }
+}
+
+impl<S, F> MonadState<'static, S> for StateTKind<S, F>
+where
+    S: Clone + 'static,
+    F: FlatMapOnce<'static> + Monad<'static> + 'static,
+{
+    fn get() -> StateT<S, F, S> {
+        StateT::get()
+    }
+
+    fn set(s: S) -> StateT<S, F, ()> {
+        StateT::set(s)
+    }
+}
+
+/// Lifts the environment of the monad underneath.
+impl<S, F, R> Ask<'static, R> for StateTKind<S, F>
+where
+    S: Clone + 'static,
+    F: Ask<'static, R> + FlatMapOnce<'static> + Monad<'static> + 'static,
+    R: 'static,
+{
+    fn ask() -> StateT<S, F, R> {
+        StateT::lift(F::ask())
+    }
+}
+
+impl<S, F, R> MonadReader<'static, R> for StateTKind<S, F>
+where
+    S: Clone + 'static,
+    F: MonadReader<'static, R> + FlatMapOnce<'static> + 'static,
+    R: 'static,
+{
+    fn local<A, K>(fa: StateT<S, F, A>, f: K) -> StateT<S, F, A>
+    where
+        A: 'static,
+        K: FnMut(R) -> R + 'static,
+    {
+        StateT::new(move |s| F::local(fa.run(s), f))
+    }
+}
+
+/// Lifts the log of the monad underneath.
+impl<S, F, W> Tell<'static, W> for StateTKind<S, F>
+where
+    S: Clone + 'static,
+    F: Tell<'static, W> + FlatMapOnce<'static> + Monad<'static> + 'static,
+    W: 'static,
+{
+    fn tell(w: W) -> StateT<S, F, ()> {
+        StateT::lift(F::tell(w))
+    }
+}
+
+impl<S, F, W> MonadWriter<'static, W> for StateTKind<S, F>
+where
+    S: Clone + 'static,
+    F: MonadWriter<'static, W> + FlatMapOnce<'static> + 'static,
+    W: 'static,
+{
+    fn listen<A: 'static>(fa: StateT<S, F, A>) -> StateT<S, F, (W, A)> {
+        StateT::new(move |s| F::fmap(F::listen(fa.run(s)), |(w, (s, a))| (s, (w, a))))
+    }
+
+    fn censor<A, K>(fa: StateT<S, F, A>, f: K) -> StateT<S, F, A>
+    where
+        A: 'static,
+        K: FnMut(W) -> W + 'static,
+    {
+        StateT::new(move |s| F::censor(fa.run(s), f))
+    }
+}
@@ -0,0 +1,127 @@
+use std::cell::RefCell;
+use std::marker::PhantomData;
+use std::rc::Rc;
+
+use part3::{Comonad, Functor, HKT};
+
+/// A position `S` in a space of `A`s, along with a way to look up the `A` at any position.
+///
+/// `Store` is the comonad of "focus and surroundings": `extract` gives the value under the focus,
+/// and `coflat_map` runs a function at every position, each time with the focus moved there. A
+/// one-dimensional cellular automaton only has to say what happens to a single cell:
+///
+/// ```rust
+/// # use rust_hkt::data::{Store, StoreKind};
+/// # use rust_hkt::part3::Comonad;
+/// // Rule 90: a cell is alive if exactly one of its neighbours was.
+/// fn rule(cells: Store<i64, bool>) -> bool {
+///     cells.peek(cells.pos() - 1) != cells.peek(cells.pos() + 1)
+/// }
+///
+/// fn render(cells: &Store<i64, bool>) -> String {
+///     (-3..=3).map(|i| if cells.peek(i) { '#' } else { '.' }).collect()
+/// }
+///
+/// let start = Store::new(|i: i64| i == 0, 0);
+/// let next = StoreKind::coflat_map(start.clone(), rule);
+/// let after = StoreKind::coflat_map(next.clone(), rule);
+///
+/// assert_eq!(render(&start), "...#...");
+/// assert_eq!(render(&next), "..#.#..");
+/// assert_eq!(render(&after), ".#...#.");
+/// assert_eq!(StoreKind::extract(after.seek(2)), true);
+/// ```
+///
+/// Nothing is cached, so each generation looks up every cell of the one before it again. Like
+/// the other types built from closures, `StoreKind` only implements the typeclasses for
+/// `'static`.
+pub struct Store<S, A> {
+    peek: Rc<dyn Fn(S) -> A>,
+    pos: S,
+}
+
+impl<S: 'static, A: 'static> Store<S, A> {
+    pub fn new<F>(peek: F, pos: S) -> Store<S, A>
+    where
+        F: Fn(S) -> A + 'static,
+    {
+        Store {
+            peek: Rc::new(peek),
+            pos,
+        }
+    }
+
+    /// The value at `s`, wherever the focus is.
+    pub fn peek(&self, s: S) -> A {
+        (self.peek)(s)
+    }
+
+    /// Moves the focus to `s`.
+    pub fn seek(self, s: S) -> Store<S, A> {
+        Store {
+            peek: self.peek,
+            pos: s,
+        }
+    }
+}
+
+impl<S: Clone, A> Store<S, A> {
+    /// The position of the focus.
+    pub fn pos(&self) -> S {
+        self.pos.clone()
+    }
+}
+
+impl<S: Clone, A> Clone for Store<S, A> {
+    fn clone(&self) -> Store<S, A> {
+        Store {
+            peek: self.peek.clone(),
+            pos: self.pos.clone(),
+        }
+    }
+}
+
+/// The kind of `Store<S, _>`.
+pub struct StoreKind<S>(PhantomData<S>);
+
+impl<S> HKT for StoreKind<S> {
+    type Apply<A> = Store<S, A>;
+}
+
+impl<S: 'static> Functor<'static> for StoreKind<S> {
+    fn fmap<A, B, F>(fa: Store<S, A>, f: F) -> Store<S, B>
+    where
+        A: 'static,
+        B: 'static,
+        F: FnMut(A) -> B + 'static,
+    {
+        // `Functor` hands over an `FnMut`, and every lookup has to be able to call it.
+        let f = RefCell::new(f);
+        let peek = fa.peek;
+        Store::new(move |s| (f.borrow_mut())(peek(s)), fa.pos)
+    }
+}
+
+impl<S: Clone + 'static> Comonad<'static> for StoreKind<S> {
+    fn extract<A>(wa: Store<S, A>) -> A {
+        (wa.peek)(wa.pos)
+    }
+
+    fn coflat_map<A, B, F>(wa: Store<S, A>, f: F) -> Store<S, B>
+    where
+        A: Clone + 'static,
+        B: 'static,
+        F: Fn(Store<S, A>) -> B + 'static,
+    {
+        let peek = wa.peek;
+        Store::new(
+            move |s| {
+                f(Store {
+                    peek: peek.clone(),
+                    pos: s,
+                })
+            },
+            wa.pos,
+        )
+    }
+}
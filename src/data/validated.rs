@@ -0,0 +1,255 @@
+use std::marker::PhantomData;
+
+use data::{Either, NonEmptyVec};
+use kernel::{CommutativeSemigroup, Semigroup};
+use part3::{
+    Applicative, ApplicativeError, Apply, Bifoldable, Bifunctor, CommutativeApplicative, Functor,
+    Selective, Semigroupal, HKT, HKT2,
+};
+
+/// Either a valid `A`, or the errors which prevented us from getting one.
+///
+/// `Validated` is `Result` with a different `Applicative`: instead of stopping at the first
+/// error, `map2` keeps going and combines the errors from both sides with their `Semigroup`.
+/// That's also why there's no `Monad` instance, since `flat_map` can't run the second step
+/// without a value from the first.
+///
+/// ```rust
+/// # use rust_hkt::data::{NonEmptyVec, Validated, ValidatedKind, ValidatedNel};
+/// # use rust_hkt::part3::Apply;
+/// #[derive(Clone, Debug, PartialEq)]
+/// struct User {
+///     name: String,
+///     age: u8,
+/// }
+///
+/// fn name(name: &str) -> ValidatedNel<String, String> {
+///     if name.is_empty() {
+///         Validated::invalid_nel("name is empty".to_string())
+///     } else {
+///         Validated::Valid(name.to_string())
+///     }
+/// }
+///
+/// fn age(age: i32) -> ValidatedNel<String, u8> {
+///     if age < 0 || age > 150 {
+///         Validated::invalid_nel(format!("{} isn't a valid age", age))
+///     } else {
+///         Validated::Valid(age as u8)
+///     }
+/// }
+///
+/// let user = ValidatedKind::map2(name("Ada"), age(36), |name, age| User { name, age });
+/// assert_eq!(user, Validated::Valid(User { name: "Ada".to_string(), age: 36 }));
+///
+/// let user = ValidatedKind::map2(name(""), age(-1), |name, age| User { name, age });
+/// assert_eq!(user, Validated::Invalid(NonEmptyVec::new(
+///     "name is empty".to_string(),
+///     vec!["-1 isn't a valid age".to_string()],
+/// )));
+///
+/// let errors = ValidatedKind::map3(
+///     Validated::<String, i32>::Invalid("a".to_string()),
+///     Validated::Valid(1),
+///     Validated::<String, i32>::Invalid("b".to_string()),
+///     |a, b, c| a + b + c,
+/// );
+/// assert_eq!(errors, Validated::Invalid("ab".to_string()));
+///
+/// let result: Result<i32, String> = Validated::Valid(1).into();
+/// assert_eq!(result, Ok(1));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Validated<E, A> {
+    Valid(A),
+    Invalid(E),
+}
+
+/// A `Validated` which collects its errors in a `NonEmptyVec`.
+pub type ValidatedNel<E, A> = Validated<NonEmptyVec<E>, A>;
+
+impl<E, A> Validated<E, A> {
+    pub fn is_valid(&self) -> bool {
+        match *self {
+            Validated::Valid(_) => true,
+            Validated::Invalid(_) => false,
+        }
+    }
+
+    pub fn is_invalid(&self) -> bool {
+        !self.is_valid()
+    }
+}
+
+impl<E, A> Validated<NonEmptyVec<E>, A> {
+    /// A single error, ready to be accumulated with others.
+    pub fn invalid_nel(e: E) -> ValidatedNel<E, A> {
+        Validated::Invalid(NonEmptyVec::of(e))
+    }
+}
+
+impl<E, A> From<Result<A, E>> for Validated<E, A> {
+    fn from(result: Result<A, E>) -> Validated<E, A> {
+        match result {
+            Ok(a) => Validated::Valid(a),
+            Err(e) => Validated::Invalid(e),
+        }
+    }
+}
+
+impl<E, A> From<Validated<E, A>> for Result<A, E> {
+    fn from(validated: Validated<E, A>) -> Result<A, E> {
+        match validated {
+            Validated::Valid(a) => Ok(a),
+            Validated::Invalid(e) => Err(e),
+        }
+    }
+}
+
+impl<E, A> From<Either<E, A>> for Validated<E, A> {
+    fn from(either: Either<E, A>) -> Validated<E, A> {
+        match either {
+            Either::Right(a) => Validated::Valid(a),
+            Either::Left(e) => Validated::Invalid(e),
+        }
+    }
+}
+
+impl<E, A> From<Validated<E, A>> for Either<E, A> {
+    fn from(validated: Validated<E, A>) -> Either<E, A> {
+        match validated {
+            Validated::Valid(a) => Either::Right(a),
+            Validated::Invalid(e) => Either::Left(e),
+        }
+    }
+}
+
+/// The kind of `Validated<E, _>`, with the error type fixed.
+pub struct ValidatedKind<E>(PhantomData<E>);
+
+impl<E> HKT for ValidatedKind<E> {
+    type Apply<A> = Validated<E, A>;
+}
+
+/// The kind of `Validated`, with both type parameters free.
+pub struct ValidatedKind2;
+
+impl HKT2 for ValidatedKind2 {
+    type Apply2<E, A> = Validated<E, A>;
+}
+
+impl<'a, E> Functor<'a> for ValidatedKind<E> {
+    fn fmap<A, B, F>(fa: Validated<E, A>, mut f: F) -> Validated<E, B>
+    where
+        F: FnMut(A) -> B,
+    {
+        match fa {
+            Validated::Valid(a) => Validated::Valid(f(a)),
+            Validated::Invalid(e) => Validated::Invalid(e),
+        }
+    }
+}
+
+impl<'a, E> Semigroupal<'a> for ValidatedKind<E>
+where
+    E: Semigroup,
+{
+    fn product<A, B>(fa: Validated<E, A>, fb: Validated<E, B>) -> Validated<E, (A, B)> {
+        match (fa, fb) {
+            (Validated::Valid(a), Validated::Valid(b)) => Validated::Valid((a, b)),
+            (Validated::Invalid(e1), Validated::Invalid(e2)) => Validated::Invalid(e1.combine(e2)),
+            (Validated::Invalid(e), _) | (_, Validated::Invalid(e)) => Validated::Invalid(e),
+        }
+    }
+}
+
+impl<'a, E> Apply<'a> for ValidatedKind<E>
+where
+    E: Semigroup,
+{
+    fn map2<A, B, C, F>(fa: Validated<E, A>, fb: Validated<E, B>, mut f: F) -> Validated<E, C>
+    where
+        F: FnMut(A, B) -> C,
+    {
+        match (fa, fb) {
+            (Validated::Valid(a), Validated::Valid(b)) => Validated::Valid(f(a, b)),
+            (Validated::Invalid(e1), Validated::Invalid(e2)) => Validated::Invalid(e1.combine(e2)),
+            (Validated::Invalid(e), _) | (_, Validated::Invalid(e)) => Validated::Invalid(e),
+        }
+    }
+}
+
+impl<'a, E> Applicative<'a> for ValidatedKind<E>
+where
+    E: Semigroup,
+{
+    fn pure<A>(a: A) -> Validated<E, A> {
+        Validated::Valid(a)
+    }
+}
+
+impl<'a, E> CommutativeApplicative<'a> for ValidatedKind<E> where E: CommutativeSemigroup {}
+
+impl<'a, E> ApplicativeError<'a, E> for ValidatedKind<E>
+where
+    E: Semigroup,
+{
+    fn raise_error<A>(e: E) -> Validated<E, A> {
+        Validated::Invalid(e)
+    }
+
+    fn handle_error_with<A, F>(fa: Validated<E, A>, mut f: F) -> Validated<E, A>
+    where
+        F: FnMut(E) -> Validated<E, A>,
+    {
+        match fa {
+            Validated::Valid(a) => Validated::Valid(a),
+            Validated::Invalid(e) => f(e),
+        }
+    }
+}
+
+/// Runs both sides whatever the `Either` turns out to be, so that every error which could happen
+/// is reported, not just the ones on the branch which was taken.
+impl<'a, E> Selective<'a> for ValidatedKind<E>
+where
+    E: Semigroup,
+{
+    fn select<A, B, F>(fab: Validated<E, Either<A, B>>, ff: Validated<E, F>) -> Validated<E, B>
+    where
+        A: Clone,
+        B: Clone,
+        F: FnOnce(A) -> B + Clone,
+    {
+        ValidatedKind::map2(fab, ff, |ab, f| match ab {
+            Either::Left(a) => f(a),
+            Either::Right(b) => b,
+        })
+    }
+}
+
+impl<'a> Bifunctor<'a> for ValidatedKind2 {
+    fn bimap<A, B, C, D, F, G>(fab: Validated<A, B>, mut f: F, mut g: G) -> Validated<C, D>
+    where
+        F: FnMut(A) -> C,
+        G: FnMut(B) -> D,
+    {
+        match fab {
+            Validated::Valid(b) => Validated::Valid(g(b)),
+            Validated::Invalid(a) => Validated::Invalid(f(a)),
+        }
+    }
+}
+
+impl Bifoldable for ValidatedKind2 {
+    fn bifold_left<A, B, C, F, G>(fab: Validated<A, B>, init: C, mut f: F, mut g: G) -> C
+    where
+        F: FnMut(C, A) -> C,
+        G: FnMut(C, B) -> C,
+    {
+        match fab {
+            Validated::Valid(b) => g(init, b),
+            Validated::Invalid(a) => f(init, a),
+        }
+    }
+}
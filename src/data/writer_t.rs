@@ -0,0 +1,363 @@
+use std::fmt;
+use std::marker::PhantomData;
+
+use kernel::{Monoid, Semigroup};
+use part3::{
+    Applicative, Apply, Ask, FlatMap, FlatMapOnce, Functor, Monad, MonadReader, MonadState,
+    MonadTrans, MonadWriter, Semigroupal, Tell, HKT,
+};
+
+use super::{Either, IdentityKind};
+
+/// A computation in `F` which also produces a log `W` alongside its result: an `F<(W, A)>`.
+///
+/// The log is a `Monoid`, and chaining computations combines their logs in order:
+///
+/// ```rust
+/// # use rust_hkt::data::{Identity, Writer, WriterT};
+/// # use rust_hkt::part3::{OptionKind, VecKind};
+/// let half = |i: i32| {
+///     if i % 2 == 0 {
+///         WriterT::new(Some((vec![format!("halved {}", i)], i / 2)))
+///     } else {
+///         WriterT::new(None)
+///     }
+/// };
+///
+/// let quarter = WriterT::<Vec<String>, OptionKind, _>::pure(12).flat_map(half).flat_map(half);
+/// assert_eq!(quarter.run(), Some((vec!["halved 12".to_string(), "halved 6".to_string()], 3)));
+/// assert_eq!(WriterT::pure(6).flat_map(half).flat_map(half).run(), None);
+///
+/// let logged: Writer<String, i32> = WriterT::<_, _, ()>::tell("one, ".to_string())
+///     .flat_map(|()| WriterT::tell("two".to_string()))
+///     .map(|()| 2);
+/// assert_eq!(logged.listen().run(), Identity(("one, two".to_string(), ("one, two".to_string(), 2))));
+///
+/// let censored = WriterT::<String, VecKind, _>::new(vec![("secret".to_string(), 1), ("public".to_string(), 2)])
+///     .censor(|log| if log == "secret" { String::new() } else { log });
+/// assert_eq!(censored.run(), vec![(String::new(), 1), ("public".to_string(), 2)]);
+/// ```
+///
+/// `flat_map` may need to combine the same log with more than one continuation (think `Vec`),
+/// so the monad instance needs `W: Clone`. Only `pure` needs an empty log, so with a log which
+/// is just a `Semigroup`, `WriterTKind` is still a `FlatMap`.
+pub struct WriterT<W, F: HKT, A> {
+    run: F::Apply<(W, A)>,
+}
+
+/// `WriterT` without any other effect.
+pub type Writer<W, A> = WriterT<W, IdentityKind, A>;
+
+impl<W, F: HKT, A> WriterT<W, F, A> {
+    pub fn new(run: F::Apply<(W, A)>) -> WriterT<W, F, A> {
+        WriterT { run }
+    }
+
+    pub fn run(self) -> F::Apply<(W, A)> {
+        self.run
+    }
+
+    pub fn pure<'a>(a: A) -> WriterT<W, F, A>
+    where
+        F: Applicative<'a>,
+        W: Monoid + 'a,
+        A: 'a,
+    {
+        WriterT::new(F::pure((W::empty(), a)))
+    }
+
+    pub fn map<'a, B, K>(self, mut f: K) -> WriterT<W, F, B>
+    where
+        F: Functor<'a>,
+        W: 'a,
+        A: 'a,
+        B: 'a,
+        K: FnMut(A) -> B + 'a,
+    {
+        WriterT::new(F::fmap(self.run, move |(w, a)| (w, f(a))))
+    }
+
+    pub fn flat_map<'a, B, K>(self, mut f: K) -> WriterT<W, F, B>
+    where
+        F: FlatMap<'a>,
+        W: Semigroup + Clone + 'a,
+        A: 'a,
+        B: 'a,
+        K: FnMut(A) -> WriterT<W, F, B> + 'a,
+    {
+        WriterT::new(F::flat_map(self.run, move |(w, a)| {
+            F::fmap(f(a).run, move |(more, b)| (w.clone().combine(more), b))
+        }))
+    }
+
+    /// Makes the log so far available as part of the result.
+    pub fn listen<'a>(self) -> WriterT<W, F, (W, A)>
+    where
+        F: Functor<'a>,
+        W: Clone + 'a,
+        A: 'a,
+    {
+        WriterT::new(F::fmap(self.run, |(w, a)| (w.clone(), (w, a))))
+    }
+
+    /// Rewrites the log so far.
+    pub fn censor<'a, K>(self, mut f: K) -> WriterT<W, F, A>
+    where
+        F: Functor<'a>,
+        W: 'a,
+        A: 'a,
+        K: FnMut(W) -> W + 'a,
+    {
+        WriterT::new(F::fmap(self.run, move |(w, a)| (f(w), a)))
+    }
+}
+
+impl<W, F: HKT> WriterT<W, F, ()> {
+    /// Appends to the log.
+    pub fn tell<'a>(w: W) -> WriterT<W, F, ()>
+    where
+        F: Applicative<'a>,
+        W: 'a,
+    {
+        WriterT::new(F::pure((w, ())))
+    }
+}
+
+impl<W, F: HKT, A> Clone for WriterT<W, F, A>
+where
+    F::Apply<(W, A)>: Clone,
+{
+    fn clone(&self) -> WriterT<W, F, A> {
+        WriterT::new(self.run.clone())
+    }
+}
+
+impl<W, F: HKT, A> fmt::Debug for WriterT<W, F, A>
+where
+    F::Apply<(W, A)>: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("WriterT").field(&self.run).finish()
+    }
+}
+
+impl<W, F: HKT, A> PartialEq for WriterT<W, F, A>
+where
+    F::Apply<(W, A)>: PartialEq,
+{
+    fn eq(&self, other: &WriterT<W, F, A>) -> bool {
+        self.run == other.run
+    }
+}
+
+/// The kind of `WriterT<W, F, _>`.
+pub struct WriterTKind<W, F>(PhantomData<(W, F)>);
+
+impl<W, F: HKT> HKT for WriterTKind<W, F> {
+    type Apply<A> = WriterT<W, F, A>;
+}
+
+impl<'a, W: 'a, F: Functor<'a>> Functor<'a> for WriterTKind<W, F> {
+    fn fmap<A, B, K>(fa: WriterT<W, F, A>, f: K) -> WriterT<W, F, B>
+    where
+        A: 'a,
+        B: 'a,
+        K: FnMut(A) -> B + 'a,
+    {
+        fa.map(f)
+    }
+}
+
+impl<'a, W, F> Semigroupal<'a> for WriterTKind<W, F>
+where
+    W: Semigroup + Clone + 'a,
+    F: Apply<'a>,
+{
+    fn product<A, B>(fa: WriterT<W, F, A>, fb: WriterT<W, F, B>) -> WriterT<W, F, (A, B)>
+    where
+        A: Clone + 'a,
+        B: Clone + 'a,
+    {
+        WriterT::new(F::map2(fa.run, fb.run, |(w, a), (more, b)| {
+            (w.combine(more), (a, b))
+        }))
+    }
+}
+
+impl<'a, W, F> Apply<'a> for WriterTKind<W, F>
+where
+    W: Semigroup + Clone + 'a,
+    F: Apply<'a>,
+{
+    fn map2<A, B, C, K>(fa: WriterT<W, F, A>, fb: WriterT<W, F, B>, mut f: K) -> WriterT<W, F, C>
+    where
+        A: Clone + 'a,
+        B: Clone + 'a,
+        C: 'a,
+        K: FnMut(A, B) -> C + 'a,
+    {
+        WriterT::new(F::map2(fa.run, fb.run, move |(w, a), (more, b)| {
+            (w.combine(more), f(a, b))
+        }))
+    }
+}
+
+impl<'a, W, F> Applicative<'a> for WriterTKind<W, F>
+where
+    W: Monoid + Clone + 'a,
+    F: Applicative<'a>,
+{
+    fn pure<A: 'a>(a: A) -> WriterT<W, F, A> {
+        WriterT::pure(a)
+    }
+}
+
+impl<'a, W, F> FlatMap<'a> for WriterTKind<W, F>
+where
+    W: Semigroup + Clone + 'a,
+    F: FlatMap<'a>,
+{
+    fn flat_map<A, B, K>(fa: WriterT<W, F, A>, f: K) -> WriterT<W, F, B>
+    where
+        A: 'a,
+        B: 'a,
+        K: FnMut(A) -> WriterT<W, F, B> + 'a,
+    {
+        fa.flat_map(f)
+    }
+}
+
+impl<'a, W, F> FlatMapOnce<'a> for WriterTKind<W, F>
+where
+    W: Semigroup + Clone + 'a,
+    F: FlatMapOnce<'a>,
+{
+    fn flat_map_once<A, B, K>(fa: WriterT<W, F, A>, f: K) -> WriterT<W, F, B>
+    where
+        A: 'a,
+        B: 'a,
+        K: FnOnce(A) -> WriterT<W, F, B> + 'a,
+    {
+        WriterT::new(F::flat_map_once(fa.run, move |(w, a)| {
+            F::fmap(f(a).run, move |(more, b)| (w.clone().combine(more), b))
+        }))
+    }
+}
+
+/// Carries the log through the `tail_rec_m` of the monad underneath, so it's as stack-safe as
+/// that one is:
+///
+/// ```rust
+/// # use rust_hkt::data::{Either, WriterT, WriterTKind};
+/// # use rust_hkt::part3::{Monad, OptionKind};
+/// let count = WriterTKind::<Vec<u8>, OptionKind>::tail_rec_m(1_000_000, |i: u32| {
+///     let log = if i % 250_000 == 0 { vec![1] } else { vec![] };
+///     WriterT::new(Some((log, if i == 0 { Either::Right(i) } else { Either::Left(i - 1) })))
+/// });
+/// assert_eq!(count.run(), Some((vec![1, 1, 1, 1, 1], 0)));
+/// ```
+impl<'a, W, F> Monad<'a> for WriterTKind<W, F>
+where
+    W: Monoid + Clone + 'a,
+    F: Monad<'a>,
+{
+    fn tail_rec_m<A, B, K>(a: A, mut f: K) -> WriterT<W, F, B>
+    where
+        A: 'a,
+        B: 'a,
+        K: FnMut(A) -> WriterT<W, F, Either<A, B>> + 'a,
+    {
+        WriterT::new(F::tail_rec_m((W::empty(), a), move |(w, a)| {
+            F::fmap(f(a).run, move |(more, step)| {
+                let w = w.clone().combine(more);
+                match step {
+                    Either::Left(a) => Either::Left((w, a)),
+                    Either::Right(b) => Either::Right((w, b)),
+                }
+            })
+        }))
+    }
+}
+
+impl<'a, W, F> MonadTrans<'a> for WriterTKind<W, F>
+where
+    W: Monoid + 'a,
+    F: Monad<'a>,
+{
+    type Inner = F;
+
+    fn lift<A: 'a>(fa: F::Apply<A>) -> WriterT<W, F, A> {
+        WriterT::new(F::fmap(fa, |a| (W::empty(), a)))
+    }
+}
+
+/// Lifts the state of the monad underneath.
+impl<'a, W, F, S> MonadState<'a, S> for WriterTKind<W, F>
+where
+    W: Monoid + Clone + 'a,
+    F: MonadState<'a, S>,
+    S: 'a,
+{
+    fn get() -> WriterT<W, F, S> {
+        <Self as MonadTrans>::lift(F::get())
+    }
+
+    fn set(s: S) -> WriterT<W, F, ()> {
+        <Self as MonadTrans>::lift(F::set(s))
+    }
+}
+
+/// Lifts the environment of the monad underneath.
+impl<'a, W, F, R> Ask<'a, R> for WriterTKind<W, F>
+where
+    W: Monoid + Clone + 'a,
+    F: Ask<'a, R>,
+    R: 'a,
+{
+    fn ask() -> WriterT<W, F, R> {
+        WriterT::new(F::fmap(F::ask(), |r| (W::empty(), r)))
+    }
+}
+
+impl<'a, W, F, R> MonadReader<'a, R> for WriterTKind<W, F>
+where
+    W: Monoid + Clone + 'a,
+    F: MonadReader<'a, R>,
+    R: 'a,
+{
+    fn local<A, K>(fa: WriterT<W, F, A>, f: K) -> WriterT<W, F, A>
+    where
+        A: 'a,
+        K: FnMut(R) -> R + 'a,
+    {
+        WriterT::new(F::local(fa.run(), f))
+    }
+}
+
+impl<'a, W, F> Tell<'a, W> for WriterTKind<W, F>
+where
+    W: Monoid + Clone + 'a,
+    F: Applicative<'a>,
+{
+    fn tell(w: W) -> WriterT<W, F, ()> {
+        WriterT::tell(w)
+    }
+}
+
+impl<'a, W, F> MonadWriter<'a, W> for WriterTKind<W, F>
+where
+    W: Monoid + Clone + 'a,
+    F: Monad<'a>,
+{
+    fn listen<A: 'a>(fa: WriterT<W, F, A>) -> WriterT<W, F, (W, A)> {
+        fa.listen()
+    }
+
+    fn censor<A, K>(fa: WriterT<W, F, A>, f: K) -> WriterT<W, F, A>
+    where
+        A: 'a,
+        K: FnMut(W) -> W + 'a,
+    {
+        fa.censor(f)
+    }
+}
@@ -0,0 +1,88 @@
+use std::marker::PhantomData;
+
+use part3::{Functor, HKT};
+
+/// A functor value, kept as "whatever you'd like to map it with, I'll give you back an `F<B>`".
+///
+/// The Yoneda lemma says that for any functor `F`, a function which is generic over `B`
+///
+/// ```text
+/// fn run<B>(k: impl FnMut(A) -> B) -> F<B>
+/// ```
+///
+/// carries exactly as much information as an `F<A>`. Going one way, `lift` turns an `F<A>` into
+/// such a function (`run(k)` is `fmap(fa, k)`). Going the other, `lower` calls it with the
+/// identity function.
+///
+/// Rust has no values which are generic, so the `B` can only live on a method, and what's stored
+/// is what that method needs: the original `F<X>` and the functions passed to `map` so far, fused
+/// into one. That makes `Yoneda` look a lot like `Coyoneda`; the difference is where the `Functor`
+/// comes in. `Coyoneda` turns any type constructor into a functor and only asks for the real one
+/// when lowering, whereas `Yoneda` only exists for a functor in the first place.
+///
+/// ```rust
+/// # use rust_hkt::data::Yoneda;
+/// # use rust_hkt::part3::VecKind;
+/// let yoneda = Yoneda::<VecKind, _, _>::lift(vec![1, 2, 3]).map(|i| i * 2);
+/// assert_eq!(yoneda.run(|i| i.to_string()), vec!["2", "4", "6"]);
+///
+/// let round_trip = Yoneda::<VecKind, _, _>::lift(vec![1, 2, 3]).lower();
+/// assert_eq!(round_trip, vec![1, 2, 3]);
+/// ```
+pub struct Yoneda<'a, F: HKT, X, A> {
+    fx: F::Apply<X>,
+    f: Box<dyn FnMut(X) -> A + 'a>,
+}
+
+impl<'a, F: Functor<'a>, X: 'a> Yoneda<'a, F, X, X> {
+    pub fn lift(fx: F::Apply<X>) -> Yoneda<'a, F, X, X> {
+        Yoneda {
+            fx,
+            f: Box::new(|x| x),
+        }
+    }
+}
+
+impl<'a, F: Functor<'a>, X: 'a, A: 'a> Yoneda<'a, F, X, A> {
+    pub fn run<B, K>(self, mut k: K) -> F::Apply<B>
+    where
+        B: 'a,
+        K: FnMut(A) -> B + 'a,
+    {
+        let mut f = self.f;
+        F::fmap(self.fx, move |x| k(f(x)))
+    }
+
+    pub fn lower(self) -> F::Apply<A> {
+        F::fmap(self.fx, self.f)
+    }
+
+    pub fn map<B, G>(self, mut g: G) -> Yoneda<'a, F, X, B>
+    where
+        G: FnMut(A) -> B + 'a,
+    {
+        let mut f = self.f;
+        Yoneda {
+            fx: self.fx,
+            f: Box::new(move |x| g(f(x))),
+        }
+    }
+}
+
+/// The kind of `Yoneda<'a, F, X, _>`.
+pub struct YonedaKind<'a, F, X>(PhantomData<(&'a (), F, X)>);
+
+impl<'a, F: HKT, X> HKT for YonedaKind<'a, F, X> {
+    type Apply<A> = Yoneda<'a, F, X, A>;
+}
+
+impl<'a, F: Functor<'a>, X: 'a> Functor<'a> for YonedaKind<'a, F, X> {
+    fn fmap<A, B, G>(fa: Yoneda<'a, F, X, A>, g: G) -> Yoneda<'a, F, X, B>
+    where
+        A: 'a,
+        B: 'a,
+        G: FnMut(A) -> B + 'a,
+    {
+        fa.map(g)
+    }
+}
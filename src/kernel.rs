@@ -0,0 +1,28 @@
+//! # Kernel
+//!
+//! The typeclasses in this module don't talk about type constructors at all, they describe
+//! plain types, so they don't need any HKT encoding. They're still the building blocks for a
+//! lot of the higher kinded machinery: folding a structure into a single value, accumulating
+//! errors and so on all need a way to combine two values into one.
+
+mod all_any;
+mod band;
+mod dual;
+mod endo;
+mod first_last;
+mod group;
+mod min_max;
+mod monoid;
+mod semigroup;
+mod sum_product;
+
+pub use self::all_any::{All, Any};
+pub use self::band::{Band, Semilattice};
+pub use self::dual::Dual;
+pub use self::endo::Endo;
+pub use self::first_last::{First, Last};
+pub use self::group::Group;
+pub use self::min_max::{Max, Min};
+pub use self::monoid::{combine_all, CommutativeMonoid, Monoid};
+pub use self::semigroup::{CommutativeSemigroup, Semigroup};
+pub use self::sum_product::{Product, SaturatingProduct, SaturatingSum, Sum};
@@ -0,0 +1,57 @@
+use super::{Band, CommutativeSemigroup, Monoid, Semigroup};
+
+/// Wraps a `bool` to combine it with `&&`, with `true` as the empty value.
+///
+/// ```rust
+/// # use rust_hkt::kernel::{All, Monoid, Semigroup};
+/// # use rust_hkt::part3::{Foldable, VecKind};
+/// assert_eq!(All(true).combine(All(false)), All(false));
+/// assert_eq!(All::empty(), All(true));
+/// assert_eq!(VecKind::fold_map(vec![2, 4, 6], |i| All(i % 2 == 0)), All(true));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct All(pub bool);
+
+/// Wraps a `bool` to combine it with `||`, with `false` as the empty value.
+///
+/// ```rust
+/// # use rust_hkt::kernel::{Any, Monoid, Semigroup};
+/// # use rust_hkt::part3::{Foldable, OptionKind};
+/// assert_eq!(Any(true).combine(Any(false)), Any(true));
+/// assert_eq!(Any::empty(), Any(false));
+/// assert_eq!(OptionKind::fold_map(None, |i: i32| Any(i > 0)), Any(false));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Any(pub bool);
+
+impl Semigroup for All {
+    fn combine(self, other: All) -> All {
+        All(self.0 && other.0)
+    }
+}
+
+impl Monoid for All {
+    fn empty() -> All {
+        All(true)
+    }
+}
+
+impl CommutativeSemigroup for All {}
+
+impl Band for All {}
+
+impl Semigroup for Any {
+    fn combine(self, other: Any) -> Any {
+        Any(self.0 || other.0)
+    }
+}
+
+impl Monoid for Any {
+    fn empty() -> Any {
+        Any(false)
+    }
+}
+
+impl CommutativeSemigroup for Any {}
+
+impl Band for Any {}
@@ -0,0 +1,64 @@
+use std::cmp::Ordering;
+use std::collections::{BTreeSet, HashSet};
+use std::hash::Hash;
+
+use super::{CommutativeSemigroup, Semigroup};
+
+/// A `Semigroup` whose `combine` is idempotent, combining a value with itself changes nothing:
+///
+/// `a.combine(a) == a`
+///
+/// Whatever has already been combined in can be combined in again harmlessly, so duplicates
+/// don't need tracking.
+///
+/// ```rust
+/// # use rust_hkt::kernel::{Max, Semigroup};
+/// assert_eq!(Max(3).combine(Max(3)), Max(3));
+/// assert_eq!(Some(Max(1)).combine(Some(Max(1))), Some(Max(1)));
+/// ```
+pub trait Band: Semigroup {}
+
+/// A `Band` which is also commutative: values can be combined in any order, any number of times.
+///
+/// That makes it the right shape for merging replicas of some state which may see the same
+/// updates in different orders, or more than once, the idea behind state-based CRDTs:
+///
+/// ```rust
+/// # use std::collections::BTreeSet;
+/// # use rust_hkt::kernel::{combine_all, Max, Monoid, Semigroup, Semilattice};
+/// fn merge<S: Semilattice + Monoid>(replicas: Vec<S>) -> S {
+///     combine_all(replicas)
+/// }
+///
+/// let a: BTreeSet<_> = vec!["x", "y"].into_iter().collect();
+/// let b: BTreeSet<_> = vec!["y", "z"].into_iter().collect();
+/// // Merging the same replica twice, in either order, makes no difference.
+/// assert_eq!(merge(vec![a.clone(), b.clone(), a.clone()]), merge(vec![b.clone(), a.clone()]));
+///
+/// let clock = (Max(3), Max(1)).combine((Max(2), Max(5)));
+/// assert_eq!(clock, (Max(3), Max(5)));
+/// ```
+pub trait Semilattice: Band + CommutativeSemigroup {}
+
+impl<S> Semilattice for S where S: Band + CommutativeSemigroup {}
+
+impl<T> Band for HashSet<T> where T: Eq + Hash {}
+
+impl<T> Band for BTreeSet<T> where T: Ord {}
+
+impl<A> Band for Option<A> where A: Band {}
+
+impl Band for Ordering {}
+
+impl Band for () {}
+
+macro_rules! tuple_band {
+    ($($name:ident),+) => {
+        impl<$($name),+> Band for ($($name,)+) where $($name: Band),+ {}
+    };
+}
+
+tuple_band!(A);
+tuple_band!(A, B);
+tuple_band!(A, B, C);
+tuple_band!(A, B, C, D);
@@ -0,0 +1,64 @@
+use super::{Band, CommutativeSemigroup, Group, Monoid, Semigroup};
+
+/// Wraps a value to combine it the other way round: `Dual(a).combine(Dual(b)) == Dual(b.combine(a))`.
+///
+/// ```rust
+/// # use rust_hkt::kernel::{Dual, Semigroup};
+/// assert_eq!(Dual(vec![1]).combine(Dual(vec![2])), Dual(vec![2, 1]));
+/// assert_eq!(Dual("a".to_string()).combine(Dual("b".to_string())).0, "ba");
+/// ```
+///
+/// `fold_map` goes from left to right, so `fold_map`ping into a `Dual` combines from right to
+/// left instead, which gives a right fold for any monoid without a separate `fold_right`:
+///
+/// ```rust
+/// # use rust_hkt::kernel::Dual;
+/// # use rust_hkt::part3::{Foldable, VecKind};
+/// let words = vec!["a", "b", "c"];
+/// let right = VecKind::fold_right(words.clone(), String::new(), |w, acc| acc + w);
+/// assert_eq!(VecKind::fold_map(words, |w| Dual(w.to_string())).0, right);
+/// ```
+///
+/// Together with `Endo` it goes the other way too: `fold_map`ping into `Endo` nests the
+/// functions like `fold_right` does, and `Dual` turns that back into a left fold:
+///
+/// ```rust
+/// # use rust_hkt::kernel::{Dual, Endo};
+/// # use rust_hkt::part3::{Foldable, VecKind};
+/// let digits = |i: i32| Endo::new(move |acc: String| acc + &i.to_string());
+/// assert_eq!(VecKind::fold_map(vec![1, 2, 3], digits).run(String::new()), "321");
+/// assert_eq!(VecKind::fold_map(vec![1, 2, 3], |i| Dual(digits(i))).0.run(String::new()), "123");
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Dual<M>(pub M);
+
+impl<M> Semigroup for Dual<M>
+where
+    M: Semigroup,
+{
+    fn combine(self, other: Dual<M>) -> Dual<M> {
+        Dual(other.0.combine(self.0))
+    }
+}
+
+impl<M> Monoid for Dual<M>
+where
+    M: Monoid,
+{
+    fn empty() -> Dual<M> {
+        Dual(M::empty())
+    }
+}
+
+impl<M> CommutativeSemigroup for Dual<M> where M: CommutativeSemigroup {}
+
+impl<M> Band for Dual<M> where M: Band {}
+
+impl<M> Group for Dual<M>
+where
+    M: Group,
+{
+    fn inverse(self) -> Dual<M> {
+        Dual(self.0.inverse())
+    }
+}
@@ -0,0 +1,77 @@
+use std::collections::VecDeque;
+
+use super::{Monoid, Semigroup};
+
+/// A function from a type to itself, combined by composition with the identity function as the
+/// empty value.
+///
+/// `Endo(f).combine(Endo(g))` is `f` after `g`, so folding a structure into an `Endo` lines its
+/// functions up from right to left. That's all `fold_right` is: `Foldable`'s default
+/// implementation maps each `a` to `Endo(|b| f(a, b))`, `fold_map`s them, and runs the result
+/// on the initial value.
+///
+/// ```rust
+/// # use rust_hkt::kernel::{combine_all, Endo, Monoid, Semigroup};
+/// let inc = Endo::new(|i: i32| i + 1);
+/// let double = Endo::new(|i: i32| i * 2);
+/// assert_eq!(inc.combine(double).run(5), 11);
+/// assert_eq!(Endo::<String>::empty().run("same".into()), "same");
+///
+/// let steps = vec![Endo::new(|s: String| s + "a"), Endo::new(|s: String| s + "b")];
+/// assert_eq!(combine_all(steps).run(String::new()), "ba");
+/// ```
+///
+/// Composing two functions into a closure which calls both would nest one call per function
+/// when it runs, so instead an `Endo` keeps the functions it's made of in order, and `run` calls
+/// them one after the other. Running a million of them doesn't grow the stack:
+///
+/// ```rust
+/// # use rust_hkt::kernel::{Endo, Monoid, Semigroup};
+/// let mut count = Endo::empty();
+/// for _ in 0..1_000_000 {
+///     count = count.combine(Endo::new(|i: u64| i + 1));
+/// }
+/// assert_eq!(count.run(0), 1_000_000);
+/// ```
+pub struct Endo<'a, A> {
+    // Outermost first, so the last one runs first.
+    fns: VecDeque<Box<dyn Fn(A) -> A + 'a>>,
+}
+
+impl<'a, A> Endo<'a, A> {
+    pub fn new<F>(f: F) -> Endo<'a, A>
+    where
+        F: Fn(A) -> A + 'a,
+    {
+        let mut fns = VecDeque::with_capacity(1);
+        fns.push_back(Box::new(f) as Box<dyn Fn(A) -> A + 'a>);
+        Endo { fns }
+    }
+
+    pub fn run(&self, a: A) -> A {
+        self.fns.iter().rev().fold(a, |a, f| f(a))
+    }
+}
+
+impl<'a, A: 'a> Semigroup for Endo<'a, A> {
+    fn combine(mut self, mut other: Endo<'a, A>) -> Endo<'a, A> {
+        // Moves the shorter list onto the longer one, so a fold in either direction stays linear.
+        if self.fns.len() >= other.fns.len() {
+            self.fns.append(&mut other.fns);
+            self
+        } else {
+            while let Some(f) = self.fns.pop_back() {
+                other.fns.push_front(f);
+            }
+            other
+        }
+    }
+}
+
+impl<'a, A: 'a> Monoid for Endo<'a, A> {
+    fn empty() -> Endo<'a, A> {
+        Endo {
+            fns: VecDeque::new(),
+        }
+    }
+}
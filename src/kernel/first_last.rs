@@ -0,0 +1,62 @@
+use super::{Band, Monoid, Semigroup};
+
+/// Wraps an `Option` to combine it by keeping the first `Some`, with `None` as the empty value.
+///
+/// Handy for merging layers of settings where the earliest one to say something wins:
+///
+/// ```rust
+/// # use rust_hkt::kernel::{First, Semigroup};
+/// # use rust_hkt::part3::{Foldable, VecKind};
+/// let flags = First(None);
+/// let env = First(Some("info"));
+/// let file = First(Some("warn"));
+/// assert_eq!(flags.combine(env).combine(file), First(Some("info")));
+///
+/// let found = VecKind::fold_map(vec![1, 4, 6, 7], |i| First(Some(i).filter(|i| i % 2 == 0)));
+/// assert_eq!(found, First(Some(4)));
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct First<A>(pub Option<A>);
+
+/// Wraps an `Option` to combine it by keeping the last `Some`, with `None` as the empty value.
+///
+/// ```rust
+/// # use rust_hkt::kernel::{Last, Monoid, Semigroup};
+/// # use rust_hkt::part3::{Foldable, VecKind};
+/// assert_eq!(Last(Some(1)).combine(Last(None)), Last(Some(1)));
+/// assert_eq!(Last(Some(1)).combine(Last(Some(2))), Last(Some(2)));
+/// assert_eq!(Last::<i32>::empty(), Last(None));
+///
+/// let found = VecKind::fold_map(vec![1, 4, 6, 7], |i| Last(Some(i).filter(|i| i % 2 == 0)));
+/// assert_eq!(found, Last(Some(6)));
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Last<A>(pub Option<A>);
+
+impl<A> Semigroup for First<A> {
+    fn combine(self, other: First<A>) -> First<A> {
+        First(self.0.or(other.0))
+    }
+}
+
+impl<A> Monoid for First<A> {
+    fn empty() -> First<A> {
+        First(None)
+    }
+}
+
+impl<A> Band for First<A> {}
+
+impl<A> Semigroup for Last<A> {
+    fn combine(self, other: Last<A>) -> Last<A> {
+        Last(other.0.or(self.0))
+    }
+}
+
+impl<A> Monoid for Last<A> {
+    fn empty() -> Last<A> {
+        Last(None)
+    }
+}
+
+impl<A> Band for Last<A> {}
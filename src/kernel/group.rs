@@ -0,0 +1,70 @@
+use std::num::Wrapping;
+use std::ops::{Add, Neg, Sub};
+
+use super::Monoid;
+
+/// A `Group` is a `Monoid` where every value can be undone, with its `inverse`:
+///
+/// `a.combine(a.inverse()) == M::empty() == a.inverse().combine(a)`
+///
+/// `remove` combines with the inverse, which takes a value back out of a total without having to
+/// start again from the beginning:
+///
+/// ```rust
+/// # use rust_hkt::kernel::{Group, Monoid, Semigroup};
+/// # use rust_hkt::part3::{Foldable, VecKind};
+/// use std::num::Wrapping;
+///
+/// let deposits = vec![10, 25, -5];
+/// let balance = VecKind::fold_map(deposits, Wrapping);
+/// assert_eq!(balance, Wrapping(30));
+///
+/// // Undo the last deposit.
+/// assert_eq!(balance.remove(Wrapping(-5)), Wrapping(35));
+/// assert_eq!(Wrapping(3u8).combine(Wrapping(3u8).inverse()), Wrapping::empty());
+/// assert_eq!((Wrapping(1), ()).inverse(), (Wrapping(-1), ()));
+/// ```
+pub trait Group: Monoid + Sized {
+    fn inverse(self) -> Self;
+
+    /// `self.combine(other.inverse())`.
+    fn remove(self, other: Self) -> Self {
+        self.combine(other.inverse())
+    }
+}
+
+impl<T> Group for Wrapping<T>
+where
+    T: Default,
+    Wrapping<T>: Add<Output = Wrapping<T>> + Sub<Output = Wrapping<T>> + Neg<Output = Wrapping<T>>,
+{
+    fn inverse(self) -> Wrapping<T> {
+        -self
+    }
+
+    fn remove(self, other: Wrapping<T>) -> Wrapping<T> {
+        self - other
+    }
+}
+
+impl Group for () {
+    fn inverse(self) {}
+}
+
+macro_rules! tuple_group {
+    ($($name:ident $idx:tt),+) => {
+        impl<$($name),+> Group for ($($name,)+)
+        where
+            $($name: Group),+
+        {
+            fn inverse(self) -> Self {
+                ($(self.$idx.inverse(),)+)
+            }
+        }
+    };
+}
+
+tuple_group!(A 0);
+tuple_group!(A 0, B 1);
+tuple_group!(A 0, B 1, C 2);
+tuple_group!(A 0, B 1, C 2, D 3);
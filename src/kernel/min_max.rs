@@ -0,0 +1,149 @@
+use std::cmp;
+
+use super::{Band, CommutativeSemigroup, Monoid, Semigroup};
+
+/// Wraps a value to combine it by keeping the smaller one.
+///
+/// It's a monoid for the bounded primitives, with the largest value as `empty`, so the minimum
+/// of any `Foldable` is a `fold_map` away.
+///
+/// ```rust
+/// # use rust_hkt::kernel::{Min, Monoid, Semigroup};
+/// # use rust_hkt::part3::{Foldable, VecKind};
+/// assert_eq!(Min(3).combine(Min(1)), Min(1));
+/// assert_eq!(Min("b").combine(Min("a")).0, "a");
+/// assert_eq!(Min::<u8>::empty(), Min(255));
+/// assert_eq!(Min::<u8>::default(), Min::empty());
+/// assert_eq!(VecKind::fold_map(vec![4, 2, 7], Min), Min(2));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Min<T>(pub T);
+
+/// Wraps a value to combine it by keeping the larger one.
+///
+/// ```rust
+/// # use rust_hkt::kernel::{Max, Semigroup};
+/// assert_eq!(Max(3).combine(Max(1)), Max(3));
+/// assert_eq!(Some(Max(2)).combine(None), Some(Max(2)));
+/// ```
+///
+/// For unbounded types, fold into `Option<Max<T>>` instead, which is empty for an empty
+/// structure:
+///
+/// ```rust
+/// # use rust_hkt::kernel::Max;
+/// # use rust_hkt::part3::{Foldable, OptionKind, VecKind};
+/// fn longest<F: Foldable>(fa: F::Apply<String>) -> Option<String> {
+///     F::fold_map(fa, |s| Some(Max((s.len(), s)))).map(|Max((_, s))| s)
+/// }
+/// assert_eq!(longest::<VecKind>(vec!["ab".into(), "abc".into()]), Some("abc".into()));
+/// assert_eq!(longest::<OptionKind>(None), None);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Max<T>(pub T);
+
+impl<T> Semigroup for Min<T>
+where
+    T: Ord,
+{
+    fn combine(self, other: Min<T>) -> Min<T> {
+        Min(cmp::min(self.0, other.0))
+    }
+}
+
+impl<T> Semigroup for Max<T>
+where
+    T: Ord,
+{
+    fn combine(self, other: Max<T>) -> Max<T> {
+        Max(cmp::max(self.0, other.0))
+    }
+}
+
+impl<T> CommutativeSemigroup for Min<T> where T: Ord {}
+
+impl<T> CommutativeSemigroup for Max<T> where T: Ord {}
+
+impl<T> Band for Min<T> where T: Ord {}
+
+impl<T> Band for Max<T> where T: Ord {}
+
+macro_rules! bounded_instances {
+    ($($t:ty)*) => {
+        $(
+            impl Monoid for Min<$t> {
+                fn empty() -> Min<$t> {
+                    Min(<$t>::MAX)
+                }
+            }
+
+            impl Monoid for Max<$t> {
+                fn empty() -> Max<$t> {
+                    Max(<$t>::MIN)
+                }
+            }
+
+            impl Default for Min<$t> {
+                fn default() -> Min<$t> {
+                    Min::empty()
+                }
+            }
+
+            impl Default for Max<$t> {
+                fn default() -> Max<$t> {
+                    Max::empty()
+                }
+            }
+        )*
+    };
+}
+
+bounded_instances!(u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize char);
+
+impl Monoid for Min<bool> {
+    fn empty() -> Min<bool> {
+        Min(true)
+    }
+}
+
+impl Monoid for Max<bool> {
+    fn empty() -> Max<bool> {
+        Max(false)
+    }
+}
+
+impl Monoid for Min<()> {
+    fn empty() -> Min<()> {
+        Min(())
+    }
+}
+
+impl Monoid for Max<()> {
+    fn empty() -> Max<()> {
+        Max(())
+    }
+}
+
+impl Default for Min<bool> {
+    fn default() -> Min<bool> {
+        Min::empty()
+    }
+}
+
+impl Default for Max<bool> {
+    fn default() -> Max<bool> {
+        Max::empty()
+    }
+}
+
+impl Default for Min<()> {
+    fn default() -> Min<()> {
+        Min::empty()
+    }
+}
+
+impl Default for Max<()> {
+    fn default() -> Max<()> {
+        Max::empty()
+    }
+}
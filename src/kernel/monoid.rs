@@ -0,0 +1,150 @@
+use std::borrow::Cow;
+use std::cmp::Ordering;
+use std::collections::{BTreeSet, HashSet, LinkedList};
+use std::hash::Hash;
+use std::num::Wrapping;
+use std::ops::Add;
+
+use super::{CommutativeSemigroup, Semigroup};
+
+/// A `Monoid` is a `Semigroup` with an identity element, `empty`:
+///
+/// `M::empty().combine(a) == a == a.combine(M::empty())`
+///
+/// ```rust
+/// # use rust_hkt::kernel::{Monoid, Semigroup};
+/// use std::borrow::Cow;
+/// use std::num::Wrapping;
+///
+/// assert_eq!(String::empty(), "");
+/// assert!(matches!(Cow::<str>::empty(), Cow::Borrowed("")));
+/// assert_eq!(Vec::<i32>::empty().combine(vec![1]), vec![1]);
+/// assert_eq!(Option::<String>::empty(), None);
+/// assert_eq!(<(String, Wrapping<i32>)>::empty(), (String::new(), Wrapping(0)));
+/// ```
+pub trait Monoid: Semigroup {
+    fn empty() -> Self;
+}
+
+/// A `Monoid` whose `combine` is commutative, see `CommutativeSemigroup`.
+///
+/// Anything which is both gets this for free. It's the bound to ask for when values are folded
+/// in no particular order and there might not be any of them, like the values of a `HashMap`:
+///
+/// ```rust
+/// # use std::collections::HashMap;
+/// # use std::num::Wrapping;
+/// # use rust_hkt::part3::{HashMapKind, UnorderedFoldable};
+/// let stock: HashMap<_, _> = vec![("apples", 3), ("pears", 1)].into_iter().collect();
+/// assert_eq!(HashMapKind::unordered_fold_map(stock, Wrapping), Wrapping(4));
+/// ```
+pub trait CommutativeMonoid: Monoid + CommutativeSemigroup {}
+
+impl<M> CommutativeMonoid for M where M: Monoid + CommutativeSemigroup {}
+
+/// Combines every value produced by `iter`, from left to right, starting from `M::empty()`.
+///
+/// ```rust
+/// # use rust_hkt::kernel::combine_all;
+/// let words = vec!["a", "b", "c"].into_iter().map(String::from);
+/// assert_eq!(combine_all::<String, _>(words), "abc");
+///
+/// let nothing: Vec<Vec<i32>> = vec![];
+/// assert_eq!(combine_all::<Vec<i32>, _>(nothing), vec![]);
+/// ```
+pub fn combine_all<M, I>(iter: I) -> M
+where
+    M: Monoid,
+    I: IntoIterator<Item = M>,
+{
+    iter.into_iter().fold(M::empty(), M::combine)
+}
+
+impl Monoid for String {
+    fn empty() -> String {
+        String::new()
+    }
+}
+
+impl<'a> Monoid for Cow<'a, str> {
+    fn empty() -> Cow<'a, str> {
+        Cow::Borrowed("")
+    }
+}
+
+impl<T> Monoid for Vec<T> {
+    fn empty() -> Vec<T> {
+        Vec::new()
+    }
+}
+
+impl<T> Monoid for LinkedList<T> {
+    fn empty() -> LinkedList<T> {
+        LinkedList::new()
+    }
+}
+
+impl<A> Monoid for Option<A>
+where
+    A: Semigroup,
+{
+    fn empty() -> Option<A> {
+        None
+    }
+}
+
+impl<T> Monoid for Wrapping<T>
+where
+    T: Default,
+    Wrapping<T>: Add<Output = Wrapping<T>>,
+{
+    fn empty() -> Wrapping<T> {
+        Wrapping(T::default())
+    }
+}
+
+impl<T> Monoid for HashSet<T>
+where
+    T: Eq + Hash,
+{
+    fn empty() -> HashSet<T> {
+        HashSet::new()
+    }
+}
+
+impl<T> Monoid for BTreeSet<T>
+where
+    T: Ord,
+{
+    fn empty() -> BTreeSet<T> {
+        BTreeSet::new()
+    }
+}
+
+impl Monoid for Ordering {
+    fn empty() -> Ordering {
+        Ordering::Equal
+    }
+}
+
+impl Monoid for () {
+    fn empty() {}
+}
+
+macro_rules! tuple_monoid {
+    ($($name:ident),+) => {
+        impl<$($name),+> Monoid for ($($name,)+)
+        where
+            $($name: Monoid),+
+        {
+            fn empty() -> Self {
+                ($($name::empty(),)+)
+            }
+        }
+    };
+}
+
+tuple_monoid!(A);
+tuple_monoid!(A, B);
+tuple_monoid!(A, B, C);
+tuple_monoid!(A, B, C, D);
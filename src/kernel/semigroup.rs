@@ -0,0 +1,191 @@
+use std::borrow::Cow;
+use std::cmp::Ordering;
+use std::collections::{BTreeSet, HashSet, LinkedList};
+use std::hash::Hash;
+use std::num::Wrapping;
+use std::ops::Add;
+
+/// A `Semigroup` is a type with an associative binary operation, `combine`:
+///
+/// `a.combine(b).combine(c) == a.combine(b.combine(c))`
+///
+/// ```rust
+/// # use rust_hkt::kernel::Semigroup;
+/// use std::borrow::Cow;
+/// use std::cmp::Ordering;
+/// use std::collections::LinkedList;
+/// use std::num::Wrapping;
+///
+/// assert_eq!("foo".to_string().combine("bar".to_string()), "foobar");
+/// assert_eq!(Cow::Borrowed("foo").combine(Cow::Borrowed("bar")), "foobar");
+/// assert_eq!(vec![1, 2].combine(vec![3]), vec![1, 2, 3]);
+/// assert_eq!(LinkedList::from([1]).combine(LinkedList::from([2])), LinkedList::from([1, 2]));
+/// assert_eq!(Some("a".to_string()).combine(None), Some("a".to_string()));
+/// assert_eq!(Some(vec![1]).combine(Some(vec![2])), Some(vec![1, 2]));
+/// assert_eq!(("a".to_string(), vec![1]).combine(("b".to_string(), vec![2])),
+///            ("ab".to_string(), vec![1, 2]));
+/// assert_eq!(Wrapping(u8::MAX).combine(Wrapping(2)), Wrapping(1));
+/// assert_eq!(Ordering::Equal.combine(Ordering::Less), Ordering::Less);
+/// ```
+///
+/// There's no instance for the numeric primitives themselves, since both addition and
+/// multiplication are equally good candidates. `Wrapping` picks addition, which can't overflow,
+/// and `Sum` and `Product` let the caller pick.
+pub trait Semigroup {
+    fn combine(self, other: Self) -> Self;
+}
+
+impl Semigroup for String {
+    fn combine(mut self, other: String) -> String {
+        self.push_str(&other);
+        self
+    }
+}
+
+impl<'a> Semigroup for Cow<'a, str> {
+    fn combine(self, other: Cow<'a, str>) -> Cow<'a, str> {
+        self + other
+    }
+}
+
+impl<T> Semigroup for Vec<T> {
+    fn combine(mut self, other: Vec<T>) -> Vec<T> {
+        self.extend(other);
+        self
+    }
+}
+
+impl<T> Semigroup for LinkedList<T> {
+    fn combine(mut self, mut other: LinkedList<T>) -> LinkedList<T> {
+        self.append(&mut other);
+        self
+    }
+}
+
+impl<A> Semigroup for Option<A>
+where
+    A: Semigroup,
+{
+    fn combine(self, other: Option<A>) -> Option<A> {
+        match (self, other) {
+            (Some(a), Some(b)) => Some(a.combine(b)),
+            (Some(a), None) => Some(a),
+            (None, b) => b,
+        }
+    }
+}
+
+impl<T> Semigroup for Wrapping<T>
+where
+    Wrapping<T>: Add<Output = Wrapping<T>>,
+{
+    fn combine(self, other: Wrapping<T>) -> Wrapping<T> {
+        self + other
+    }
+}
+
+/// The union of the two sets.
+impl<T> Semigroup for HashSet<T>
+where
+    T: Eq + Hash,
+{
+    fn combine(mut self, other: HashSet<T>) -> HashSet<T> {
+        self.extend(other);
+        self
+    }
+}
+
+/// The union of the two sets.
+impl<T> Semigroup for BTreeSet<T>
+where
+    T: Ord,
+{
+    fn combine(mut self, other: BTreeSet<T>) -> BTreeSet<T> {
+        self.extend(other);
+        self
+    }
+}
+
+/// The first result which isn't `Equal`, i.e. a lexicographic comparison.
+impl Semigroup for Ordering {
+    fn combine(self, other: Ordering) -> Ordering {
+        self.then(other)
+    }
+}
+
+impl Semigroup for () {
+    fn combine(self, _: ()) {}
+}
+
+/// A `Semigroup` whose `combine` is also commutative:
+///
+/// `a.combine(b) == b.combine(a)`
+///
+/// Combining values in whatever order they turn up, e.g. from a `HashMap` or from several
+/// threads, only gives a well-defined answer for these.
+pub trait CommutativeSemigroup: Semigroup {}
+
+impl<A> CommutativeSemigroup for Option<A> where A: CommutativeSemigroup {}
+
+impl<T> CommutativeSemigroup for Wrapping<T> where Wrapping<T>: Add<Output = Wrapping<T>> {}
+
+impl<T> CommutativeSemigroup for HashSet<T> where T: Eq + Hash {}
+
+impl<T> CommutativeSemigroup for BTreeSet<T> where T: Ord {}
+
+impl CommutativeSemigroup for () {}
+
+macro_rules! tuple_semigroup {
+    ($($name:ident $idx:tt),+) => {
+        impl<$($name),+> Semigroup for ($($name,)+)
+        where
+            $($name: Semigroup),+
+        {
+            fn combine(self, other: Self) -> Self {
+                ($(self.$idx.combine(other.$idx),)+)
+            }
+        }
+    };
+}
+
+tuple_semigroup!(A 0);
+tuple_semigroup!(A 0, B 1);
+tuple_semigroup!(A 0, B 1, C 2);
+tuple_semigroup!(A 0, B 1, C 2, D 3);
+
+macro_rules! tuple_commutative_semigroup {
+    ($($name:ident),+) => {
+        impl<$($name),+> CommutativeSemigroup for ($($name,)+) where $($name: CommutativeSemigroup),+ {}
+    };
+}
+
+tuple_commutative_semigroup!(A);
+tuple_commutative_semigroup!(A, B);
+tuple_commutative_semigroup!(A, B, C);
+tuple_commutative_semigroup!(A, B, C, D);
+
+/// Combines any number of values with their `Semigroup`, from left to right:
+///
+/// ```rust
+/// # #[macro_use] extern crate rust_hkt;
+/// # use std::num::Wrapping;
+/// # fn main() {
+/// assert_eq!(combine!(vec![1], vec![2, 3], vec![4]), vec![1, 2, 3, 4]);
+/// assert_eq!(combine!("a".to_string(), "b".to_string()), "ab");
+/// assert_eq!(combine!(Some(Wrapping(1)), None, Some(Wrapping(2)),), Some(Wrapping(3)));
+/// assert_eq!(combine!(Wrapping(1)), Wrapping(1));
+/// # }
+/// ```
+///
+/// `combine!(a, b, c)` is `a.combine(b).combine(c)`, or Cats' `a |+| b |+| c`. Unlike
+/// `combine_all` there's no `empty` to start from, so it works for any `Semigroup`, but it needs
+/// at least one value.
+#[macro_export]
+macro_rules! combine {
+    ($a:expr $(,)?) => {
+        $a
+    };
+    ($a:expr, $b:expr $(, $rest:expr)* $(,)?) => {
+        $crate::combine!($crate::kernel::Semigroup::combine($a, $b) $(, $rest)*)
+    };
+}
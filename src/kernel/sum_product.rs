@@ -0,0 +1,198 @@
+use std::ops::Deref;
+
+use super::{CommutativeSemigroup, Monoid, Semigroup};
+
+/// Wraps a number to combine it by adding, with `0` as the empty value.
+///
+/// Integers wrap around on overflow, the same way `Wrapping` does, so that `combine` is total
+/// and never panics, even in debug builds. `SaturatingSum` stops at the largest value instead:
+///
+/// ```rust
+/// # use rust_hkt::kernel::{Monoid, SaturatingSum, Semigroup, Sum};
+/// # use rust_hkt::part3::{Foldable, VecKind};
+/// assert_eq!(Sum(1).combine(Sum(2)), Sum(3));
+/// assert_eq!(Sum::<u8>::empty(), Sum(0));
+/// assert_eq!(Sum(255u8).combine(Sum(1)), Sum(0));
+/// assert_eq!(VecKind::fold_map(vec![i8::MAX, 1], Sum), Sum(i8::MIN));
+/// assert_eq!(VecKind::fold_map(vec![1, 2, 3], Sum), Sum(6));
+///
+/// fn total<F: Foldable>(fa: F::Apply<i64>) -> i64 {
+///     *F::fold_map(fa, Sum)
+/// }
+/// assert_eq!(total::<VecKind>(vec![4, 5, 6]), 15);
+///
+/// let sizes = vec![250u8, 10];
+/// assert_eq!(VecKind::fold_map(sizes.clone(), Sum), Sum(4));
+/// assert_eq!(VecKind::fold_map(sizes, SaturatingSum), SaturatingSum(255));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Sum<T>(pub T);
+
+/// Wraps a number to combine it by multiplying, with `1` as the empty value.
+///
+/// Integers wrap around on overflow, like `Sum`. `SaturatingProduct` stops at the largest value
+/// instead.
+///
+/// ```rust
+/// # use rust_hkt::kernel::{Monoid, Product, SaturatingProduct, Semigroup};
+/// # use rust_hkt::part3::{Foldable, OptionKind, VecKind};
+/// assert_eq!(Product(3).combine(Product(4)), Product(12));
+/// assert_eq!(Product(16u8).combine(Product(17)), Product(16));
+/// assert_eq!(SaturatingProduct(16u8).combine(SaturatingProduct(17)), SaturatingProduct(255));
+/// assert_eq!(Product::<f64>::empty(), Product(1.0));
+/// assert_eq!(VecKind::fold_map(vec![1, 2, 3, 4], Product), Product(24));
+/// assert_eq!(OptionKind::fold_map(None, Product::<u32>), Product(1));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Product<T>(pub T);
+
+impl<T> Deref for Sum<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T> Deref for Product<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T> From<T> for Sum<T> {
+    fn from(t: T) -> Sum<T> {
+        Sum(t)
+    }
+}
+
+impl<T> From<T> for Product<T> {
+    fn from(t: T) -> Product<T> {
+        Product(t)
+    }
+}
+
+macro_rules! integer_instances {
+    ($($t:ty)*) => {
+        $(
+            impl Semigroup for Sum<$t> {
+                fn combine(self, other: Sum<$t>) -> Sum<$t> {
+                    Sum(self.0.wrapping_add(other.0))
+                }
+            }
+
+            impl Monoid for Sum<$t> {
+                fn empty() -> Sum<$t> {
+                    Sum(0)
+                }
+            }
+
+            impl CommutativeSemigroup for Sum<$t> {}
+
+            impl Semigroup for Product<$t> {
+                fn combine(self, other: Product<$t>) -> Product<$t> {
+                    Product(self.0.wrapping_mul(other.0))
+                }
+            }
+
+            impl Monoid for Product<$t> {
+                fn empty() -> Product<$t> {
+                    Product(1)
+                }
+            }
+
+            impl CommutativeSemigroup for Product<$t> {}
+        )*
+    };
+}
+
+integer_instances!(u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize);
+
+// Floating point addition and multiplication are only associative up to rounding, the same
+// caveat every numeric library accepts for summing floats.
+macro_rules! float_instances {
+    ($($t:ty)*) => {
+        $(
+            impl Semigroup for Sum<$t> {
+                fn combine(self, other: Sum<$t>) -> Sum<$t> {
+                    Sum(self.0 + other.0)
+                }
+            }
+
+            impl Monoid for Sum<$t> {
+                fn empty() -> Sum<$t> {
+                    Sum(0.0)
+                }
+            }
+
+            impl CommutativeSemigroup for Sum<$t> {}
+
+            impl Semigroup for Product<$t> {
+                fn combine(self, other: Product<$t>) -> Product<$t> {
+                    Product(self.0 * other.0)
+                }
+            }
+
+            impl Monoid for Product<$t> {
+                fn empty() -> Product<$t> {
+                    Product(1.0)
+                }
+            }
+
+            impl CommutativeSemigroup for Product<$t> {}
+        )*
+    };
+}
+
+float_instances!(f32 f64);
+
+/// Wraps an unsigned integer to combine it by adding, stopping at the largest value.
+///
+/// There are no instances for signed integers: saturating at both ends isn't associative, as
+/// `(i8::MAX + 1) + -1` is `126` but `i8::MAX + (1 + -1)` is `127`.
+///
+/// ```rust
+/// # use rust_hkt::kernel::SaturatingSum;
+/// # use rust_hkt::part3::{Foldable, VecKind};
+/// let sizes: Vec<u32> = vec![u32::MAX - 1, 10, 20];
+/// assert_eq!(VecKind::fold_map(sizes, SaturatingSum).0, u32::MAX);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SaturatingSum<T>(pub T);
+
+/// Wraps an unsigned integer to combine it by multiplying, stopping at the largest value.
+///
+/// Like `SaturatingSum`, it's only a monoid for unsigned integers.
+///
+/// ```rust
+/// # use rust_hkt::kernel::{Semigroup, SaturatingProduct};
+/// assert_eq!(SaturatingProduct(16u8).combine(SaturatingProduct(17)), SaturatingProduct(255));
+/// assert_eq!(SaturatingProduct(255u8).combine(SaturatingProduct(0)), SaturatingProduct(0));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SaturatingProduct<T>(pub T);
+
+macro_rules! saturating_instances {
+    ($name:ident, $empty:expr, $combine:ident; $($t:ty)*) => {
+        $(
+            impl Semigroup for $name<$t> {
+                fn combine(self, other: $name<$t>) -> $name<$t> {
+                    $name(self.0.$combine(other.0))
+                }
+            }
+
+            impl Monoid for $name<$t> {
+                fn empty() -> $name<$t> {
+                    $name($empty)
+                }
+            }
+
+            impl CommutativeSemigroup for $name<$t> {}
+        )*
+    };
+}
+
+saturating_instances!(SaturatingSum, 0, saturating_add; u8 u16 u32 u64 u128 usize);
+saturating_instances!(SaturatingProduct, 1, saturating_mul; u8 u16 u32 u64 u128 usize);
@@ -0,0 +1,29 @@
+//! # Laws
+//!
+//! A typeclass is more than its signatures. `fmap(fa, |a| a)` type checks whether or not it gives
+//! back `fa`, but code written against `Functor` is entitled to assume that it does. The functions
+//! in this module state those assumptions as pairs of values which should be equal, so that
+//! anyone writing an instance can check it against the examples or generators of their choice.
+
+mod applicative;
+mod band;
+mod flat_map;
+mod functor;
+mod group;
+mod is_eq;
+mod monad;
+mod monoid;
+mod semigroup;
+
+pub use self::applicative::{
+    applicative_composition, applicative_homomorphism, applicative_identity,
+    applicative_interchange,
+};
+pub use self::band::band_idempotence;
+pub use self::flat_map::flat_map_associativity;
+pub use self::functor::{covariant_composition, covariant_identity};
+pub use self::group::{group_left_inverse, group_right_inverse};
+pub use self::is_eq::IsEq;
+pub use self::monad::{monad_left_identity, monad_right_identity};
+pub use self::monoid::{monoid_left_identity, monoid_right_identity};
+pub use self::semigroup::{semigroup_associativity, semigroup_commutativity};
@@ -0,0 +1,106 @@
+use part3::Applicative;
+
+use super::IsEq;
+
+/// Applying a pure identity function changes nothing: `ap(pure(|a| a), fa) == fa`.
+///
+/// ```rust
+/// # use rust_hkt::laws::applicative_identity;
+/// # use rust_hkt::part3::{OptionKind, VecKind};
+/// applicative_identity::<OptionKind, _>(Some(1)).assert();
+///
+/// applicative_identity::<VecKind, _>(vec![1, 2, 3]).assert();
+/// ```
+pub fn applicative_identity<'a, F, A>(fa: F::Apply<A>) -> IsEq<F::Apply<A>>
+where
+    F: Applicative<'a>,
+    A: Clone + 'a,
+    F::Apply<A>: Clone,
+{
+    let id: fn(A) -> A = |a| a;
+    IsEq::new(F::ap(F::pure(id), fa.clone()), fa)
+}
+
+/// Applying a pure function to a pure value is the same as applying it outside:
+/// `ap(pure(f), pure(a)) == pure(f(a))`.
+///
+/// ```rust
+/// # use rust_hkt::laws::applicative_homomorphism;
+/// # use rust_hkt::part3::{ResultKind, VecKind};
+/// applicative_homomorphism::<VecKind, _, _, _>(1, |i: i32| i + 1).assert();
+///
+/// applicative_homomorphism::<ResultKind<()>, _, _, _>("a", str::len).assert();
+/// ```
+pub fn applicative_homomorphism<'a, F, A, B, G>(a: A, f: G) -> IsEq<F::Apply<B>>
+where
+    F: Applicative<'a>,
+    A: Clone + 'a,
+    B: 'a,
+    G: FnOnce(A) -> B + Clone + 'a,
+{
+    let lhs = F::ap(F::pure(f.clone()), F::pure(a.clone()));
+    IsEq::new(lhs, F::pure(f(a)))
+}
+
+/// It doesn't matter which side the pure value is on:
+/// `ap(ff, pure(a)) == ap(pure(|f| f(a)), ff)`.
+///
+/// ```rust
+/// # use rust_hkt::laws::applicative_interchange;
+/// # use rust_hkt::part3::{OptionKind, VecKind};
+/// applicative_interchange::<OptionKind, _, _, _>(1, Some(|i: i32| i * 2)).assert();
+///
+/// let fs = vec![1, 2, 3].into_iter().map(|n| move |i: i32| i * n).collect();
+/// applicative_interchange::<VecKind, _, _, _>(10, fs).assert();
+/// ```
+pub fn applicative_interchange<'a, F, A, B, G>(a: A, ff: F::Apply<G>) -> IsEq<F::Apply<B>>
+where
+    F: Applicative<'a>,
+    A: Clone + 'a,
+    B: 'a,
+    G: FnOnce(A) -> B + Clone + 'a,
+    F::Apply<G>: Clone,
+{
+    let lhs = F::ap(ff.clone(), F::pure(a.clone()));
+    let rhs = F::ap(F::pure(move |f: G| f(a)), ff);
+    IsEq::new(lhs, rhs)
+}
+
+/// Applying composed functions is the same as applying them one after the other:
+/// `ap(map2(fh, fg, |h, g| |a| h(g(a))), fa) == ap(fh, ap(fg, fa))`.
+///
+/// ```rust
+/// # use rust_hkt::laws::applicative_composition;
+/// # use rust_hkt::part3::{OptionKind, VecKind};
+/// applicative_composition::<OptionKind, _, _, _, _, _>(
+///     Some(1),
+///     Some(|i: i32| i + 1),
+///     Some(|i: i32| i.to_string()),
+/// )
+/// .assert();
+///
+/// let adders = vec![1, 2].into_iter().map(|n| move |i: i32| i + n).collect();
+/// let multipliers = vec![10, 100].into_iter().map(|n| move |i: i32| i * n).collect();
+/// applicative_composition::<VecKind, _, _, _, _, _>(vec![1, 2], adders, multipliers).assert();
+/// ```
+pub fn applicative_composition<'a, F, A, B, C, G, H>(
+    fa: F::Apply<A>,
+    fg: F::Apply<G>,
+    fh: F::Apply<H>,
+) -> IsEq<F::Apply<C>>
+where
+    F: Applicative<'a>,
+    A: Clone + 'a,
+    B: Clone + 'a,
+    C: 'a,
+    G: FnOnce(A) -> B + Clone + 'a,
+    H: FnOnce(B) -> C + Clone + 'a,
+    F::Apply<A>: Clone,
+    F::Apply<G>: Clone,
+    F::Apply<H>: Clone,
+{
+    let composed = F::map2(fh.clone(), fg.clone(), |h, g| move |a| h(g(a)));
+    let lhs = F::ap(composed, fa.clone());
+    let rhs = F::ap(fh, F::ap(fg, fa));
+    IsEq::new(lhs, rhs)
+}
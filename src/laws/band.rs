@@ -0,0 +1,20 @@
+use kernel::Band;
+
+use super::IsEq;
+
+/// Combining a value with itself changes nothing: `a.combine(a) == a`.
+///
+/// ```rust
+/// # use std::collections::BTreeSet;
+/// # use rust_hkt::kernel::Min;
+/// # use rust_hkt::laws::band_idempotence;
+/// band_idempotence(Min(1)).assert();
+///
+/// band_idempotence(vec![1, 2].into_iter().collect::<BTreeSet<_>>()).assert();
+/// ```
+pub fn band_idempotence<A>(a: A) -> IsEq<A>
+where
+    A: Band + Clone,
+{
+    IsEq::new(a.clone().combine(a.clone()), a)
+}
@@ -0,0 +1,41 @@
+use part3::FlatMap;
+
+use super::IsEq;
+
+/// The way a chain of `flat_map`s is nested doesn't matter:
+/// `flat_map(flat_map(fa, f), g) == flat_map(fa, |a| flat_map(f(a), g))`.
+///
+/// ```rust
+/// # use rust_hkt::data::{Identity, IdentityKind};
+/// # use rust_hkt::laws::flat_map_associativity;
+/// # use rust_hkt::part3::{OptionKind, ResultKind, VecKind};
+/// let half = |i: i32| if i % 2 == 0 { Some(i / 2) } else { None };
+/// for fa in vec![Some(4), Some(6), Some(3), None] {
+///     flat_map_associativity::<OptionKind, _, _, _, _, _>(fa, half, half).assert();
+/// }
+///
+/// flat_map_associativity::<VecKind, _, _, _, _, _>(vec![1, 2], |i| vec![i, i * 10], |i| vec![i, -i]).assert();
+///
+/// let positive = |i: i32| if i > 0 { Ok(i) } else { Err(format!("{} isn't positive", i)) };
+/// flat_map_associativity::<ResultKind<String>, _, _, _, _, _>(Ok(1), move |i| positive(i - 1), positive).assert();
+///
+/// flat_map_associativity::<IdentityKind, _, _, _, _, _>(Identity(1), |i| Identity(i + 1), |i| Identity(i * 2)).assert();
+/// ```
+pub fn flat_map_associativity<'a, M, A, B, C, F, G>(
+    fa: M::Apply<A>,
+    mut f: F,
+    g: G,
+) -> IsEq<M::Apply<C>>
+where
+    M: FlatMap<'a>,
+    A: 'a,
+    B: 'a,
+    C: 'a,
+    M::Apply<A>: Clone,
+    F: FnMut(A) -> M::Apply<B> + Clone + 'a,
+    G: FnMut(B) -> M::Apply<C> + Clone + 'a,
+{
+    let lhs = M::flat_map(M::flat_map(fa.clone(), f.clone()), g.clone());
+    let rhs = M::flat_map(fa, move |a| M::flat_map(f(a), g.clone()));
+    IsEq::new(lhs, rhs)
+}
@@ -0,0 +1,52 @@
+use part3::Functor;
+
+use super::IsEq;
+
+/// Mapping the identity function changes nothing: `fmap(fa, |a| a) == fa`.
+///
+/// ```rust
+/// # use rust_hkt::laws::covariant_identity;
+/// # use rust_hkt::part3::{OptionKind, ResultKind, VecKind};
+/// covariant_identity::<OptionKind, _>(Some(1)).assert();
+///
+/// covariant_identity::<VecKind, _>(vec![1, 2, 3]).assert();
+///
+/// covariant_identity::<ResultKind<&str>, i32>(Err("e")).assert();
+/// ```
+pub fn covariant_identity<'a, F, A>(fa: F::Apply<A>) -> IsEq<F::Apply<A>>
+where
+    F: Functor<'a>,
+    A: 'a,
+    F::Apply<A>: Clone,
+{
+    IsEq::new(F::fmap(fa.clone(), |a| a), fa)
+}
+
+/// Mapping twice is the same as mapping once with the composed function:
+/// `fmap(fmap(fa, f), g) == fmap(fa, |a| g(f(a)))`.
+///
+/// ```rust
+/// # use rust_hkt::laws::covariant_composition;
+/// # use rust_hkt::part3::{OptionKind, VecKind};
+/// covariant_composition::<OptionKind, _, _, _, _, _>(Some(1), |i| i + 1, |i| i * 2).assert();
+///
+/// covariant_composition::<VecKind, _, _, _, _, _>(vec!["a", "bc"], str::len, |n| n.to_string()).assert();
+/// ```
+pub fn covariant_composition<'a, F, A, B, C, G, H>(
+    fa: F::Apply<A>,
+    mut f: G,
+    mut g: H,
+) -> IsEq<F::Apply<C>>
+where
+    F: Functor<'a>,
+    A: 'a,
+    B: 'a,
+    C: 'a,
+    F::Apply<A>: Clone,
+    G: FnMut(A) -> B + Clone + 'a,
+    H: FnMut(B) -> C + Clone + 'a,
+{
+    let lhs = F::fmap(F::fmap(fa.clone(), f.clone()), g.clone());
+    let rhs = F::fmap(fa, move |a| g(f(a)));
+    IsEq::new(lhs, rhs)
+}
@@ -0,0 +1,36 @@
+use kernel::Group;
+
+use super::IsEq;
+
+/// Combining with the inverse on the left gives `empty`: `a.inverse().combine(a) == M::empty()`.
+///
+/// ```rust
+/// # use std::num::Wrapping;
+/// # use rust_hkt::laws::group_left_inverse;
+/// group_left_inverse(Wrapping(7)).assert();
+///
+/// group_left_inverse((Wrapping(1u8), Wrapping(-2i64))).assert();
+/// ```
+pub fn group_left_inverse<A>(a: A) -> IsEq<A>
+where
+    A: Group + Clone,
+{
+    IsEq::new(a.clone().inverse().combine(a), A::empty())
+}
+
+/// Combining with the inverse on the right gives `empty`: `a.combine(a.inverse()) == M::empty()`.
+///
+/// ```rust
+/// # use std::num::Wrapping;
+/// # use rust_hkt::kernel::Dual;
+/// # use rust_hkt::laws::group_right_inverse;
+/// group_right_inverse(Wrapping(i32::MIN)).assert();
+///
+/// group_right_inverse(Dual(Wrapping(3))).assert();
+/// ```
+pub fn group_right_inverse<A>(a: A) -> IsEq<A>
+where
+    A: Group + Clone,
+{
+    IsEq::new(a.clone().combine(a.inverse()), A::empty())
+}
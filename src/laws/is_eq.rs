@@ -0,0 +1,78 @@
+use std::fmt::Debug;
+
+/// Two values which a law says should be equal: the two sides of the equation.
+///
+/// Laws return an `IsEq` rather than asserting anything themselves, which leaves it to the
+/// caller to decide what equality means for its type and what to do when it doesn't hold. An
+/// example-based test can just `assert` it, and a property-based one can check `equals`:
+///
+/// ```rust
+/// # use rust_hkt::laws::{covariant_identity, IsEq};
+/// # use rust_hkt::part3::OptionKind;
+/// covariant_identity::<OptionKind, _>(Some(1)).assert();
+///
+/// assert!(IsEq::new(vec![1, 2], vec![1, 2]).equals());
+/// assert!(!IsEq::new("a", "b").equals());
+/// assert!(IsEq::new("a", "A").equals_by(|lhs, rhs| lhs.eq_ignore_ascii_case(rhs)));
+/// ```
+///
+/// Types which can't be compared directly, like the lazy ones in `data`, can be run first:
+///
+/// ```rust
+/// # use rust_hkt::data::{Eval, EvalKind};
+/// # use rust_hkt::laws::{flat_map_associativity, monad_left_identity};
+/// monad_left_identity::<EvalKind, _, _, _>(1, |i| Eval::now(i + 1)).map(Eval::value).assert();
+///
+/// let half = |i: i32| Eval::later(move || i / 2);
+/// flat_map_associativity::<EvalKind, _, _, _, _, _>(Eval::now(12), half, half)
+///     .map(Eval::value)
+///     .assert();
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct IsEq<A> {
+    pub lhs: A,
+    pub rhs: A,
+}
+
+impl<A> IsEq<A> {
+    pub fn new(lhs: A, rhs: A) -> IsEq<A> {
+        IsEq { lhs, rhs }
+    }
+
+    /// Whether the two sides are equal.
+    pub fn equals(&self) -> bool
+    where
+        A: PartialEq,
+    {
+        self.lhs == self.rhs
+    }
+
+    /// Whether the two sides are equal according to `eq`.
+    pub fn equals_by<F>(&self, eq: F) -> bool
+    where
+        F: FnOnce(&A, &A) -> bool,
+    {
+        eq(&self.lhs, &self.rhs)
+    }
+
+    /// Applies `f` to both sides.
+    pub fn map<B, F>(self, mut f: F) -> IsEq<B>
+    where
+        F: FnMut(A) -> B,
+    {
+        IsEq::new(f(self.lhs), f(self.rhs))
+    }
+
+    /// Panics, showing both sides, if they aren't equal.
+    pub fn assert(self)
+    where
+        A: PartialEq + Debug,
+    {
+        assert!(
+            self.equals(),
+            "law doesn't hold:\n  lhs: {:?}\n  rhs: {:?}",
+            self.lhs,
+            self.rhs
+        );
+    }
+}
@@ -0,0 +1,55 @@
+use part3::Monad;
+
+use super::IsEq;
+
+/// `pure` followed by `flat_map` is just function application: `flat_map(pure(a), f) == f(a)`.
+///
+/// ```rust
+/// # use rust_hkt::data::{Identity, IdentityKind};
+/// # use rust_hkt::laws::monad_left_identity;
+/// # use rust_hkt::part3::{OptionKind, ResultKind, VecKind};
+/// let half = |i: i32| if i % 2 == 0 { Some(i / 2) } else { None };
+/// for i in 0..4 {
+///     monad_left_identity::<OptionKind, _, _, _>(i, half).assert();
+/// }
+///
+/// monad_left_identity::<VecKind, _, _, _>(3, |i| (0..i).collect::<Vec<_>>()).assert();
+///
+/// monad_left_identity::<ResultKind<String>, _, _, _>("x", |s| s.parse::<i32>().map_err(|e| e.to_string())).assert();
+///
+/// monad_left_identity::<IdentityKind, _, _, _>(1, |i| Identity(i + 1)).assert();
+/// ```
+pub fn monad_left_identity<'a, M, A, B, F>(a: A, mut f: F) -> IsEq<M::Apply<B>>
+where
+    M: Monad<'a>,
+    A: Clone + 'a,
+    B: 'a,
+    F: FnMut(A) -> M::Apply<B> + Clone + 'a,
+{
+    let lhs = M::flat_map(M::pure(a.clone()), f.clone());
+    IsEq::new(lhs, f(a))
+}
+
+/// `flat_map` with `pure` changes nothing: `flat_map(fa, pure) == fa`.
+///
+/// ```rust
+/// # use rust_hkt::data::{Identity, IdentityKind};
+/// # use rust_hkt::laws::monad_right_identity;
+/// # use rust_hkt::part3::{OptionKind, ResultKind, VecKind};
+/// monad_right_identity::<OptionKind, _>(Some(1)).assert();
+/// monad_right_identity::<OptionKind, i32>(None).assert();
+///
+/// monad_right_identity::<VecKind, _>(vec![1, 2, 3]).assert();
+///
+/// monad_right_identity::<ResultKind<&str>, i32>(Err("e")).assert();
+///
+/// monad_right_identity::<IdentityKind, _>(Identity("a")).assert();
+/// ```
+pub fn monad_right_identity<'a, M, A>(fa: M::Apply<A>) -> IsEq<M::Apply<A>>
+where
+    M: Monad<'a>,
+    A: 'a,
+    M::Apply<A>: Clone,
+{
+    IsEq::new(M::flat_map(fa.clone(), |a| M::pure(a)), fa)
+}
@@ -0,0 +1,34 @@
+use kernel::Monoid;
+
+use super::IsEq;
+
+/// Combining with `empty` on the left changes nothing: `M::empty().combine(a) == a`.
+///
+/// ```rust
+/// # use rust_hkt::laws::monoid_left_identity;
+/// monoid_left_identity(vec![1, 2]).assert();
+///
+/// monoid_left_identity(Some("a".to_string())).assert();
+/// ```
+pub fn monoid_left_identity<A>(a: A) -> IsEq<A>
+where
+    A: Monoid + Clone,
+{
+    IsEq::new(A::empty().combine(a.clone()), a)
+}
+
+/// Combining with `empty` on the right changes nothing: `a.combine(M::empty()) == a`.
+///
+/// ```rust
+/// # use std::num::Wrapping;
+/// # use rust_hkt::laws::monoid_right_identity;
+/// monoid_right_identity(("a".to_string(), Wrapping(1))).assert();
+///
+/// monoid_right_identity(None::<String>).assert();
+/// ```
+pub fn monoid_right_identity<A>(a: A) -> IsEq<A>
+where
+    A: Monoid + Clone,
+{
+    IsEq::new(a.clone().combine(A::empty()), a)
+}
@@ -0,0 +1,40 @@
+use kernel::{CommutativeSemigroup, Semigroup};
+
+use super::IsEq;
+
+/// It doesn't matter how a chain of `combine`s is bracketed:
+/// `a.combine(b).combine(c) == a.combine(b.combine(c))`.
+///
+/// ```rust
+/// # use std::num::Wrapping;
+/// # use rust_hkt::laws::semigroup_associativity;
+/// semigroup_associativity("a".to_string(), "b".to_string(), "c".to_string()).assert();
+///
+/// semigroup_associativity(Some(vec![1]), None, Some(vec![2, 3])).assert();
+///
+/// semigroup_associativity(Wrapping(u8::MAX), Wrapping(1), Wrapping(2)).assert();
+/// ```
+pub fn semigroup_associativity<A>(a: A, b: A, c: A) -> IsEq<A>
+where
+    A: Semigroup + Clone,
+{
+    let lhs = a.clone().combine(b.clone()).combine(c.clone());
+    IsEq::new(lhs, a.combine(b.combine(c)))
+}
+
+/// The order of a `combine` doesn't matter: `a.combine(b) == b.combine(a)`.
+///
+/// ```rust
+/// # use std::num::Wrapping;
+/// # use rust_hkt::kernel::Max;
+/// # use rust_hkt::laws::semigroup_commutativity;
+/// semigroup_commutativity(Wrapping(1), Wrapping(2)).assert();
+///
+/// semigroup_commutativity(Some(Max(3)), None).assert();
+/// ```
+pub fn semigroup_commutativity<A>(a: A, b: A) -> IsEq<A>
+where
+    A: CommutativeSemigroup + Clone,
+{
+    IsEq::new(a.clone().combine(b.clone()), b.combine(a))
+}
@@ -15,6 +15,25 @@
 //! section of folks: FP people unfamiliar with Rust, and Rust people less familiar with FP.
 //!
 //! So, with that out of the way, let's get started.
+#[cfg(feature = "futures")]
+extern crate futures;
+#[cfg(feature = "testing")]
+extern crate proptest;
+#[cfg(feature = "rayon")]
+extern crate rayon;
+#[cfg(feature = "derive")]
+extern crate rust_hkt_derive;
+
+pub mod data;
+pub mod kernel;
+pub mod laws;
+#[cfg(feature = "rayon")]
+pub mod par;
 pub mod part1;
 pub mod part2;
 pub mod part3;
+pub mod part4;
+pub mod prelude;
+pub mod syntax;
+#[cfg(feature = "testing")]
+pub mod testing;
@@ -0,0 +1,148 @@
+//! # Parallel traversal
+//!
+//! `traverse` calls its function on one element after another. When that function is expensive
+//! and the effects are plain data, like validating a batch of records into `Validated`, there's
+//! no reason not to call it on every core at once. This module does that with `rayon`, behind
+//! the `rayon` feature.
+//!
+//! Only the function calls run in parallel. The effects they return are then combined on the
+//! calling thread, so an applicative doesn't need to be `Sync` itself, just its values `Send`.
+
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use rayon::prelude::*;
+
+use kernel::CommutativeMonoid;
+use part3::{Applicative, CommutativeApplicative, Traverse, VecKind, HKT};
+
+/// A `Traverse` which can call its function in parallel.
+///
+/// The effects are combined in the same order as `traverse` would, so the result is the same:
+///
+/// ```rust
+/// # use rust_hkt::data::{Validated, ValidatedKind};
+/// # use rust_hkt::par::ParTraverse;
+/// # use rust_hkt::part3::{Traverse, VecKind};
+/// let validate = |i: u32| {
+///     if i % 1000 == 999 { Validated::Invalid(vec![i]) } else { Validated::Valid(i * 2) }
+/// };
+///
+/// let validated = VecKind::par_traverse::<ValidatedKind<Vec<u32>>, _, _, _>((0..5000).collect(), validate);
+/// assert_eq!(validated, Validated::Invalid(vec![999, 1999, 2999, 3999, 4999]));
+/// assert_eq!(validated, VecKind::traverse::<ValidatedKind<Vec<u32>>, _, _, _>((0..5000).collect(), validate));
+///
+/// let valid = VecKind::par_traverse::<ValidatedKind<Vec<u32>>, _, _, _>((0..999).collect(), validate);
+/// assert_eq!(valid, Validated::Valid((0..999).map(|i| i * 2).collect()));
+/// ```
+pub trait ParTraverse<'a>: Traverse<'a> {
+    fn par_traverse<G, A, B, F>(fa: Self::Apply<A>, f: F) -> G::Apply<Self::Apply<B>>
+    where
+        G: Applicative<'a>,
+        A: Send + 'a,
+        B: Clone + 'a,
+        G::Apply<B>: Send,
+        Self::Apply<B>: Clone + 'a,
+        F: Fn(A) -> G::Apply<B> + Sync + Send + 'a;
+}
+
+impl<'a> ParTraverse<'a> for VecKind {
+    fn par_traverse<G, A, B, F>(fa: Vec<A>, f: F) -> G::Apply<Vec<B>>
+    where
+        G: Applicative<'a>,
+        A: Send,
+        B: Clone + 'a,
+        G::Apply<B>: Send,
+        F: Fn(A) -> G::Apply<B> + Sync + Send,
+    {
+        let effects: Vec<G::Apply<B>> = fa.into_par_iter().map(f).collect();
+        let init = G::pure(Vec::with_capacity(effects.len()));
+        effects.into_iter().fold(init, |acc, gb| {
+            G::map2_lazy(
+                acc,
+                || gb,
+                |mut bs, b| {
+                    bs.push(b);
+                    bs
+                },
+            )
+        })
+    }
+}
+
+/// Traverses the values of a `HashMap` in parallel, keeping their keys.
+///
+/// A `HashMap` has no order to combine the effects in, so the applicative has to be commutative
+/// for the result not to depend on where the keys happened to land:
+///
+/// ```rust
+/// # use std::collections::HashMap;
+/// # use std::num::Wrapping;
+/// # use rust_hkt::data::{Validated, ValidatedKind};
+/// # use rust_hkt::par::par_traverse_hash_map;
+/// # use rust_hkt::part3::OptionKind;
+/// let ages: HashMap<&str, i32> = vec![("ada", 36), ("alan", 41), ("grace", -1), ("edsger", -2)]
+///     .into_iter()
+///     .collect();
+///
+/// // Count the invalid ages rather than collecting them, so the order doesn't matter.
+/// let checked = par_traverse_hash_map::<ValidatedKind<Wrapping<u32>>, _, _, _, _>(ages.clone(), |age| {
+///     if age < 0 { Validated::Invalid(Wrapping(1)) } else { Validated::Valid(age as u8) }
+/// });
+/// assert_eq!(checked, Validated::Invalid(Wrapping(2)));
+///
+/// let halved = par_traverse_hash_map::<OptionKind, _, _, _, _>(ages, |age| Some(age / 2));
+/// assert_eq!(halved.unwrap()["alan"], 20);
+/// ```
+pub fn par_traverse_hash_map<'a, G, K, A, B, F>(
+    fa: HashMap<K, A>,
+    f: F,
+) -> <G as HKT>::Apply<HashMap<K, B>>
+where
+    G: CommutativeApplicative<'a>,
+    K: Eq + Hash + Clone + Send + 'a,
+    A: Send + 'a,
+    B: Clone + 'a,
+    G::Apply<B>: Send,
+    F: Fn(A) -> G::Apply<B> + Sync + Send + 'a,
+{
+    let effects: Vec<(K, G::Apply<B>)> = fa.into_par_iter().map(|(k, a)| (k, f(a))).collect();
+    let init = G::pure(HashMap::with_capacity(effects.len()));
+    effects.into_iter().fold(init, |acc, (k, gb)| {
+        G::map2_lazy(
+            acc,
+            || gb,
+            move |mut map, b| {
+                map.insert(k.clone(), b);
+                map
+            },
+        )
+    })
+}
+
+/// Folds the values of a `HashMap` in parallel.
+///
+/// The values are combined in whatever order the threads finish in, so the `Monoid` has to be
+/// commutative as well, just like for `UnorderedFoldable`, which this gives the same answer as:
+///
+/// ```rust
+/// # use std::collections::HashMap;
+/// # use std::num::Wrapping;
+/// # use rust_hkt::par::par_fold_map_hash_map;
+/// # use rust_hkt::part3::{HashMapKind, UnorderedFoldable};
+/// let lengths: HashMap<u32, String> = (0..1000).map(|i| (i, "x".repeat(i as usize % 7))).collect();
+///
+/// let total = par_fold_map_hash_map(lengths.clone(), |s| Wrapping(s.len()));
+/// assert_eq!(total, HashMapKind::unordered_fold_map(lengths, |s| Wrapping(s.len())));
+/// ```
+pub fn par_fold_map_hash_map<K, A, M, F>(fa: HashMap<K, A>, f: F) -> M
+where
+    K: Eq + Hash + Send,
+    A: Send,
+    M: CommutativeMonoid + Send,
+    F: Fn(A) -> M + Sync + Send,
+{
+    fa.into_par_iter()
+        .map(|(_, a)| f(a))
+        .reduce(M::empty, M::combine)
+}
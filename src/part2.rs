@@ -67,6 +67,44 @@
 //! assert_eq!(double_in_context(Some(1)), Some(2));
 //! ```
 //!
+//! And since `Vec` is a functor too, the very same function works on it without any changes,
+//! which is the whole point of the exercise:
+//!
+//! ```rust
+//! # use rust_hkt::part2::Functor;
+//! # fn double_in_context<F>(f: F) -> F::T where F: Functor<i32, C=i32> {
+//! #     f.fmap(|i| i * 2)
+//! # }
+//! assert_eq!(double_in_context(vec![1, 2]), vec![2, 4]);
+//! assert_eq!(double_in_context(Vec::new()), Vec::new());
+//! ```
+//!
+//! What about `Result`? It has kind `* -> * -> *`: it needs *two* types before it's concrete, so
+//! it doesn't fit `HKT<U>` as is. The trick is to partially apply it, fixing the error type in
+//! the impl and leaving only the `Ok` type free, which makes `Result<_, E>` a perfectly good
+//! `* -> *` for any given `E`:
+//!
+//! ```rust
+//! # use rust_hkt::part2::Functor;
+//! # fn double_in_context<F>(f: F) -> F::T where F: Functor<i32, C=i32> {
+//! #     f.fmap(|i| i * 2)
+//! # }
+//! assert_eq!(double_in_context(Ok::<i32, String>(1)), Ok(2));
+//! assert_eq!(double_in_context(Err::<i32, _>("nope".to_string())), Err("nope".to_string()));
+//! ```
+//!
+//! Note that because `fmap` only borrows `self`, the error has to be cloned into the result.
+//!
+//! Fixing a parameter is fine for `Functor`, but it means we can never talk about mapping the
+//! error side. For that we need an encoding that swaps both type parameters at once, `HKT2`:
+//!
+//! ```rust
+//! # use rust_hkt::part2::HKT2;
+//! fn swap_both<R>(_: R) where R: HKT2<String, u8, A=i32, B=bool, T=Result<String, u8>> {}
+//!
+//! swap_both(Ok::<i32, bool>(1));
+//! ```
+//!
 //! Great! Let's ramp up the complexity a bit with an example carefully crafted to break our
 //! interface:
 //!
@@ -93,7 +131,7 @@
 //! but because we're trying to map twice, we're actually dealing with two different functors:
 //! A `Functor<i32, C=i32>`, and a `Functor<String, C=i32>`
 //!
-//! ```
+//! ```text
 //! error[E0599]: no method named fmap found for type <F as HKT<String>>::T in the current scope
 //!  --> src/part2.rs:78:23
 //!   |
@@ -109,18 +147,39 @@
 //!
 //! We can get around this, in two ways, but neither option is particularly satisfying:
 //!
-//! 1. We can can add bounds to the function itself. This is painful and ugly, and
+//! 1. We can can add bounds to the function itself. This is painful and ugly, and it has to be
+//!    repeated in every function which maps more than once:
 //! ```
-//! // TODO
+//! # use rust_hkt::part2::{Functor, HKT};
+//! fn double_and_convert_to_string_in_context<F>(f: F) -> <F::T as HKT<String>>::T
+//! where
+//!     F: Functor<i32, C = i32>,
+//!     F::T: Functor<String, C = i32>,
+//! {
+//!     f.fmap(|i| i * 2).fmap(|i| format!("{}", i))
+//! }
+//!
+//! assert_eq!(double_and_convert_to_string_in_context(Some(1)), Some("2".to_string()));
 //! ```
 //!
-//! 2. We can modify the definition of functor, and specify that the return type `F::T`
+//! 2. We can modify the definition of functor, and specify that the return type `F::T` is a
+//!    functor too. `Functor2` does exactly that, so at least the bounds only have to be written once:
 //!
 //! ```
-//! // TODO
+//! # use rust_hkt::part2::{Functor, Functor2, HKT};
+//! fn double_and_convert_to_string_in_context<F>(f: F) -> <F::T as HKT<String>>::T
+//! where
+//!     F: Functor2<i32, String, C = i32>,
+//! {
+//!     f.fmap(|i| i * 2).fmap(|i| format!("{}", i))
+//! }
+//!
+//! assert_eq!(double_and_convert_to_string_in_context(Some(1)), Some("2".to_string()));
+//! assert_eq!(double_and_convert_to_string_in_context(vec![1, 2]), vec!["2", "4"]);
 //! ```
 //!
-//! However, we're just kicking the can further down the road.
+//! However, we're just kicking the can further down the road: `Functor2` buys us exactly one more
+//! `fmap`. Mapping three times needs a `Functor3`, and so on.
 //!
 //! To put it another way: our functor returns an `F::T`, and we don't know anything at all
 //! about `F::T`. However, a functor is an HKT, `F<A>` for which a function, `map`, exists that takes a
@@ -131,6 +190,7 @@
 //! of a functor: that the output-kind must be the same as the input-kind.
 //!
 
+use kernel::Monoid;
 
 pub trait HKT<T> {
     type C;
@@ -142,16 +202,49 @@ impl<T, U> HKT<U> for Option<T> {
     type T = Option<U>;
 }
 
+impl<T, U, E> HKT<U> for Result<T, E> {
+    type C = T;
+    type T = Result<U, E>;
+}
+
+/// The two-parameter version of `HKT`, for type constructors of kind `* -> * -> *`.
+/// `A` and `B` are the current types, `T` is the constructor applied to `U` and `V`.
+pub trait HKT2<U, V> {
+    type A;
+    type B;
+    type T;
+}
+
+impl<T, E, U, V> HKT2<U, V> for Result<T, E> {
+    type A = T;
+    type B = E;
+    type T = Result<U, V>;
+}
+
+/// The mapping function only needs to be `FnMut`, so it's free to keep state between calls:
+///
+/// ```rust
+/// # use rust_hkt::part2::Functor;
+/// let mut count = 0;
+/// let numbered = vec!["a", "b"].fmap(|s| {
+///     count += 1;
+///     format!("{}{}", s, count)
+/// });
+/// assert_eq!(numbered, vec!["a1", "b2"]);
+/// assert_eq!(count, 2);
+/// ```
 pub trait Functor<U>: HKT<U> {
     fn fmap<F>(&self, f: F) -> Self::T
     where
-        F: Fn(&Self::C) -> U;
+        F: FnMut(&Self::C) -> U;
 }
 
 impl<T, U> Functor<U> for Option<T> {
-    fn fmap<F>(&self, f: F) -> Option<U>
+    // Spelled out the same way as in the walkthrough above.
+    #[allow(clippy::manual_map)]
+    fn fmap<F>(&self, mut f: F) -> Option<U>
     where
-        F: Fn(&T) -> U,
+        F: FnMut(&T) -> U,
     {
         match *self {
             Some(ref value) => Some(f(value)),
@@ -160,25 +253,542 @@ impl<T, U> Functor<U> for Option<T> {
     }
 }
 
-pub trait Functor2<U, B>: HKT<U> where Self::T: HKT<B> {
+impl<T, U> HKT<U> for Vec<T> {
+    type C = T;
+    type T = Vec<U>;
+}
 
-    fn fmap<F>(&self, f: F) -> Self::T
-        where
-            F: Fn(&Self::C) -> U;
+impl<T, U> Functor<U> for Vec<T> {
+    fn fmap<F>(&self, f: F) -> Vec<U>
+    where
+        F: FnMut(&T) -> U,
+    {
+        self.iter().map(f).collect()
+    }
 }
 
-impl<T, U, B> Functor2<U, B> for Option<T> where Self::T: Functor<B> {
-    fn fmap<F>(&self, f: F) -> Option<U>
-        where
-            F: Fn(&T) -> U,
+impl<T, U, E> Functor<U> for Result<T, E>
+where
+    E: Clone,
+{
+    fn fmap<F>(&self, mut f: F) -> Result<U, E>
+    where
+        F: FnMut(&T) -> U,
     {
         match *self {
-            Some(ref value) => Some(f(value)),
+            Ok(ref value) => Ok(f(value)),
+            Err(ref e) => Err(e.clone()),
+        }
+    }
+}
+
+/// `Functor::fmap` only borrows `self`, so the mapping function only ever sees `&C`. That's fine for
+/// `i32`, but anything which can't be cloned, or shouldn't be, has to be moved out instead.
+/// `OwnedFunctor` consumes `self` and hands the function each value by value.
+///
+/// ```rust
+/// # use rust_hkt::part2::OwnedFunctor;
+/// use std::fmt::Display;
+///
+/// let boxed: Option<Box<dyn Display>> = Some(Box::new(1));
+/// assert_eq!(boxed.fmap_owned(|d| d.to_string()), Some("1".to_string()));
+///
+/// let strings = vec!["a".to_string(), "b".to_string()];
+/// assert_eq!(strings.fmap_owned(|s| s + "!"), vec!["a!", "b!"]);
+///
+/// // No `E: Clone` needed, the error is moved across.
+/// struct NotClone;
+/// let res: Result<i32, NotClone> = Ok(1);
+/// assert_eq!(res.fmap_owned(|i| i * 2).ok(), Some(2));
+/// ```
+pub trait OwnedFunctor<U>: HKT<U> {
+    fn fmap_owned<F>(self, f: F) -> Self::T
+    where
+        F: FnMut(Self::C) -> U;
+}
+
+impl<T, U> OwnedFunctor<U> for Option<T> {
+    fn fmap_owned<F>(self, f: F) -> Option<U>
+    where
+        F: FnMut(T) -> U,
+    {
+        self.map(f)
+    }
+}
+
+impl<T, U> OwnedFunctor<U> for Vec<T> {
+    fn fmap_owned<F>(self, f: F) -> Vec<U>
+    where
+        F: FnMut(T) -> U,
+    {
+        self.into_iter().map(f).collect()
+    }
+}
+
+impl<T, U, E> OwnedFunctor<U> for Result<T, E> {
+    fn fmap_owned<F>(self, f: F) -> Result<U, E>
+    where
+        F: FnMut(T) -> U,
+    {
+        self.map(f)
+    }
+}
+
+/// An `FnMut` can be called again, so it can't give away what it captured. `Option` and `Result`
+/// hold at most one value and call the function at most once, so `FunctorOnce` lets them take an
+/// `FnOnce` instead, which is free to move its captures into the result:
+///
+/// ```rust
+/// # use rust_hkt::part2::FunctorOnce;
+/// let greeting = "hello".to_string();
+/// assert_eq!(Some("world").fmap_once(move |s| greeting + " " + s), Some("hello world".to_string()));
+///
+/// let mut names = vec!["a".to_string()];
+/// let res: Result<&str, ()> = Ok("b");
+/// let all = res.fmap_once(move |s| {
+///     names.push(s.to_string());
+///     names
+/// });
+/// assert_eq!(all, Ok(vec!["a".to_string(), "b".to_string()]));
+/// ```
+///
+/// `Vec` calls the function once per element, so it doesn't implement `FunctorOnce`: mapping one
+/// takes an `FnMut`, through `Functor` or `OwnedFunctor`.
+pub trait FunctorOnce<U>: HKT<U> {
+    fn fmap_once<F>(self, f: F) -> Self::T
+    where
+        F: FnOnce(Self::C) -> U;
+}
+
+impl<T, U> FunctorOnce<U> for Option<T> {
+    fn fmap_once<F>(self, f: F) -> Option<U>
+    where
+        F: FnOnce(T) -> U,
+    {
+        self.map(f)
+    }
+}
+
+impl<T, U, E> FunctorOnce<U> for Result<T, E> {
+    fn fmap_once<F>(self, f: F) -> Result<U, E>
+    where
+        F: FnOnce(T) -> U,
+    {
+        self.map(f)
+    }
+}
+
+/// An `Applicative` is a `Functor` which can lift a plain value into the context (`pure`) and
+/// combine two values in the context (`map2`, `ap`).
+///
+/// Ideally `map2` would take an `F<B>`, but we have no way to name "`Self`, holding a `B`" here.
+/// What we *can* say is that the other value is a functor which, mapped to `U`, produces exactly
+/// our `Self::T`. That pins down the type constructor, and is enough to implement `map2` in
+/// terms of the other value's `fmap`.
+///
+/// ```rust
+/// # use rust_hkt::part2::Applicative;
+/// fn add_in_context<F>(fa: &F, fb: &F) -> F::T where F: Applicative<i32, C = i32> {
+///     fa.map2(fb, |a, b| a + b)
+/// }
+///
+/// assert_eq!(add_in_context(&Some(1), &Some(2)), Some(3));
+/// assert_eq!(add_in_context(&Some(1), &None), None);
+/// assert_eq!(add_in_context(&vec![1, 2], &vec![10, 20]), vec![11, 21, 12, 22]);
+///
+/// assert_eq!(Some(1).map2(&Some("a"), |i, s| format!("{}{}", s, i)), Some("a1".to_string()));
+///
+/// assert_eq!(<Option<()> as Applicative<i32>>::pure(1), Some(1));
+/// assert_eq!(<Vec<()> as Applicative<i32>>::pure(1), vec![1]);
+///
+/// let add_one = |i: &i32| i + 1;
+/// assert_eq!(Some(1).ap(&Some(add_one)), Some(2));
+/// ```
+pub trait Applicative<U>: Functor<U> {
+    fn pure(value: U) -> Self::T;
+
+    fn map2<FB, F>(&self, fb: &FB, f: F) -> Self::T
+    where
+        FB: Functor<U, T = Self::T>,
+        F: FnMut(&Self::C, &FB::C) -> U;
+
+    fn ap<FF>(&self, ff: &FF) -> Self::T
+    where
+        FF: Functor<U, T = Self::T>,
+        FF::C: Fn(&Self::C) -> U,
+    {
+        self.map2(ff, |a, f| f(a))
+    }
+}
+
+impl<T, U> Applicative<U> for Option<T> {
+    fn pure(value: U) -> Option<U> {
+        Some(value)
+    }
+
+    fn map2<FB, F>(&self, fb: &FB, mut f: F) -> Option<U>
+    where
+        FB: Functor<U, T = Option<U>>,
+        F: FnMut(&T, &FB::C) -> U,
+    {
+        match *self {
+            Some(ref a) => fb.fmap(|b| f(a, b)),
             None => None,
         }
     }
 }
 
-fn functor_2_test() {
+impl<T, U> Applicative<U> for Vec<T> {
+    fn pure(value: U) -> Vec<U> {
+        vec![value]
+    }
+
+    fn map2<FB, F>(&self, fb: &FB, mut f: F) -> Vec<U>
+    where
+        FB: Functor<U, T = Vec<U>>,
+        F: FnMut(&T, &FB::C) -> U,
+    {
+        let mut out = Vec::new();
+        for a in self {
+            out.extend(fb.fmap(|b| f(a, b)));
+        }
+        out
+    }
+}
+
+impl<T, U, E> Applicative<U> for Result<T, E>
+where
+    E: Clone,
+{
+    fn pure(value: U) -> Result<U, E> {
+        Ok(value)
+    }
+
+    fn map2<FB, F>(&self, fb: &FB, mut f: F) -> Result<U, E>
+    where
+        FB: Functor<U, T = Result<U, E>>,
+        F: FnMut(&T, &FB::C) -> U,
+    {
+        match *self {
+            Ok(ref a) => fb.fmap(|b| f(a, b)),
+            Err(ref e) => Err(e.clone()),
+        }
+    }
+}
+
+/// A `Monad` is an `Applicative` which can sequence computations in the context, where each step
+/// gets to decide what the next context looks like (`flat_map`).
+///
+/// `flat_map` has the same chaining problem as `fmap`: it returns a `Self::T` which the compiler
+/// knows nothing about. To keep going in a generic context we have to add bounds on `M::T`
+/// ourselves:
+///
+/// ```rust
+/// # use rust_hkt::part2::{Functor, Monad};
+/// fn half_then_double<M, H>(m: M, half: H) -> M::T
+/// where
+///     M: Monad<i32, C = i32>,
+///     M::T: Functor<i32, C = i32, T = M::T>,
+///     H: Fn(&i32) -> M::T,
+/// {
+///     m.flat_map(half).fmap(|i| i * 2)
+/// }
+///
+/// let half = |i: &i32| if i % 2 == 0 { Some(i / 2) } else { None };
+/// assert_eq!(half_then_double(Some(4), half), Some(4));
+/// assert_eq!(half_then_double(Some(3), half), None);
+///
+/// let half = |i: &i32| if i % 2 == 0 { Ok(i / 2) } else { Err(format!("{} is odd", i)) };
+/// assert_eq!(half_then_double(Ok(4), half), Ok(4));
+/// assert_eq!(half_then_double(Ok(3), half), Err("3 is odd".to_string()));
+///
+/// let half = |i: &i32| if i % 2 == 0 { vec![i / 2] } else { vec![] };
+/// assert_eq!(half_then_double(vec![1, 2, 3, 4], half), vec![2, 4]);
+/// ```
+pub trait Monad<U>: Applicative<U> {
+    fn flat_map<F>(&self, f: F) -> Self::T
+    where
+        F: FnMut(&Self::C) -> Self::T;
+}
+
+impl<T, U> Monad<U> for Option<T> {
+    fn flat_map<F>(&self, f: F) -> Option<U>
+    where
+        F: FnMut(&T) -> Option<U>,
+    {
+        self.as_ref().and_then(f)
+    }
+}
+
+impl<T, U> Monad<U> for Vec<T> {
+    fn flat_map<F>(&self, f: F) -> Vec<U>
+    where
+        F: FnMut(&T) -> Vec<U>,
+    {
+        self.iter().flat_map(f).collect()
+    }
+}
+
+impl<T, U, E> Monad<U> for Result<T, E>
+where
+    E: Clone,
+{
+    fn flat_map<F>(&self, mut f: F) -> Result<U, E>
+    where
+        F: FnMut(&T) -> Result<U, E>,
+    {
+        match *self {
+            Ok(ref a) => f(a),
+            Err(ref e) => Err(e.clone()),
+        }
+    }
+}
+
+/// A `Foldable` is anything we can collapse into a single value, visiting the elements either
+/// left to right (`fold_left`) or right to left (`fold_right`).
+///
+/// Folding never changes the type constructor, so unlike the rest of the hierarchy this trait
+/// doesn't need `HKT` at all, just the current type `C`.
+///
+/// `fold_map` maps every element into a `Monoid` and combines the results.
+///
+/// ```rust
+/// # use rust_hkt::part2::Foldable;
+/// fn sum<F>(f: &F) -> i32 where F: Foldable<C = i32> {
+///     f.fold_left(0, |acc, i| acc + i)
+/// }
+///
+/// assert_eq!(sum(&vec![1, 2, 3]), 6);
+/// assert_eq!(sum(&Some(1)), 1);
+/// assert_eq!(sum(&None), 0);
+/// assert_eq!(sum(&Err::<i32, ()>(())), 0);
+///
+/// let digits = vec![1, 2, 3].fold_right(String::new(), |i, acc| acc + &i.to_string());
+/// assert_eq!(digits, "321");
+///
+/// assert_eq!(vec![1, 2].fold_map(|i| i.to_string()), "12");
+/// ```
+pub trait Foldable {
+    type C;
+
+    fn fold_left<B, F>(&self, init: B, f: F) -> B
+    where
+        F: FnMut(B, &Self::C) -> B;
+
+    fn fold_right<B, F>(&self, init: B, f: F) -> B
+    where
+        F: FnMut(&Self::C, B) -> B;
+
+    fn fold_map<M, F>(&self, mut f: F) -> M
+    where
+        M: Monoid,
+        F: FnMut(&Self::C) -> M,
+    {
+        self.fold_left(M::empty(), |acc, a| acc.combine(f(a)))
+    }
+}
+
+impl<T> Foldable for Option<T> {
+    type C = T;
+
+    fn fold_left<B, F>(&self, init: B, mut f: F) -> B
+    where
+        F: FnMut(B, &T) -> B,
+    {
+        match *self {
+            Some(ref a) => f(init, a),
+            None => init,
+        }
+    }
+
+    fn fold_right<B, F>(&self, init: B, mut f: F) -> B
+    where
+        F: FnMut(&T, B) -> B,
+    {
+        match *self {
+            Some(ref a) => f(a, init),
+            None => init,
+        }
+    }
+}
+
+impl<T> Foldable for Vec<T> {
+    type C = T;
+
+    fn fold_left<B, F>(&self, init: B, f: F) -> B
+    where
+        F: FnMut(B, &T) -> B,
+    {
+        self.iter().fold(init, f)
+    }
+
+    fn fold_right<B, F>(&self, init: B, mut f: F) -> B
+    where
+        F: FnMut(&T, B) -> B,
+    {
+        self.iter().rev().fold(init, |acc, a| f(a, acc))
+    }
+}
+
+impl<T, E> Foldable for Result<T, E> {
+    type C = T;
+
+    fn fold_left<B, F>(&self, init: B, mut f: F) -> B
+    where
+        F: FnMut(B, &T) -> B,
+    {
+        match *self {
+            Ok(ref a) => f(init, a),
+            Err(_) => init,
+        }
+    }
+
+    fn fold_right<B, F>(&self, init: B, mut f: F) -> B
+    where
+        F: FnMut(&T, B) -> B,
+    {
+        match *self {
+            Ok(ref a) => f(a, init),
+            Err(_) => init,
+        }
+    }
+}
+
+/// A `Traverse` is a `Functor` and `Foldable` which can run an effect for every element and collect
+/// the results inside the effect: `F<A>` and `A -> G<B>` give us a `G<F<B>>`.
+///
+/// This is where the encoding really starts to creak. `G` is a concrete applicative value, eg.
+/// `Option<B>`, so "`G` holding an `F<B>`" is `<G as HKT<F<B>>>::T`. Nothing tells the compiler
+/// that this is an applicative again, so we have to say so ourselves, and we even have to give it
+/// a name, `GT`, because rustc can't cope with a bound on `<G as HKT<..>>::T` directly.
+///
+/// ```rust
+/// # use rust_hkt::part2::{Traverse, HKT};
+/// let parse = |s: &&str| s.parse::<i32>().ok();
+/// assert_eq!(vec!["1", "2"].traverse(parse), Some(vec![1, 2]));
+/// assert_eq!(vec!["1", "x"].traverse(parse), None);
+/// assert_eq!(Some("1").traverse(parse), Some(Some(1)));
+///
+/// let all: Option<Vec<i32>> = vec![Some(1), Some(2)].sequence();
+/// assert_eq!(all, Some(vec![1, 2]));
+/// let all: Vec<Option<i32>> = Some(vec![1, 2]).sequence();
+/// assert_eq!(all, vec![Some(1), Some(2)]);
+///
+/// // `Foldable` has a `C` too, so we have to say which one we mean.
+/// fn positive<T>(t: &T) -> Option<<T as HKT<i32>>::T>
+/// where
+///     T: Traverse<i32> + HKT<i32, C = i32>,
+/// {
+///     t.traverse(|i| if *i > 0 { Some(*i) } else { None })
+/// }
+///
+/// assert_eq!(positive(&vec![1, 2]), Some(vec![1, 2]));
+/// assert_eq!(positive(&vec![1, -2]), None);
+/// assert_eq!(positive(&Ok::<i32, ()>(1)), Some(Ok(1)));
+/// ```
+pub trait Traverse<U>: Functor<U> + Foldable {
+    fn traverse<G, GT, F>(&self, f: F) -> GT
+    where
+        G: Applicative<<Self as HKT<U>>::T, C = U, T = GT>,
+        GT: Applicative<<Self as HKT<U>>::T, C = <Self as HKT<U>>::T, T = GT>,
+        F: FnMut(&<Self as HKT<U>>::C) -> G;
+
+    fn sequence<G, GT>(&self) -> GT
+    where
+        Self: HKT<U, C = G>,
+        G: Applicative<<Self as HKT<U>>::T, C = U, T = GT> + Clone,
+        GT: Applicative<<Self as HKT<U>>::T, C = <Self as HKT<U>>::T, T = GT>,
+    {
+        self.traverse(|g| g.clone())
+    }
+}
+
+impl<T, U> Traverse<U> for Option<T>
+where
+    U: Clone,
+{
+    fn traverse<G, GT, F>(&self, mut f: F) -> GT
+    where
+        G: Applicative<Option<U>, C = U, T = GT>,
+        GT: Applicative<Option<U>, C = Option<U>, T = GT>,
+        F: FnMut(&T) -> G,
+    {
+        match *self {
+            Some(ref a) => f(a).fmap(|b| Some(b.clone())),
+            None => G::pure(None),
+        }
+    }
+}
+
+impl<T, U> Traverse<U> for Vec<T>
+where
+    U: Clone,
+{
+    fn traverse<G, GT, F>(&self, mut f: F) -> GT
+    where
+        G: Applicative<Vec<U>, C = U, T = GT>,
+        GT: Applicative<Vec<U>, C = Vec<U>, T = GT>,
+        F: FnMut(&T) -> G,
+    {
+        traverse_slice(self, &mut f)
+    }
+}
+
+// `map2` only lends its function the `Vec` built so far, so pushing one element at a time would
+// have to copy that `Vec` at every step. Joining halves copies each element once per level
+// instead, `O(n log n)` in total rather than `O(n²)`.
+fn traverse_slice<T, U, G, GT, F>(xs: &[T], f: &mut F) -> GT
+where
+    U: Clone,
+    G: Applicative<Vec<U>, C = U, T = GT>,
+    GT: Applicative<Vec<U>, C = Vec<U>, T = GT>,
+    F: FnMut(&T) -> G,
+{
+    match xs {
+        [] => G::pure(Vec::new()),
+        [a] => f(a).fmap(|b| vec![b.clone()]),
+        _ => {
+            let (left, right) = xs.split_at(xs.len() / 2);
+            let left = traverse_slice::<T, U, G, GT, F>(left, f);
+            let right = traverse_slice::<T, U, G, GT, F>(right, f);
+            left.map2(&right, |l, r| {
+                let mut bs = Vec::with_capacity(l.len() + r.len());
+                bs.extend_from_slice(l);
+                bs.extend_from_slice(r);
+                bs
+            })
+        }
+    }
+}
+
+impl<T, U, E> Traverse<U> for Result<T, E>
+where
+    U: Clone,
+    E: Clone,
+{
+    fn traverse<G, GT, F>(&self, mut f: F) -> GT
+    where
+        G: Applicative<Result<U, E>, C = U, T = GT>,
+        GT: Applicative<Result<U, E>, C = Result<U, E>, T = GT>,
+        F: FnMut(&T) -> G,
+    {
+        match *self {
+            Ok(ref a) => f(a).fmap(|b| Ok(b.clone())),
+            Err(ref e) => G::pure(Err(e.clone())),
+        }
+    }
+}
+
+/// A `Functor` whose output, `Self::T`, is known to be a `Functor` again, with the same current
+/// type. It's implemented for every type which fits, so there's nothing to implement by hand.
+pub trait Functor2<U, B>: Functor<U, T: Functor<B, C = U>> {}
+
+impl<F, U, B> Functor2<U, B> for F
+where
+    F: Functor<U>,
+    F::T: Functor<B, C = U>,
+{
+}
 
-}
\ No newline at end of file
+#[allow(dead_code)]
+fn functor_2_test() {}
@@ -1,4 +1,490 @@
-//! TODO: Discussion leading to definition of Kind. I could have given this a cool sounding name
-//! like `Lifted`, and I did for a while, but I decided to settle with `Kind`.
+//! # Generic associated types
 //!
-//!
\ No newline at end of file
+//! At the end of part 2 we were stuck: our `HKT` trait had no way of saying that the output of
+//! `fmap` is the *same* type constructor as the input, just applied to a different type. Every
+//! time we wanted to keep going we had to bolt another bound onto `F::T`.
+//!
+//! Generic associated types (GATs) let an associated type take type parameters of its own, which
+//! is exactly the missing piece: an associated type constructor.
+//!
+//! ```rust
+//! trait HKT {
+//!     type Apply<A>;
+//! }
+//! ```
+//!
+//! `Apply<A>` *is* `F<A>`. Note that there's nothing to implement it on yet: `Option` on its
+//! own isn't a type, and `Option<T>` has already been applied. So we introduce a zero-sized type
+//! to stand in for the unapplied constructor, its *kind*:
+//!
+//! ```rust
+//! # trait HKT { type Apply<A>; }
+//! struct OptionKind;
+//!
+//! impl HKT for OptionKind {
+//!     type Apply<A> = Option<A>;
+//! }
+//! ```
+//!
+//! And now `Functor` reads almost exactly like the pseudo-code from part 1:
+//!
+//! ```rust
+//! # use rust_hkt::part3::HKT;
+//! trait Functor: HKT {
+//!     fn fmap<A, B, F>(fa: Self::Apply<A>, f: F) -> Self::Apply<B>
+//!     where
+//!         F: Fn(A) -> B;
+//! }
+//! ```
+//!
+//! `fmap` takes an `F<A>` and gives back an `F<B>`, for the same `F`. The compiler knows this
+//! too, so the example which broke part 2 now just works, no extra bounds required:
+//!
+//! ```rust
+//! # use rust_hkt::part3::{Functor, OptionKind, VecKind};
+//! fn double_and_convert_to_string_in_context<'a, F>(fa: F::Apply<i32>) -> F::Apply<String>
+//! where
+//!     F: Functor<'a>,
+//! {
+//!     F::fmap(F::fmap(fa, |i| i * 2), |i| format!("{}", i))
+//! }
+//!
+//! assert_eq!(double_and_convert_to_string_in_context::<OptionKind>(Some(1)), Some("2".to_string()));
+//! assert_eq!(double_and_convert_to_string_in_context::<VecKind>(vec![1, 2]), vec!["2", "4"]);
+//! ```
+//!
+//! The price is that we have to name the kind ourselves. `F::Apply<i32>` could be produced by
+//! any number of kinds, so the compiler can't work backwards from `Option<i32>` to `OptionKind`.
+//!
+//! The `'a` is the other difference from the sketch above. `Option` runs `f` straight away, but a
+//! lazy type like `data::Eval` has to store it, and a stored closure needs a
+//! lifetime. Every typeclass which takes a function is parameterised by how long that function
+//! (and the values it handles) must live: `OptionKind` implements `Functor<'a>` for every `'a`,
+//! so borrowed data is fine, whereas `EvalKind` only implements `Functor<'static>`.
+//!
+//! The rest of the hierarchy carries over the same way, and none of it needs the workarounds from
+//! part 2. `Traverse`, which took two extra type parameters and a wall of bounds there, is now a
+//! one-liner to call:
+//!
+//! ```rust
+//! # use rust_hkt::part3::{OptionKind, Traverse, VecKind};
+//! let parse = |s: &str| s.parse::<i32>().ok();
+//! assert_eq!(VecKind::traverse::<OptionKind, _, _, _>(vec!["1", "2"], parse), Some(vec![1, 2]));
+//! assert_eq!(VecKind::traverse::<OptionKind, _, _, _>(vec!["1", "x"], parse), None);
+//! ```
+
+use std::cell::{Cell, RefCell};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, LinkedList, VecDeque};
+use std::marker::PhantomData;
+use std::ops::ControlFlow;
+use std::rc::Rc;
+use std::sync::{Arc, Mutex};
+use std::task::Poll;
+
+mod align;
+mod alternative;
+mod applicative;
+mod applicative_error;
+mod apply;
+mod bifoldable;
+mod bifunctor;
+mod bitraverse;
+mod borrowed;
+mod bracket;
+mod comonad;
+mod compactable;
+mod contravariant;
+mod decidable;
+mod defer;
+mod divisible;
+mod flat_map;
+mod foldable;
+mod foldable_with_index;
+mod function_k;
+mod functor;
+mod functor_filter;
+mod functor_with_index;
+#[cfg(feature = "futures")]
+mod future;
+mod invariant;
+mod monad;
+mod monad_error;
+mod monad_reader;
+mod monad_state;
+mod monad_trans;
+mod monad_writer;
+mod monoid_k;
+mod non_empty_traverse;
+mod parallel;
+mod profunctor;
+mod reducible;
+mod representable;
+mod selective;
+mod semigroup_k;
+mod semigroupal;
+mod traverse;
+mod traverse_filter;
+mod traverse_with_index;
+mod unordered_foldable;
+mod zip;
+
+pub use self::align::Align;
+pub use self::alternative::Alternative;
+pub use self::applicative::{Applicative, CommutativeApplicative};
+pub use self::applicative_error::ApplicativeError;
+pub use self::apply::Apply;
+pub use self::bifoldable::Bifoldable;
+pub use self::bifunctor::Bifunctor;
+pub use self::bitraverse::Bitraverse;
+pub use self::borrowed::{BorrowedFoldable, BorrowedFunctor, BorrowedHKT, CowSliceKind, SliceKind};
+pub use self::bracket::Bracket;
+pub use self::comonad::Comonad;
+pub use self::compactable::Compactable;
+pub use self::contravariant::Contravariant;
+pub use self::decidable::{Decidable, Decide};
+pub use self::defer::Defer;
+pub use self::divisible::{Divide, Divisible};
+pub use self::flat_map::{FlatMap, FlatMapOnce};
+pub use self::foldable::{then_compare, Foldable};
+pub use self::foldable_with_index::FoldableWithIndex;
+pub use self::function_k::{AndThen, FunctionK, IdentityK};
+pub use self::functor::Functor;
+pub use self::functor_filter::FunctorFilter;
+pub use self::functor_with_index::FunctorWithIndex;
+#[cfg(feature = "futures")]
+pub use self::future::{
+    sequence_async, sequence_async_sequential, traverse_async, traverse_async_sequential,
+    FutureKind, SequentialFutureKind,
+};
+pub use self::invariant::Invariant;
+pub use self::monad::Monad;
+pub use self::monad_error::MonadError;
+pub use self::monad_reader::{Ask, MonadReader};
+pub use self::monad_state::MonadState;
+pub use self::monad_trans::MonadTrans;
+pub use self::monad_writer::{MonadWriter, Tell};
+pub use self::monoid_k::MonoidK;
+pub use self::non_empty_traverse::NonEmptyTraverse;
+pub use self::parallel::Parallel;
+pub use self::profunctor::Profunctor;
+pub use self::reducible::Reducible;
+pub use self::representable::Representable;
+pub use self::selective::Selective;
+pub use self::semigroup_k::SemigroupK;
+pub use self::semigroupal::Semigroupal;
+pub use self::traverse::Traverse;
+pub use self::traverse_filter::TraverseFilter;
+pub use self::traverse_with_index::TraverseWithIndex;
+pub use self::unordered_foldable::UnorderedFoldable;
+pub use self::zip::Zip;
+
+#[cfg(feature = "derive")]
+pub use rust_hkt_derive::{Foldable, Functor, Traverse};
+
+/// A type constructor of kind `* -> *`, `Apply<A>` being the constructor applied to `A`.
+pub trait HKT {
+    type Apply<A>;
+}
+
+/// The kind of `Option`.
+pub struct OptionKind;
+
+impl HKT for OptionKind {
+    type Apply<A> = Option<A>;
+}
+
+/// The kind of `Vec`.
+pub struct VecKind;
+
+impl HKT for VecKind {
+    type Apply<A> = Vec<A>;
+}
+
+/// The kind of `VecDeque`.
+pub struct VecDequeKind;
+
+impl HKT for VecDequeKind {
+    type Apply<A> = VecDeque<A>;
+}
+
+/// The kind of `LinkedList`.
+pub struct LinkedListKind;
+
+impl HKT for LinkedListKind {
+    type Apply<A> = LinkedList<A>;
+}
+
+/// The kind of `Box`.
+///
+/// A `Box` holds exactly one value, so it has the same instances as `data::Identity`, only on
+/// the heap.
+pub struct BoxKind;
+
+impl HKT for BoxKind {
+    type Apply<A> = Box<A>;
+}
+
+/// The kind of `Rc`.
+///
+/// Unlike a `Box`, an `Rc`'s value may be shared, and getting it out to map or fold over needs
+/// `A: Clone`, which none of the typeclasses can ask for. So there's no `Functor`, only
+/// `RcKind::fmap_owned`, which asks for it.
+pub struct RcKind;
+
+impl HKT for RcKind {
+    type Apply<A> = Rc<A>;
+}
+
+/// The kind of `Arc`, which is in the same position as `RcKind`.
+pub struct ArcKind;
+
+impl HKT for ArcKind {
+    type Apply<A> = Arc<A>;
+}
+
+/// The kind of `Result<_, E>`, with the error type fixed.
+pub struct ResultKind<E>(PhantomData<E>);
+
+impl<E> HKT for ResultKind<E> {
+    type Apply<A> = Result<A, E>;
+}
+
+/// The kind of `ControlFlow<B, _>`, with the `Break` type fixed.
+///
+/// It's the same shape as `ResultKind<E>`: `Continue` carries on and gets mapped over, `Break`
+/// stops everything and is passed along untouched.
+///
+/// ```rust
+/// # use std::ops::ControlFlow;
+/// # use rust_hkt::part3::{ControlFlowKind, FlatMap, Functor, Traverse, VecKind};
+/// let step = |total: u32, i: u32| match total + i {
+///     t if t > 10 => ControlFlow::Break(t),
+///     t => ControlFlow::Continue(t),
+/// };
+/// let halved = ControlFlowKind::fmap(step(1, 2), |t| t / 2);
+/// assert_eq!(halved, ControlFlow::Continue(1));
+/// assert_eq!(ControlFlowKind::flat_map(step(8, 2), |t| step(t, 5)), ControlFlow::Break(15));
+///
+/// let checked = VecKind::traverse::<ControlFlowKind<&str>, _, _, _>(vec![1, 2, 3], |i| {
+///     if i < 3 { ControlFlow::Continue(i * 2) } else { ControlFlow::Break("too big") }
+/// });
+/// assert_eq!(checked, ControlFlow::Break("too big"));
+/// ```
+pub struct ControlFlowKind<B>(PhantomData<B>);
+
+impl<B> HKT for ControlFlowKind<B> {
+    type Apply<C> = ControlFlow<B, C>;
+}
+
+/// The kind of `Poll`. `Pending` works like `None`: anything combined with it is still pending.
+///
+/// ```rust
+/// # use std::task::Poll;
+/// # use rust_hkt::part3::{Apply, Functor, PollKind};
+/// assert_eq!(PollKind::fmap(Poll::Ready(2), |n| n * 10), Poll::Ready(20));
+/// assert_eq!(PollKind::map2(Poll::Ready(1), Poll::<i32>::Pending, |a, b| a + b), Poll::Pending);
+/// ```
+pub struct PollKind;
+
+impl HKT for PollKind {
+    type Apply<A> = Poll<A>;
+}
+
+/// The kind of `Poll<Result<_, E>>`, what a fallible future's `poll` returns.
+///
+/// `fmap` maps the `Ok` value of a ready result. `map2` is ready with an error as soon as either
+/// side is, and otherwise waits for both, the way `try_join` does. So a hand written future can
+/// combine two inner polls without matching on every pair of states:
+///
+/// ```rust
+/// # use std::task::Poll;
+/// # use rust_hkt::part3::{Apply, Functor, PollResultKind};
+/// fn poll_sum(a: Poll<Result<u32, String>>, b: Poll<Result<u32, String>>) -> Poll<Result<u32, String>> {
+///     PollResultKind::map2(a, b, |a, b| a + b)
+/// }
+///
+/// assert_eq!(poll_sum(Poll::Ready(Ok(1)), Poll::Ready(Ok(2))), Poll::Ready(Ok(3)));
+/// assert_eq!(poll_sum(Poll::Ready(Ok(1)), Poll::Pending), Poll::Pending);
+/// assert_eq!(poll_sum(Poll::Pending, Poll::Ready(Err("closed".into()))), Poll::Ready(Err("closed".into())));
+///
+/// let len = PollResultKind::<()>::fmap(Poll::Ready(Ok("abc")), |s| s.len());
+/// assert_eq!(len, Poll::Ready(Ok(3)));
+/// ```
+pub struct PollResultKind<E>(PhantomData<E>);
+
+impl<E> HKT for PollResultKind<E> {
+    type Apply<A> = Poll<Result<A, E>>;
+}
+
+/// The kind of `PhantomData`, a container which never holds anything.
+///
+/// It's the trivial instance of everything: `fmap` and `map2` just change the type, folds
+/// return their initial value, and `traverse` never calls its function. That makes it a handy
+/// base case, say for a struct whose only mention of its type parameter is a marker:
+///
+/// ```rust
+/// # use std::marker::PhantomData;
+/// # use rust_hkt::part3::{Foldable, Functor, OptionKind, PhantomDataKind, Traverse};
+/// let marker: PhantomData<u32> = PhantomDataKind::fmap(PhantomData::<&str>, |s| s.len() as u32);
+/// assert_eq!(PhantomDataKind::fold_left(marker, 7, |acc, n| acc + n), 7);
+///
+/// let traversed = PhantomDataKind::traverse::<OptionKind, _, _, _>(marker, |_| None::<i32>);
+/// assert_eq!(traversed, Some(PhantomData));
+/// ```
+pub struct PhantomDataKind;
+
+impl HKT for PhantomDataKind {
+    type Apply<A> = PhantomData<A>;
+}
+
+/// The kind of arrays of `N` elements.
+pub struct ArrayKind<const N: usize>;
+
+impl<const N: usize> HKT for ArrayKind<N> {
+    type Apply<A> = [A; N];
+}
+
+/// The kind of `HashMap<K, _>`, with the key type fixed.
+pub struct HashMapKind<K>(PhantomData<K>);
+
+impl<K> HKT for HashMapKind<K> {
+    type Apply<A> = HashMap<K, A>;
+}
+
+/// The kind of `BTreeMap<K, _>`, with the key type fixed.
+pub struct BTreeMapKind<K>(PhantomData<K>);
+
+impl<K> HKT for BTreeMapKind<K> {
+    type Apply<A> = BTreeMap<K, A>;
+}
+
+/// The kind of `HashSet`.
+///
+/// A set can't be a `Functor`, since mapping could merge elements and `fmap` has no way of
+/// asking for `Eq` and `Hash` on its output. It can still be folded, in no particular order.
+pub struct HashSetKind;
+
+impl HKT for HashSetKind {
+    type Apply<A> = HashSet<A>;
+}
+
+/// The kind of `BTreeSet`. Like `HashSetKind` it can be folded but not mapped over.
+pub struct BTreeSetKind;
+
+impl HKT for BTreeSetKind {
+    type Apply<A> = BTreeSet<A>;
+}
+
+/// The kind of `Cell`.
+pub struct CellKind;
+
+impl HKT for CellKind {
+    type Apply<A> = Cell<A>;
+}
+
+/// The kind of `RefCell`.
+pub struct RefCellKind;
+
+impl HKT for RefCellKind {
+    type Apply<A> = RefCell<A>;
+}
+
+/// The kind of `Mutex`.
+pub struct MutexKind;
+
+impl HKT for MutexKind {
+    type Apply<A> = Mutex<A>;
+}
+
+/// A type constructor of kind `* -> * -> *`, `Apply2<A, B>` being the constructor applied to `A`
+/// and `B`.
+pub trait HKT2 {
+    type Apply2<A, B>;
+}
+
+/// The kind of `Result`, with both type parameters free.
+///
+/// The error comes first, so that the right hand side is the one `ResultKind<E>` maps over, just
+/// like `Either` in Haskell or Scala.
+pub struct ResultKind2;
+
+impl HKT2 for ResultKind2 {
+    type Apply2<A, B> = Result<B, A>;
+}
+
+/// The kind of `ControlFlow`, with both type parameters free. `Break` comes first, like the error
+/// in `ResultKind2`.
+pub struct ControlFlowKind2;
+
+impl HKT2 for ControlFlowKind2 {
+    type Apply2<B, C> = ControlFlow<B, C>;
+}
+
+/// The kind of boxed functions from `R`, the function functor.
+///
+/// `fmap` is composition, `map2` feeds the same argument to both functions, and `flat_map` feeds
+/// it to the function it returns as well:
+///
+/// ```rust
+/// # use rust_hkt::part3::{Apply, FlatMap, FunctionKind, Functor};
+/// let len: Box<dyn FnMut(&'static str) -> usize> = Box::new(|s| s.len());
+/// let mut doubled = FunctionKind::fmap(len, |n| n * 2);
+/// assert_eq!(doubled("abc"), 6);
+///
+/// let upper: Box<dyn FnMut(&'static str) -> String> = Box::new(|s| s.to_uppercase());
+/// let mut both = FunctionKind::map2(doubled, upper, |n, s| format!("{} {}", s, n));
+/// assert_eq!(both("ab"), "AB 4");
+///
+/// let first: Box<dyn FnMut(&'static str) -> char> = Box::new(|s| s.chars().next().unwrap());
+/// let mut count_first = FunctionKind::flat_map(first, |c| -> Box<dyn FnMut(&'static str) -> usize> {
+///     Box::new(move |s| s.matches(c).count())
+/// });
+/// assert_eq!(count_first("abracadabra"), 5);
+/// ```
+///
+/// There's no `Applicative`, and so no `Monad`: `pure` would have to return its value from every
+/// call, which needs `A: Clone`. `data::Reader` runs once, which is why it can be a `Monad`.
+/// Like `FunctionKind2` the function is `FnMut`, and only gets instances for `'static`.
+pub struct FunctionKind<R>(PhantomData<R>);
+
+impl<R> HKT for FunctionKind<R> {
+    type Apply<A> = Box<dyn FnMut(R) -> A>;
+}
+
+/// The kind of `(W, _)`, a value with some extra data alongside it.
+///
+/// It's `WriterT`'s shape without the transformer: `map2` combines the `W`s with their
+/// `Semigroup`, `pure` starts from `W::empty()`, and the value can always be extracted.
+///
+/// ```rust
+/// # use rust_hkt::part3::{Applicative, Apply, Comonad, OptionKind, Traverse, TupleKind};
+/// let total = TupleKind::map2((vec!["a"], 1), (vec!["b"], 2), |a, b| a + b);
+/// assert_eq!(total, (vec!["a", "b"], 3));
+/// assert_eq!(TupleKind::<String>::pure(1), (String::new(), 1));
+/// assert_eq!(TupleKind::extract(("ignored", 1)), 1);
+///
+/// let parsed = TupleKind::traverse::<OptionKind, _, _, _>(("port", "80"), |s| s.parse::<u16>().ok());
+/// assert_eq!(parsed, Some(("port", 80)));
+/// ```
+pub struct TupleKind<W>(PhantomData<W>);
+
+impl<W> HKT for TupleKind<W> {
+    type Apply<A> = (W, A);
+}
+
+/// The kind of 2-tuples.
+pub struct TupleKind2;
+
+impl HKT2 for TupleKind2 {
+    type Apply2<A, B> = (A, B);
+}
+
+/// The kind of boxed functions, `A -> B`.
+///
+/// The function is `FnMut` rather than `Fn`, so that it can be built from the `FnMut`s the
+/// typeclasses take. Like every stored closure it only gets instances for `'static`.
+pub struct FunctionKind2;
+
+impl HKT2 for FunctionKind2 {
+    type Apply2<A, B> = Box<dyn FnMut(A) -> B>;
+}
@@ -0,0 +1,109 @@
+use std::collections::{BTreeMap, HashMap};
+use std::hash::Hash;
+
+use data::Ior;
+
+use super::{BTreeMapKind, Functor, HashMapKind, OptionKind, VecKind};
+
+/// Zipping which keeps whatever doesn't line up: `align(F<A>, F<B>) -> F<Ior<A, B>>`.
+///
+/// `map2` on `Vec` pairs every element with every other one, and `zip` stops at the shorter
+/// side. `align` pairs elements up by position, then carries on with the leftovers of the longer
+/// side, so no information is lost. For a `HashMap` the "position" is the key, which makes
+/// `align_with` an outer join:
+///
+/// ```rust
+/// # use std::collections::HashMap;
+/// # use rust_hkt::data::Ior;
+/// # use rust_hkt::part3::{Align, HashMapKind, OptionKind, VecKind};
+/// assert_eq!(
+///     VecKind::align(vec![1, 2, 3], vec!["a"]),
+///     vec![Ior::Both(1, "a"), Ior::Left(2), Ior::Left(3)]
+/// );
+/// assert_eq!(OptionKind::align(None::<i32>, Some("a")), Some(Ior::Right("a")));
+///
+/// let stock: HashMap<_, _> = vec![("apples", 3), ("pears", 1)].into_iter().collect();
+/// let sold: HashMap<_, _> = vec![("pears", 1), ("plums", 2)].into_iter().collect();
+/// let left = HashMapKind::align_with(stock, sold, |ior| {
+///     let (have, gone) = ior.pad();
+///     have.unwrap_or(0) - gone.unwrap_or(0)
+/// });
+///
+/// let expected: HashMap<_, _> = vec![("apples", 3), ("pears", 0), ("plums", -2)].into_iter().collect();
+/// assert_eq!(left, expected);
+/// ```
+///
+/// `align(fa, fa)` should be `fmap(fa, |a| Ior::Both(a, a))`: there's no padding when both
+/// sides have the same shape.
+pub trait Align<'a>: Functor<'a> {
+    fn align<A, B>(fa: Self::Apply<A>, fb: Self::Apply<B>) -> Self::Apply<Ior<A, B>>
+    where
+        A: 'a,
+        B: 'a;
+
+    fn align_with<A, B, C, F>(fa: Self::Apply<A>, fb: Self::Apply<B>, f: F) -> Self::Apply<C>
+    where
+        A: 'a,
+        B: 'a,
+        C: 'a,
+        F: FnMut(Ior<A, B>) -> C + 'a,
+    {
+        Self::fmap(Self::align(fa, fb), f)
+    }
+}
+
+impl<'a> Align<'a> for OptionKind {
+    fn align<A, B>(fa: Option<A>, fb: Option<B>) -> Option<Ior<A, B>> {
+        match (fa, fb) {
+            (Some(a), Some(b)) => Some(Ior::Both(a, b)),
+            (Some(a), None) => Some(Ior::Left(a)),
+            (None, Some(b)) => Some(Ior::Right(b)),
+            (None, None) => None,
+        }
+    }
+}
+
+impl<'a> Align<'a> for VecKind {
+    fn align<A, B>(fa: Vec<A>, fb: Vec<B>) -> Vec<Ior<A, B>> {
+        let mut fa = fa.into_iter();
+        let mut fb = fb.into_iter();
+        let mut out = Vec::with_capacity(fa.len().max(fb.len()));
+        while let Some(ior) = OptionKind::align(fa.next(), fb.next()) {
+            out.push(ior);
+        }
+        out
+    }
+}
+
+impl<'a, K> Align<'a> for HashMapKind<K>
+where
+    K: Eq + Hash,
+{
+    fn align<A, B>(fa: HashMap<K, A>, mut fb: HashMap<K, B>) -> HashMap<K, Ior<A, B>> {
+        let mut out = HashMap::with_capacity(fa.len().max(fb.len()));
+        for (k, a) in fa {
+            let ior = match fb.remove(&k) {
+                Some(b) => Ior::Both(a, b),
+                None => Ior::Left(a),
+            };
+            out.insert(k, ior);
+        }
+        out.extend(fb.into_iter().map(|(k, b)| (k, Ior::Right(b))));
+        out
+    }
+}
+
+impl<'a, K: Ord> Align<'a> for BTreeMapKind<K> {
+    fn align<A, B>(fa: BTreeMap<K, A>, mut fb: BTreeMap<K, B>) -> BTreeMap<K, Ior<A, B>> {
+        let mut out = BTreeMap::new();
+        for (k, a) in fa {
+            let ior = match fb.remove(&k) {
+                Some(b) => Ior::Both(a, b),
+                None => Ior::Left(a),
+            };
+            out.insert(k, ior);
+        }
+        out.extend(fb.into_iter().map(|(k, b)| (k, Ior::Right(b))));
+        out
+    }
+}
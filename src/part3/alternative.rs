@@ -0,0 +1,73 @@
+use super::{Applicative, LinkedListKind, MonoidK, OptionKind, VecKind};
+
+/// An `Applicative` which is also a `MonoidK`, i.e. one with a notion of failure (`empty_k`) and
+/// of choice (`combine_k`).
+///
+/// `empty_k` has to be absorbing for `ap` on the left, and `ap` has to distribute over
+/// `combine_k`. For `Option` that means the first alternative to succeed wins, and for `Vec` that
+/// every alternative is kept, like a parser which returns all of its parses:
+///
+/// ```rust
+/// # use rust_hkt::part3::{Alternative, FlatMap, Functor, OptionKind, VecKind};
+/// let parse = |s: &str| {
+///     OptionKind::choice(vec![
+///         s.parse::<i32>().ok(),
+///         s.strip_prefix("0x").and_then(|hex| i32::from_str_radix(hex, 16).ok()),
+///     ])
+/// };
+/// assert_eq!(parse("12"), Some(12));
+/// assert_eq!(parse("0x12"), Some(18));
+/// assert_eq!(parse("twelve"), None);
+///
+/// let pythagorean = VecKind::flat_map((1..15).collect(), |a| {
+///     VecKind::flat_map((a..15).collect(), move |b| {
+///         VecKind::flat_map((b..15).collect(), move |c| {
+///             VecKind::fmap(VecKind::guard(a * a + b * b == c * c), move |()| (a, b, c))
+///         })
+///     })
+/// });
+/// assert_eq!(pythagorean, vec![(3, 4, 5), (5, 12, 13), (6, 8, 10)]);
+/// ```
+///
+/// Haskell's `some` and `many`, which repeat an effect until it fails, are left out: `Option`
+/// and `Vec` never change their answer on a second try, so neither would terminate. `optional`
+/// is the one-shot version.
+pub trait Alternative<'a>: Applicative<'a> + MonoidK {
+    /// `pure(())` if `cond` holds, otherwise `empty_k()`, which cuts the rest of a `flat_map`
+    /// chain short.
+    fn guard(cond: bool) -> Self::Apply<()> {
+        if cond {
+            Self::pure(())
+        } else {
+            Self::empty_k()
+        }
+    }
+
+    /// Every alternative combined with `combine_k`, or `empty_k()` if there aren't any.
+    fn choice<A, I>(alternatives: I) -> Self::Apply<A>
+    where
+        I: IntoIterator<Item = Self::Apply<A>>,
+    {
+        alternatives
+            .into_iter()
+            .fold(Self::empty_k(), |acc, fa| Self::combine_k(acc, fa))
+    }
+
+    /// Tries `fa`, with `None` as the fallback.
+    ///
+    /// ```rust
+    /// # use rust_hkt::part3::{Alternative, OptionKind, VecKind};
+    /// assert_eq!(OptionKind::optional(Some(1)), Some(Some(1)));
+    /// assert_eq!(OptionKind::optional(None::<i32>), Some(None));
+    /// assert_eq!(VecKind::optional(vec![1, 2]), vec![Some(1), Some(2), None]);
+    /// ```
+    fn optional<A: 'a>(fa: Self::Apply<A>) -> Self::Apply<Option<A>> {
+        Self::combine_k(Self::fmap(fa, Some), Self::pure(None))
+    }
+}
+
+impl<'a> Alternative<'a> for OptionKind {}
+
+impl<'a> Alternative<'a> for VecKind {}
+
+impl<'a> Alternative<'a> for LinkedListKind {}
@@ -0,0 +1,94 @@
+use std::collections::{LinkedList, VecDeque};
+use std::marker::PhantomData;
+use std::ops::ControlFlow;
+use std::task::Poll;
+
+use kernel::Monoid;
+
+use super::{
+    Apply, ControlFlowKind, LinkedListKind, OptionKind, PhantomDataKind, PollKind, PollResultKind,
+    ResultKind, TupleKind, VecDequeKind, VecKind,
+};
+
+/// An `Apply` which can also lift a plain value into the context with `pure`:
+///
+/// ```rust
+/// # use rust_hkt::part3::{Applicative, Apply, OptionKind, ResultKind, VecKind};
+/// assert_eq!(OptionKind::pure(1), Some(1));
+/// assert_eq!(VecKind::map2(VecKind::pure(1), vec![10, 20], |a, b| a + b), vec![11, 21]);
+/// assert_eq!(ResultKind::<()>::pure(1), Ok(1));
+/// ```
+pub trait Applicative<'a>: Apply<'a> {
+    fn pure<A: 'a>(a: A) -> Self::Apply<A>;
+}
+
+/// An `Applicative` whose effects can be combined in either order:
+///
+/// `map2(fa, fb, |a, b| (a, b)) == map2(fb, fa, |b, a| (a, b))`
+///
+/// `Option` is commutative, since the result is `None` whichever side was `None`, but `Result`
+/// isn't: with two `Err`s, the one on the left wins. Anything which runs effects in an order it
+/// doesn't control, like traversing a `HashMap`, needs one of these to give a predictable result.
+pub trait CommutativeApplicative<'a>: Applicative<'a> {}
+
+impl<'a> CommutativeApplicative<'a> for OptionKind {}
+
+impl<'a> Applicative<'a> for OptionKind {
+    fn pure<A>(a: A) -> Option<A> {
+        Some(a)
+    }
+}
+
+impl<'a> Applicative<'a> for VecKind {
+    fn pure<A>(a: A) -> Vec<A> {
+        vec![a]
+    }
+}
+
+impl<'a> Applicative<'a> for VecDequeKind {
+    fn pure<A>(a: A) -> VecDeque<A> {
+        VecDeque::from([a])
+    }
+}
+
+impl<'a> Applicative<'a> for LinkedListKind {
+    fn pure<A>(a: A) -> LinkedList<A> {
+        LinkedList::from([a])
+    }
+}
+
+impl<'a, B> Applicative<'a> for ControlFlowKind<B> {
+    fn pure<C>(c: C) -> ControlFlow<B, C> {
+        ControlFlow::Continue(c)
+    }
+}
+
+impl<'a> Applicative<'a> for PhantomDataKind {
+    fn pure<A>(_: A) -> PhantomData<A> {
+        PhantomData
+    }
+}
+
+impl<'a> Applicative<'a> for PollKind {
+    fn pure<A>(a: A) -> Poll<A> {
+        Poll::Ready(a)
+    }
+}
+
+impl<'a, E> Applicative<'a> for PollResultKind<E> {
+    fn pure<A>(a: A) -> Poll<Result<A, E>> {
+        Poll::Ready(Ok(a))
+    }
+}
+
+impl<'a, E> Applicative<'a> for ResultKind<E> {
+    fn pure<A>(a: A) -> Result<A, E> {
+        Ok(a)
+    }
+}
+
+impl<'a, W: Monoid> Applicative<'a> for TupleKind<W> {
+    fn pure<A>(a: A) -> (W, A) {
+        (W::empty(), a)
+    }
+}
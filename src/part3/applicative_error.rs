@@ -0,0 +1,109 @@
+use data::Either;
+
+use super::{Applicative, OptionKind, ResultKind};
+
+/// An `Applicative` which can fail with an `E`, and recover from it.
+///
+/// Recovering only needs to look at the error, never to feed a value into the rest of a
+/// computation, so this works for types like `Validated` which have no `Monad`. `MonadError`
+/// adds the combinators which do need `flat_map`.
+///
+/// ```rust
+/// # use rust_hkt::data::{Either, Validated, ValidatedKind};
+/// # use rust_hkt::part3::{ApplicativeError, OptionKind, ResultKind};
+/// let parsed = "x".parse::<i32>().map_err(|e| e.to_string());
+/// assert_eq!(ResultKind::handle_error(parsed, |_| 0), Ok(0));
+///
+/// let fallback = ResultKind::recover_with(Err::<i32, _>("not found"), |e| {
+///     if *e == "not found" { Some(Ok(0)) } else { None }
+/// });
+/// assert_eq!(fallback, Ok(0));
+/// assert_eq!(ResultKind::recover_with(Err::<i32, _>("timeout"), |_| None), Err("timeout"));
+///
+/// let checked = ValidatedKind::from_either(Either::Left(vec!["too short"]));
+/// assert_eq!(ValidatedKind::attempt::<i32>(checked), Validated::Valid(Either::Left(vec!["too short"])));
+///
+/// assert_eq!(OptionKind::handle_error_with(None, |()| Some(1)), Some(1));
+/// ```
+pub trait ApplicativeError<'a, E>: Applicative<'a> {
+    fn raise_error<A: 'a>(e: E) -> Self::Apply<A>;
+
+    /// Replaces a failure with whatever `f` makes of its error, which may well be another
+    /// failure.
+    fn handle_error_with<A, F>(fa: Self::Apply<A>, f: F) -> Self::Apply<A>
+    where
+        A: 'a,
+        F: FnMut(E) -> Self::Apply<A> + 'a;
+
+    /// Replaces a failure with a value computed from its error.
+    fn handle_error<A, F>(fa: Self::Apply<A>, mut f: F) -> Self::Apply<A>
+    where
+        A: 'a,
+        F: FnMut(E) -> A + 'a,
+    {
+        Self::handle_error_with(fa, move |e| Self::pure(f(e)))
+    }
+
+    /// `handle_error_with` for only some errors: where `f` gives back `None`, the original
+    /// error is kept.
+    fn recover_with<A, F>(fa: Self::Apply<A>, mut f: F) -> Self::Apply<A>
+    where
+        A: 'a,
+        F: FnMut(&E) -> Option<Self::Apply<A>> + 'a,
+    {
+        Self::handle_error_with(fa, move |e| match f(&e) {
+            Some(fa) => fa,
+            None => Self::raise_error(e),
+        })
+    }
+
+    /// Moves the error into the value, so that the result never fails.
+    fn attempt<A>(fa: Self::Apply<A>) -> Self::Apply<Either<E, A>>
+    where
+        A: 'a,
+        E: 'a,
+    {
+        Self::handle_error(Self::fmap(fa, Either::Right), Either::Left)
+    }
+
+    fn from_either<A: 'a>(either: Either<E, A>) -> Self::Apply<A> {
+        match either {
+            Either::Left(e) => Self::raise_error(e),
+            Either::Right(a) => Self::pure(a),
+        }
+    }
+
+    fn from_result<A: 'a>(result: Result<A, E>) -> Self::Apply<A> {
+        match result {
+            Ok(a) => Self::pure(a),
+            Err(e) => Self::raise_error(e),
+        }
+    }
+}
+
+/// `None` carries no information, so its error is `()`.
+impl<'a> ApplicativeError<'a, ()> for OptionKind {
+    fn raise_error<A>(_: ()) -> Option<A> {
+        None
+    }
+
+    fn handle_error_with<A, F>(fa: Option<A>, mut f: F) -> Option<A>
+    where
+        F: FnMut(()) -> Option<A>,
+    {
+        fa.or_else(|| f(()))
+    }
+}
+
+impl<'a, E> ApplicativeError<'a, E> for ResultKind<E> {
+    fn raise_error<A>(e: E) -> Result<A, E> {
+        Err(e)
+    }
+
+    fn handle_error_with<A, F>(fa: Result<A, E>, f: F) -> Result<A, E>
+    where
+        F: FnMut(E) -> Result<A, E>,
+    {
+        fa.or_else(f)
+    }
+}
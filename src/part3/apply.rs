@@ -0,0 +1,299 @@
+use std::collections::{LinkedList, VecDeque};
+use std::marker::PhantomData;
+use std::ops::ControlFlow;
+use std::task::Poll;
+
+use kernel::Semigroup;
+
+use super::{
+    ControlFlowKind, FunctionKind, Functor, LinkedListKind, OptionKind, PhantomDataKind, PollKind,
+    PollResultKind, ResultKind, Semigroupal, TupleKind, VecDequeKind, VecKind,
+};
+
+/// A `Functor` which can combine two values in context with `map2`, but not necessarily put a
+/// plain value into one. That's `Applicative`, which adds `pure`.
+///
+/// Most instances are applicatives too, but not all: a `Const<C, _>` can only be combined when
+/// `C` is a `Semigroup`, and only has a `pure` when `C` is a `Monoid`.
+///
+/// `map2` needs `A` and `B` to be `Clone`: `Vec` combines every element with every other element,
+/// so it has to hand out the same value more than once.
+///
+/// ```rust
+/// # use rust_hkt::part3::{Apply, OptionKind, ResultKind, VecKind};
+/// assert_eq!(OptionKind::map2(Some(1), Some(2), |a, b| a + b), Some(3));
+/// assert_eq!(OptionKind::map2(Some(1), None::<i32>, |a, b| a + b), None);
+/// assert_eq!(VecKind::map2(vec![1, 2], vec![10, 20], |a, b| a + b), vec![11, 21, 12, 22]);
+/// assert_eq!(ResultKind::map2(Ok(1), Err::<i32, _>("e"), |a, b| a + b), Err("e"));
+/// assert_eq!(OptionKind::ap(Some(|i: i32| i + 1), Some(1)), Some(2));
+/// assert_eq!(OptionKind::map3(Some(1), Some(2), Some(3), |a, b, c| a + b + c), Some(6));
+/// assert_eq!(VecKind::map4(vec![1], vec![2], vec![3, 4], vec![5], |a, b, c, d| a + b + c + d), vec![11, 12]);
+/// ```
+pub trait Apply<'a>: Functor<'a> + Semigroupal<'a> {
+    fn map2<A, B, C, F>(fa: Self::Apply<A>, fb: Self::Apply<B>, f: F) -> Self::Apply<C>
+    where
+        A: Clone + 'a,
+        B: Clone + 'a,
+        C: 'a,
+        F: FnMut(A, B) -> C + 'a;
+
+    fn map3<A, B, C, D, F>(
+        fa: Self::Apply<A>,
+        fb: Self::Apply<B>,
+        fc: Self::Apply<C>,
+        mut f: F,
+    ) -> Self::Apply<D>
+    where
+        A: Clone + 'a,
+        B: Clone + 'a,
+        C: Clone + 'a,
+        D: 'a,
+        F: FnMut(A, B, C) -> D + 'a,
+    {
+        let fab = Self::map2(fa, fb, |a, b| (a, b));
+        Self::map2(fab, fc, move |(a, b), c| f(a, b, c))
+    }
+
+    fn map4<A, B, C, D, E, F>(
+        fa: Self::Apply<A>,
+        fb: Self::Apply<B>,
+        fc: Self::Apply<C>,
+        fd: Self::Apply<D>,
+        mut f: F,
+    ) -> Self::Apply<E>
+    where
+        A: Clone + 'a,
+        B: Clone + 'a,
+        C: Clone + 'a,
+        D: Clone + 'a,
+        E: 'a,
+        F: FnMut(A, B, C, D) -> E + 'a,
+    {
+        let fabc = Self::map3(fa, fb, fc, |a, b, c| (a, b, c));
+        Self::map2(fabc, fd, move |(a, b, c), d| f(a, b, c, d))
+    }
+
+    /// `map2` with the second argument only computed if it's needed.
+    ///
+    /// For an applicative which can fail, like `Option`, a failed `fa` decides the result on its
+    /// own, so there's no point computing `fb`. `traverse` uses this to stop calling its function
+    /// after the first failure.
+    ///
+    /// ```rust
+    /// # use rust_hkt::part3::{Apply, OptionKind, ResultKind};
+    /// let mut computed = false;
+    /// let sum = OptionKind::map2_lazy(None::<i32>, || { computed = true; Some(1) }, |a, b| a + b);
+    /// assert_eq!(sum, None);
+    /// assert!(!computed);
+    ///
+    /// let sum = ResultKind::<()>::map2_lazy(Ok(1), || Ok(2), |a, b| a + b);
+    /// assert_eq!(sum, Ok(3));
+    /// ```
+    fn map2_lazy<A, B, C, F, G>(fa: Self::Apply<A>, fb: G, f: F) -> Self::Apply<C>
+    where
+        A: Clone + 'a,
+        B: Clone + 'a,
+        C: 'a,
+        F: FnMut(A, B) -> C + 'a,
+        G: FnOnce() -> Self::Apply<B>,
+    {
+        Self::map2(fa, fb(), f)
+    }
+
+    fn ap<A, B, F>(ff: Self::Apply<F>, fa: Self::Apply<A>) -> Self::Apply<B>
+    where
+        A: Clone + 'a,
+        B: 'a,
+        F: FnOnce(A) -> B + Clone + 'a,
+    {
+        Self::map2(ff, fa, |f, a| f(a))
+    }
+}
+
+impl<'a> Apply<'a> for OptionKind {
+    fn map2<A, B, C, F>(fa: Option<A>, fb: Option<B>, mut f: F) -> Option<C>
+    where
+        F: FnMut(A, B) -> C,
+    {
+        match (fa, fb) {
+            (Some(a), Some(b)) => Some(f(a, b)),
+            _ => None,
+        }
+    }
+
+    fn map2_lazy<A, B, C, F, G>(fa: Option<A>, fb: G, mut f: F) -> Option<C>
+    where
+        F: FnMut(A, B) -> C,
+        G: FnOnce() -> Option<B>,
+    {
+        let a = fa?;
+        fb().map(|b| f(a, b))
+    }
+}
+
+impl<'a> Apply<'a> for VecKind {
+    fn map2<A, B, C, F>(fa: Vec<A>, fb: Vec<B>, mut f: F) -> Vec<C>
+    where
+        A: Clone,
+        B: Clone,
+        F: FnMut(A, B) -> C,
+    {
+        let mut out = Vec::with_capacity(fa.len() * fb.len());
+        for a in fa {
+            for b in fb.iter().cloned() {
+                out.push(f(a.clone(), b));
+            }
+        }
+        out
+    }
+}
+
+impl<'a> Apply<'a> for VecDequeKind {
+    fn map2<A, B, C, F>(fa: VecDeque<A>, fb: VecDeque<B>, mut f: F) -> VecDeque<C>
+    where
+        A: Clone,
+        B: Clone,
+        F: FnMut(A, B) -> C,
+    {
+        let mut out = VecDeque::with_capacity(fa.len() * fb.len());
+        for a in fa {
+            for b in fb.iter().cloned() {
+                out.push_back(f(a.clone(), b));
+            }
+        }
+        out
+    }
+}
+
+impl<'a> Apply<'a> for LinkedListKind {
+    fn map2<A, B, C, F>(fa: LinkedList<A>, fb: LinkedList<B>, mut f: F) -> LinkedList<C>
+    where
+        A: Clone,
+        B: Clone,
+        F: FnMut(A, B) -> C,
+    {
+        let mut out = LinkedList::new();
+        for a in fa {
+            for b in fb.iter().cloned() {
+                out.push_back(f(a.clone(), b));
+            }
+        }
+        out
+    }
+}
+
+impl<'a, B> Apply<'a> for ControlFlowKind<B> {
+    fn map2<C, D, E, F>(fc: ControlFlow<B, C>, fd: ControlFlow<B, D>, mut f: F) -> ControlFlow<B, E>
+    where
+        F: FnMut(C, D) -> E,
+    {
+        match (fc, fd) {
+            (ControlFlow::Continue(c), ControlFlow::Continue(d)) => ControlFlow::Continue(f(c, d)),
+            (ControlFlow::Break(b), _) | (_, ControlFlow::Break(b)) => ControlFlow::Break(b),
+        }
+    }
+
+    fn map2_lazy<C, D, E, F, G>(fc: ControlFlow<B, C>, fd: G, mut f: F) -> ControlFlow<B, E>
+    where
+        F: FnMut(C, D) -> E,
+        G: FnOnce() -> ControlFlow<B, D>,
+    {
+        let c = fc?;
+        fd().map_continue(|d| f(c, d))
+    }
+}
+
+impl<'a> Apply<'a> for PhantomDataKind {
+    fn map2<A, B, C, F>(_: PhantomData<A>, _: PhantomData<B>, _: F) -> PhantomData<C>
+    where
+        F: FnMut(A, B) -> C,
+    {
+        PhantomData
+    }
+}
+
+impl<'a> Apply<'a> for PollKind {
+    fn map2<A, B, C, F>(fa: Poll<A>, fb: Poll<B>, mut f: F) -> Poll<C>
+    where
+        F: FnMut(A, B) -> C,
+    {
+        match (fa, fb) {
+            (Poll::Ready(a), Poll::Ready(b)) => Poll::Ready(f(a, b)),
+            _ => Poll::Pending,
+        }
+    }
+
+    fn map2_lazy<A, B, C, F, G>(fa: Poll<A>, fb: G, mut f: F) -> Poll<C>
+    where
+        F: FnMut(A, B) -> C,
+        G: FnOnce() -> Poll<B>,
+    {
+        match fa {
+            Poll::Ready(a) => fb().map(|b| f(a, b)),
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
+
+impl<'a, E> Apply<'a> for PollResultKind<E> {
+    fn map2<A, B, C, F>(
+        fa: Poll<Result<A, E>>,
+        fb: Poll<Result<B, E>>,
+        mut f: F,
+    ) -> Poll<Result<C, E>>
+    where
+        F: FnMut(A, B) -> C,
+    {
+        match (fa, fb) {
+            (Poll::Ready(Err(e)), _) | (_, Poll::Ready(Err(e))) => Poll::Ready(Err(e)),
+            (Poll::Ready(Ok(a)), Poll::Ready(Ok(b))) => Poll::Ready(Ok(f(a, b))),
+            _ => Poll::Pending,
+        }
+    }
+}
+
+impl<'a, E> Apply<'a> for ResultKind<E> {
+    fn map2<A, B, C, F>(fa: Result<A, E>, fb: Result<B, E>, mut f: F) -> Result<C, E>
+    where
+        F: FnMut(A, B) -> C,
+    {
+        match (fa, fb) {
+            (Ok(a), Ok(b)) => Ok(f(a, b)),
+            (Err(e), _) | (_, Err(e)) => Err(e),
+        }
+    }
+
+    fn map2_lazy<A, B, C, F, G>(fa: Result<A, E>, fb: G, mut f: F) -> Result<C, E>
+    where
+        F: FnMut(A, B) -> C,
+        G: FnOnce() -> Result<B, E>,
+    {
+        let a = fa?;
+        fb().map(|b| f(a, b))
+    }
+}
+
+impl<R: Clone + 'static> Apply<'static> for FunctionKind<R> {
+    fn map2<A, B, C, F>(
+        mut fa: Box<dyn FnMut(R) -> A>,
+        mut fb: Box<dyn FnMut(R) -> B>,
+        mut f: F,
+    ) -> Box<dyn FnMut(R) -> C>
+    where
+        A: Clone + 'static,
+        B: Clone + 'static,
+        C: 'static,
+        F: FnMut(A, B) -> C + 'static,
+    {
+        Box::new(move |r: R| f(fa(r.clone()), fb(r)))
+    }
+}
+
+impl<'a, W: Semigroup> Apply<'a> for TupleKind<W> {
+    fn map2<A, B, C, F>(fa: (W, A), fb: (W, B), mut f: F) -> (W, C)
+    where
+        F: FnMut(A, B) -> C,
+    {
+        (fa.0.combine(fb.0), f(fa.1, fb.1))
+    }
+}
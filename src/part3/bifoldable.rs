@@ -0,0 +1,71 @@
+use kernel::Monoid;
+
+use super::{ResultKind2, TupleKind2, HKT2};
+
+/// `Foldable` for both type parameters of a two parameter type constructor.
+///
+/// Where `Foldable` for `ResultKind<E>` skips over an `Err`, `Bifoldable` for `ResultKind2`
+/// gets to see it, with one function for each side:
+///
+/// ```rust
+/// # use rust_hkt::data::{Either, EitherKind2, Ior, IorKind2};
+/// # use rust_hkt::part3::{Bifoldable, ResultKind2, TupleKind2};
+/// fn describe<B>(fab: B::Apply2<i32, &str>) -> String
+/// where
+///     B: Bifoldable,
+/// {
+///     B::bifold_map(fab, |i| format!("[{}]", i), |s| s.to_string())
+/// }
+///
+/// assert_eq!(describe::<ResultKind2>(Err(404)), "[404]");
+/// assert_eq!(describe::<EitherKind2>(Either::Right("fine")), "fine");
+/// assert_eq!(describe::<IorKind2>(Ior::Both(1, "a")), "[1]a");
+/// assert_eq!(describe::<TupleKind2>((2, "b")), "[2]b");
+///
+/// let total = TupleKind2::bifold_left((1, "four"), 0, |acc, i| acc + i, |acc, s| acc + s.len());
+/// assert_eq!(total, 5);
+/// ```
+pub trait Bifoldable: HKT2 {
+    fn bifold_left<A, B, C, F, G>(fab: Self::Apply2<A, B>, init: C, f: F, g: G) -> C
+    where
+        F: FnMut(C, A) -> C,
+        G: FnMut(C, B) -> C;
+
+    fn bifold_map<A, B, M, F, G>(fab: Self::Apply2<A, B>, mut f: F, mut g: G) -> M
+    where
+        M: Monoid,
+        F: FnMut(A) -> M,
+        G: FnMut(B) -> M,
+    {
+        Self::bifold_left(
+            fab,
+            M::empty(),
+            |acc, a| acc.combine(f(a)),
+            |acc, b| acc.combine(g(b)),
+        )
+    }
+}
+
+impl Bifoldable for ResultKind2 {
+    fn bifold_left<A, B, C, F, G>(fab: Result<B, A>, init: C, mut f: F, mut g: G) -> C
+    where
+        F: FnMut(C, A) -> C,
+        G: FnMut(C, B) -> C,
+    {
+        match fab {
+            Ok(b) => g(init, b),
+            Err(a) => f(init, a),
+        }
+    }
+}
+
+impl Bifoldable for TupleKind2 {
+    fn bifold_left<A, B, C, F, G>((a, b): (A, B), init: C, mut f: F, mut g: G) -> C
+    where
+        F: FnMut(C, A) -> C,
+        G: FnMut(C, B) -> C,
+    {
+        let acc = f(init, a);
+        g(acc, b)
+    }
+}
@@ -0,0 +1,87 @@
+use std::ops::ControlFlow;
+
+use super::{ControlFlowKind2, ResultKind2, TupleKind2, HKT2};
+
+/// A `Bifunctor` is a type constructor with two type parameters, both of which can be mapped over.
+///
+/// ```rust
+/// # use std::ops::ControlFlow;
+/// # use rust_hkt::part3::{Bifunctor, ControlFlowKind2, ResultKind2, TupleKind2};
+/// fn describe_error<'a, B>(fab: B::Apply2<i32, String>) -> B::Apply2<String, String>
+/// where
+///     B: Bifunctor<'a>,
+/// {
+///     B::map_left(fab, |code| format!("error code {}", code))
+/// }
+///
+/// assert_eq!(describe_error::<ResultKind2>(Err(404)), Err("error code 404".to_string()));
+/// assert_eq!(describe_error::<ResultKind2>(Ok("fine".to_string())), Ok("fine".to_string()));
+/// assert_eq!(describe_error::<TupleKind2>((1, "a".to_string())), ("error code 1".to_string(), "a".to_string()));
+///
+/// assert_eq!(TupleKind2::bimap((1, 2), |a| a + 1, |b| b * 10), (2, 20));
+/// assert_eq!(ResultKind2::map_right(Ok::<i32, ()>(1), |i| i + 1), Ok(2));
+/// assert_eq!(ControlFlowKind2::map_left(ControlFlow::<_, ()>::Break(1), |i| i + 1), ControlFlow::Break(2));
+/// ```
+pub trait Bifunctor<'a>: HKT2 {
+    fn bimap<A, B, C, D, F, G>(fab: Self::Apply2<A, B>, f: F, g: G) -> Self::Apply2<C, D>
+    where
+        A: 'a,
+        B: 'a,
+        C: 'a,
+        D: 'a,
+        F: FnMut(A) -> C + 'a,
+        G: FnMut(B) -> D + 'a;
+
+    fn map_left<A, B, C, F>(fab: Self::Apply2<A, B>, f: F) -> Self::Apply2<C, B>
+    where
+        A: 'a,
+        B: 'a,
+        C: 'a,
+        F: FnMut(A) -> C + 'a,
+    {
+        Self::bimap(fab, f, |b| b)
+    }
+
+    fn map_right<A, B, D, G>(fab: Self::Apply2<A, B>, g: G) -> Self::Apply2<A, D>
+    where
+        A: 'a,
+        B: 'a,
+        D: 'a,
+        G: FnMut(B) -> D + 'a,
+    {
+        Self::bimap(fab, |a| a, g)
+    }
+}
+
+impl<'a> Bifunctor<'a> for ResultKind2 {
+    fn bimap<A, B, C, D, F, G>(fab: Result<B, A>, f: F, g: G) -> Result<D, C>
+    where
+        F: FnMut(A) -> C,
+        G: FnMut(B) -> D,
+    {
+        fab.map(g).map_err(f)
+    }
+}
+
+impl<'a> Bifunctor<'a> for ControlFlowKind2 {
+    fn bimap<A, B, C, D, F, G>(fab: ControlFlow<A, B>, mut f: F, mut g: G) -> ControlFlow<C, D>
+    where
+        F: FnMut(A) -> C,
+        G: FnMut(B) -> D,
+    {
+        match fab {
+            ControlFlow::Break(a) => ControlFlow::Break(f(a)),
+            ControlFlow::Continue(b) => ControlFlow::Continue(g(b)),
+        }
+    }
+}
+
+impl<'a> Bifunctor<'a> for TupleKind2 {
+    fn bimap<A, B, C, D, F, G>((a, b): (A, B), mut f: F, mut g: G) -> (C, D)
+    where
+        F: FnMut(A) -> C,
+        G: FnMut(B) -> D,
+    {
+        (f(a), g(b))
+    }
+}
@@ -0,0 +1,88 @@
+use super::{Applicative, Bifoldable, Bifunctor, ResultKind2, TupleKind2};
+
+/// `Traverse` for both type parameters of a two parameter type constructor.
+///
+/// `bitraverse` runs an effect on whichever sides are there, and collects the results back up in
+/// the same shape. With `Validated` as the effect, that checks both halves of a pair at once:
+///
+/// ```rust
+/// # use rust_hkt::data::{Either, EitherKind2, Validated, ValidatedKind};
+/// # use rust_hkt::part3::{Bitraverse, OptionKind, ResultKind2, TupleKind2};
+/// fn positive(i: i32) -> Validated<Vec<String>, i32> {
+///     if i > 0 { Validated::Valid(i) } else { Validated::Invalid(vec![format!("{} <= 0", i)]) }
+/// }
+///
+/// fn non_empty(s: &str) -> Validated<Vec<String>, &str> {
+///     if s.is_empty() { Validated::Invalid(vec!["empty".to_string()]) } else { Validated::Valid(s) }
+/// }
+///
+/// let checked = TupleKind2::bitraverse::<ValidatedKind<_>, _, _, _, _, _, _>((-1, ""), positive, non_empty);
+/// assert_eq!(checked, Validated::Invalid(vec!["-1 <= 0".to_string(), "empty".to_string()]));
+///
+/// let checked = EitherKind2::bitraverse::<ValidatedKind<_>, _, _, _, _, _, _>(Either::Left(1), positive, non_empty);
+/// assert_eq!(checked, Validated::Valid(Either::Left(1)));
+///
+/// let parsed = ResultKind2::bisequence::<OptionKind, _, _>(Err::<Option<i32>, _>(Some("nope")));
+/// assert_eq!(parsed, Some(Err("nope")));
+/// ```
+pub trait Bitraverse<'a>: Bifunctor<'a> + Bifoldable {
+    fn bitraverse<G, A, B, C, D, F, H>(
+        fab: Self::Apply2<A, B>,
+        f: F,
+        g: H,
+    ) -> G::Apply<Self::Apply2<C, D>>
+    where
+        G: Applicative<'a>,
+        A: 'a,
+        B: 'a,
+        C: Clone + 'a,
+        D: Clone + 'a,
+        F: FnMut(A) -> G::Apply<C> + 'a,
+        H: FnMut(B) -> G::Apply<D> + 'a;
+
+    fn bisequence<G, A, B>(
+        fab: Self::Apply2<G::Apply<A>, G::Apply<B>>,
+    ) -> G::Apply<Self::Apply2<A, B>>
+    where
+        G: Applicative<'a>,
+        A: Clone + 'a,
+        B: Clone + 'a,
+        G::Apply<A>: 'a,
+        G::Apply<B>: 'a,
+    {
+        Self::bitraverse::<G, _, _, _, _, _, _>(fab, |ga| ga, |gb| gb)
+    }
+}
+
+impl<'a> Bitraverse<'a> for ResultKind2 {
+    fn bitraverse<G, A, B, C, D, F, H>(
+        fab: Result<B, A>,
+        mut f: F,
+        mut g: H,
+    ) -> G::Apply<Result<D, C>>
+    where
+        G: Applicative<'a>,
+        C: Clone + 'a,
+        D: Clone + 'a,
+        F: FnMut(A) -> G::Apply<C>,
+        H: FnMut(B) -> G::Apply<D>,
+    {
+        match fab {
+            Ok(b) => G::fmap(g(b), Ok),
+            Err(a) => G::fmap(f(a), Err),
+        }
+    }
+}
+
+impl<'a> Bitraverse<'a> for TupleKind2 {
+    fn bitraverse<G, A, B, C, D, F, H>((a, b): (A, B), mut f: F, mut g: H) -> G::Apply<(C, D)>
+    where
+        G: Applicative<'a>,
+        C: Clone + 'a,
+        D: Clone + 'a,
+        F: FnMut(A) -> G::Apply<C>,
+        H: FnMut(B) -> G::Apply<D>,
+    {
+        G::map2(f(a), g(b), |c, d| (c, d))
+    }
+}
@@ -0,0 +1,148 @@
+use std::borrow::Cow;
+
+use kernel::Monoid;
+
+use super::{VecKind, HKT};
+
+/// A type constructor of a lifetime and a type, `Apply<'b, A>`.
+///
+/// `HKT::Apply<A>` has no room for a lifetime, so nothing which borrows its contents, like a
+/// slice or a `Cow`, can have a kind. `BorrowedHKT` adds one: `Apply<'b, A>` is the constructor
+/// applied to a lifetime as well as a type.
+///
+/// A borrowed container can't be turned into a borrowed container of something else, since there
+/// would be nothing for it to borrow from. So `fmap_borrowed` produces an owned container, named
+/// by `BorrowedFunctor::Owned`, and both it and `fold_left_borrowed` hand out elements by
+/// reference:
+///
+/// ```rust
+/// # use std::borrow::Cow;
+/// # use rust_hkt::part3::{BorrowedFoldable, BorrowedFunctor, CowSliceKind, SliceKind};
+/// let words = ["a", "bc"];
+/// assert_eq!(SliceKind::fmap_borrowed(&words[..], |s| s.len()), vec![1, 2]);
+/// assert_eq!(SliceKind::fold_map_borrowed(&words[..], |s| s.to_uppercase()), "ABC");
+///
+/// fn total<'b, F: BorrowedFoldable>(fa: F::Apply<'b, u32>) -> u32 {
+///     F::fold_left_borrowed(fa, 0, |acc, i| acc + i)
+/// }
+/// assert_eq!(total::<CowSliceKind>(Cow::Borrowed(&[1, 2][..])), 3);
+/// assert_eq!(total::<CowSliceKind>(Cow::Owned(vec![3, 4])), 7);
+/// ```
+///
+/// `A` has to be `Clone` because `Cow<'b, [A]>` is only a type when `[A]` is `ToOwned`.
+pub trait BorrowedHKT {
+    type Apply<'b, A: Clone + 'b>;
+}
+
+/// `fmap` for a borrowed container, into its owned counterpart.
+pub trait BorrowedFunctor: BorrowedHKT {
+    type Owned: HKT;
+
+    fn fmap_borrowed<'b, A, B, F>(fa: Self::Apply<'b, A>, f: F) -> <Self::Owned as HKT>::Apply<B>
+    where
+        A: Clone + 'b,
+        F: FnMut(&A) -> B;
+
+    /// `fmap_borrowed` with a function which takes its elements by value.
+    ///
+    /// By default every element is cloned, but an instance which owns its elements can hand them
+    /// over instead, like an owned `Cow` does with its `Vec`:
+    ///
+    /// ```rust
+    /// # use std::borrow::Cow;
+    /// # use rust_hkt::part3::{BorrowedFunctor, CowSliceKind};
+    /// let names = vec!["ada".to_string(), "grace".to_string()];
+    /// let shouted = CowSliceKind::fmap_owned(Cow::Owned(names), |name| name.to_uppercase());
+    /// assert_eq!(shouted, vec!["ADA", "GRACE"]);
+    /// ```
+    fn fmap_owned<'b, A, B, F>(fa: Self::Apply<'b, A>, mut f: F) -> <Self::Owned as HKT>::Apply<B>
+    where
+        A: Clone + 'b,
+        F: FnMut(A) -> B,
+    {
+        Self::fmap_borrowed(fa, |a| f(a.clone()))
+    }
+}
+
+/// `fold_left` and `fold_map` for a borrowed container.
+pub trait BorrowedFoldable: BorrowedHKT {
+    fn fold_left_borrowed<'b, A, B, F>(fa: Self::Apply<'b, A>, init: B, f: F) -> B
+    where
+        A: Clone + 'b,
+        F: FnMut(B, &A) -> B;
+
+    fn fold_map_borrowed<'b, A, M, F>(fa: Self::Apply<'b, A>, mut f: F) -> M
+    where
+        A: Clone + 'b,
+        M: Monoid,
+        F: FnMut(&A) -> M,
+    {
+        Self::fold_left_borrowed(fa, M::empty(), |acc, a| acc.combine(f(a)))
+    }
+}
+
+/// The kind of `&'b [A]`.
+pub struct SliceKind;
+
+impl BorrowedHKT for SliceKind {
+    type Apply<'b, A: Clone + 'b> = &'b [A];
+}
+
+impl BorrowedFunctor for SliceKind {
+    type Owned = VecKind;
+
+    fn fmap_borrowed<'b, A, B, F>(fa: &'b [A], f: F) -> Vec<B>
+    where
+        A: Clone + 'b,
+        F: FnMut(&A) -> B,
+    {
+        fa.iter().map(f).collect()
+    }
+}
+
+impl BorrowedFoldable for SliceKind {
+    fn fold_left_borrowed<'b, A, B, F>(fa: &'b [A], init: B, f: F) -> B
+    where
+        A: Clone + 'b,
+        F: FnMut(B, &A) -> B,
+    {
+        fa.iter().fold(init, f)
+    }
+}
+
+/// The kind of `Cow<'b, [A]>`, which is either borrowed or owned.
+pub struct CowSliceKind;
+
+impl BorrowedHKT for CowSliceKind {
+    type Apply<'b, A: Clone + 'b> = Cow<'b, [A]>;
+}
+
+impl BorrowedFunctor for CowSliceKind {
+    type Owned = VecKind;
+
+    fn fmap_borrowed<'b, A, B, F>(fa: Cow<'b, [A]>, f: F) -> Vec<B>
+    where
+        A: Clone + 'b,
+        F: FnMut(&A) -> B,
+    {
+        fa.iter().map(f).collect()
+    }
+
+    fn fmap_owned<'b, A, B, F>(fa: Cow<'b, [A]>, f: F) -> Vec<B>
+    where
+        A: Clone + 'b,
+        F: FnMut(A) -> B,
+    {
+        fa.into_owned().into_iter().map(f).collect()
+    }
+}
+
+impl BorrowedFoldable for CowSliceKind {
+    fn fold_left_borrowed<'b, A, B, F>(fa: Cow<'b, [A]>, init: B, f: F) -> B
+    where
+        A: Clone + 'b,
+        F: FnMut(B, &A) -> B,
+    {
+        fa.iter().fold(init, f)
+    }
+}
@@ -0,0 +1,67 @@
+use super::Monad;
+
+/// Resource safety: making sure that whatever is acquired gets released.
+///
+/// `guarantee` runs a finalizer after a computation however it ends, whether it succeeds, fails
+/// or panics. `bracket` builds on it: acquire a resource, use it, and release it afterwards no
+/// matter what happened in between:
+///
+/// ```rust
+/// # use std::cell::RefCell;
+/// # use std::rc::Rc;
+/// # use rust_hkt::data::{Either, EitherT, EitherTKind, IO, IOKind};
+/// # use rust_hkt::part3::Bracket;
+/// let events = Rc::new(RefCell::new(Vec::new()));
+/// let log = |events: &Rc<RefCell<Vec<String>>>, event: String| {
+///     let events = events.clone();
+///     IO::delay(move || events.borrow_mut().push(event))
+/// };
+///
+/// type App = EitherTKind<String, IOKind>;
+/// let open = EitherT::lift(log(&events, "open".to_string()).map(|()| "file.txt"));
+/// let program = App::bracket(
+///     open,
+///     |_| EitherT::<_, IOKind, usize>::left("couldn't read".to_string()),
+///     {
+///         let events = events.clone();
+///         move |name| EitherT::lift(log(&events, format!("close {}", name)))
+///     },
+/// );
+///
+/// assert_eq!(program.run().run(), Either::Left("couldn't read".to_string()));
+/// assert_eq!(*events.borrow(), vec!["open", "close file.txt"]);
+/// ```
+///
+/// For `IO` the failure to guard against is a panic. The finalizer runs, then the panic carries
+/// on:
+///
+/// ```rust
+/// # use std::cell::Cell;
+/// # use std::panic;
+/// # use std::rc::Rc;
+/// # use rust_hkt::data::{IO, IOKind};
+/// # use rust_hkt::part3::Bracket;
+/// let released = Rc::new(Cell::new(false));
+/// let program = IOKind::guarantee(IO::delay(|| -> i32 { panic!("boom") }), {
+///     let released = released.clone();
+///     IO::delay(move || released.set(true))
+/// });
+///
+/// assert!(panic::catch_unwind(panic::AssertUnwindSafe(|| program.run())).is_err());
+/// assert!(released.get());
+/// ```
+pub trait Bracket<'a>: Monad<'a> {
+    fn guarantee<A: 'a>(fa: Self::Apply<A>, finalizer: Self::Apply<()>) -> Self::Apply<A>;
+
+    fn bracket<A, B, U, R>(acquire: Self::Apply<A>, mut use_: U, mut release: R) -> Self::Apply<B>
+    where
+        A: Clone + 'a,
+        B: 'a,
+        U: FnMut(A) -> Self::Apply<B> + 'a,
+        R: FnMut(A) -> Self::Apply<()> + 'a,
+    {
+        Self::flat_map(acquire, move |a| {
+            Self::guarantee(use_(a.clone()), release(a))
+        })
+    }
+}
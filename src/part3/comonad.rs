@@ -0,0 +1,52 @@
+use super::{BoxKind, Functor, TupleKind};
+
+/// The dual of `Monad`: where a monad can put a value into a context (`pure`) and chain functions
+/// which produce a context (`flat_map`), a comonad can take a value out of a context (`extract`)
+/// and extend functions which consume a context (`coflat_map`).
+///
+/// `coflat_map` may need to hand the function more than one context built from the same values,
+/// so `A` has to be `Clone`. A lazy comonad like `data::Store` shares the function between all of
+/// its positions and calls it whenever one is looked up, so `f` has to be an `Fn` rather than an
+/// `FnMut`.
+///
+/// ```rust
+/// # use rust_hkt::part3::{BoxKind, Comonad};
+/// assert_eq!(BoxKind::extract(Box::new(1)), 1);
+/// assert_eq!(BoxKind::coflat_map(Box::new(2), |b| *b * 10), Box::new(20));
+/// ```
+pub trait Comonad<'a>: Functor<'a> {
+    fn extract<A>(wa: Self::Apply<A>) -> A;
+
+    fn coflat_map<A, B, F>(wa: Self::Apply<A>, f: F) -> Self::Apply<B>
+    where
+        A: Clone + 'a,
+        B: 'a,
+        F: Fn(Self::Apply<A>) -> B + 'a;
+}
+
+impl<'a> Comonad<'a> for BoxKind {
+    fn extract<A>(wa: Box<A>) -> A {
+        *wa
+    }
+
+    fn coflat_map<A, B, F>(wa: Box<A>, f: F) -> Box<B>
+    where
+        F: Fn(Box<A>) -> B,
+    {
+        Box::new(f(wa))
+    }
+}
+
+impl<'a, W: Clone> Comonad<'a> for TupleKind<W> {
+    fn extract<A>(wa: (W, A)) -> A {
+        wa.1
+    }
+
+    fn coflat_map<A, B, F>(wa: (W, A), f: F) -> (W, B)
+    where
+        F: Fn((W, A)) -> B,
+    {
+        let w = wa.0.clone();
+        (w, f(wa))
+    }
+}
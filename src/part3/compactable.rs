@@ -0,0 +1,98 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use data::Either;
+
+use super::{HashMapKind, OptionKind, VecKind, HKT};
+
+/// Dropping the holes from a container, or splitting it in two.
+///
+/// `compact` throws away the `None`s and unwraps the rest. `separate` sorts `Left`s from
+/// `Right`s, which, after a `fmap` into `Either`, is the familiar "partition the successes from
+/// the failures":
+///
+/// ```rust
+/// # use rust_hkt::data::Either;
+/// # use rust_hkt::part3::{Compactable, Functor, VecKind};
+/// let parsed = VecKind::fmap(vec!["1", "x", "3"], |s| s.parse::<i32>().ok());
+/// assert_eq!(VecKind::compact(parsed), vec![1, 3]);
+///
+/// let parsed = VecKind::fmap(vec!["1", "x", "3"], |s| match s.parse::<i32>() {
+///     Ok(i) => Either::Right(i),
+///     Err(_) => Either::Left(s),
+/// });
+/// assert_eq!(VecKind::separate(parsed), (vec!["x"], vec![1, 3]));
+/// ```
+///
+/// A `HashMap` keeps each entry's key, and an `Option` is compacted the same way as a `Vec` of at
+/// most one element:
+///
+/// ```rust
+/// # use std::collections::HashMap;
+/// # use rust_hkt::data::Either;
+/// # use rust_hkt::part3::{Compactable, HashMapKind, OptionKind};
+/// let ages = HashMap::from([("Ada", Some(36)), ("Bob", None)]);
+/// assert_eq!(HashMapKind::compact(ages), HashMap::from([("Ada", 36)]));
+///
+/// assert_eq!(OptionKind::compact(Some(Some(1))), Some(1));
+/// assert_eq!(OptionKind::separate(Some(Either::<i32, &str>::Left(1))), (Some(1), None));
+/// ```
+pub trait Compactable: HKT {
+    fn compact<A>(fa: Self::Apply<Option<A>>) -> Self::Apply<A>;
+
+    fn separate<A, B>(fab: Self::Apply<Either<A, B>>) -> (Self::Apply<A>, Self::Apply<B>);
+}
+
+impl Compactable for OptionKind {
+    fn compact<A>(fa: Option<Option<A>>) -> Option<A> {
+        fa.flatten()
+    }
+
+    fn separate<A, B>(fab: Option<Either<A, B>>) -> (Option<A>, Option<B>) {
+        match fab {
+            Some(Either::Left(a)) => (Some(a), None),
+            Some(Either::Right(b)) => (None, Some(b)),
+            None => (None, None),
+        }
+    }
+}
+
+impl Compactable for VecKind {
+    fn compact<A>(fa: Vec<Option<A>>) -> Vec<A> {
+        fa.into_iter().flatten().collect()
+    }
+
+    fn separate<A, B>(fab: Vec<Either<A, B>>) -> (Vec<A>, Vec<B>) {
+        let (mut lefts, mut rights) = (Vec::new(), Vec::new());
+        for ab in fab {
+            match ab {
+                Either::Left(a) => lefts.push(a),
+                Either::Right(b) => rights.push(b),
+            }
+        }
+        (lefts, rights)
+    }
+}
+
+impl<K: Eq + Hash> Compactable for HashMapKind<K> {
+    fn compact<A>(fa: HashMap<K, Option<A>>) -> HashMap<K, A> {
+        fa.into_iter()
+            .filter_map(|(k, a)| a.map(|a| (k, a)))
+            .collect()
+    }
+
+    fn separate<A, B>(fab: HashMap<K, Either<A, B>>) -> (HashMap<K, A>, HashMap<K, B>) {
+        let (mut lefts, mut rights) = (HashMap::new(), HashMap::new());
+        for (k, ab) in fab {
+            match ab {
+                Either::Left(a) => {
+                    lefts.insert(k, a);
+                }
+                Either::Right(b) => {
+                    rights.insert(k, b);
+                }
+            }
+        }
+        (lefts, rights)
+    }
+}
@@ -0,0 +1,29 @@
+use super::HKT;
+
+/// The mirror image of `Functor`: something which *consumes* `A`s, rather than producing them.
+///
+/// To turn a consumer of `A`s into a consumer of `B`s, `contramap` needs a way to get from a `B`
+/// to an `A`, the opposite direction to `fmap`. Checking a user's name is checking a string:
+///
+/// ```rust
+/// # use rust_hkt::data::{Predicate, PredicateKind};
+/// # use rust_hkt::part3::Contravariant;
+/// struct User {
+///     name: String,
+/// }
+///
+/// let non_empty = Predicate::new(|s: String| !s.is_empty());
+/// let has_name = PredicateKind::contramap(non_empty, |user: User| user.name);
+/// assert!(has_name.test(User { name: "Ada".to_string() }));
+/// assert!(!has_name.test(User { name: String::new() }));
+/// ```
+///
+/// The consumers are shared, and one may be called again while it's already running, so `f` has
+/// to be an `Fn` rather than an `FnMut`.
+pub trait Contravariant<'a>: HKT {
+    fn contramap<A, B, F>(fa: Self::Apply<A>, f: F) -> Self::Apply<B>
+    where
+        A: 'a,
+        B: 'a,
+        F: Fn(B) -> A + 'a;
+}
@@ -0,0 +1,56 @@
+use std::convert::Infallible;
+
+use data::Either;
+
+use super::{Divide, Divisible};
+
+/// The contravariant counterpart of choice: a consumer of `C`s which decides, for each `C`,
+/// whether to hand it to the consumer of `A`s or to the consumer of `B`s.
+///
+/// `divide` is how a struct is consumed, `choose` is how an enum is:
+///
+/// ```rust
+/// # use rust_hkt::data::{Either, Encoder, EncoderKind};
+/// # use rust_hkt::part3::Decide;
+/// enum Shape {
+///     Circle(u32),
+///     Square(u32),
+/// }
+///
+/// let circle = Encoder::new(|r: u32| format!("circle r={}", r));
+/// let square = Encoder::new(|side: u32| format!("square {}x{}", side, side));
+/// let shape = EncoderKind::choose(circle, square, |shape| match shape {
+///     Shape::Circle(r) => Either::Left(r),
+///     Shape::Square(side) => Either::Right(side),
+/// });
+/// assert_eq!(shape.encode(Shape::Circle(2)), "circle r=2");
+/// assert_eq!(shape.encode(Shape::Square(3)), "square 3x3");
+/// ```
+pub trait Decide<'a>: Divide<'a> {
+    fn choose<A, B, C, F>(fa: Self::Apply<A>, fb: Self::Apply<B>, f: F) -> Self::Apply<C>
+    where
+        A: 'a,
+        B: 'a,
+        C: 'a,
+        F: Fn(C) -> Either<A, B> + 'a;
+}
+
+/// A `Decide` with a unit: `lose` consumes a type which has no values, so it's never called.
+///
+/// ```rust
+/// # use std::convert::Infallible;
+/// # use rust_hkt::data::{Comparator, ComparatorKind, Either};
+/// # use rust_hkt::part3::{Decidable, Decide};
+/// let never = ComparatorKind::lose(|never: Infallible| never);
+/// let ok_only = ComparatorKind::choose(Comparator::<i32>::of(), never, |r: Result<i32, Infallible>| match r {
+///     Ok(i) => Either::Left(i),
+///     Err(never) => Either::Right(never),
+/// });
+/// assert!(ok_only.compare(Ok(1), Ok(2)).is_lt());
+/// ```
+pub trait Decidable<'a>: Divisible<'a> + Decide<'a> {
+    fn lose<A, F>(f: F) -> Self::Apply<A>
+    where
+        A: 'a,
+        F: Fn(A) -> Infallible + 'a;
+}
@@ -0,0 +1,52 @@
+use super::HKT;
+
+/// Suspends the construction of an `F<A>` until it's run.
+///
+/// A recursive definition like the one below builds its whole structure up front if nothing
+/// holds it back, recursing once per step before anything has been run. `defer` puts off each
+/// step until it's needed, so the recursion happens while running instead, which for `Eval` and
+/// `IO` means it's stack safe:
+///
+/// ```rust
+/// # use rust_hkt::data::{Eval, EvalKind, IOKind};
+/// # use rust_hkt::part3::{Applicative, Defer, FlatMap, Monad};
+/// fn sum_to<F>(n: u64) -> F::Apply<u64>
+/// where
+///     F: Defer<'static> + Monad<'static>,
+/// {
+///     if n == 0 {
+///         F::pure(0)
+///     } else {
+///         F::flat_map(F::defer(move || sum_to::<F>(n - 1)), move |sum| F::pure(sum + n))
+///     }
+/// }
+///
+/// assert_eq!(sum_to::<EvalKind>(100_000).value(), 5_000_050_000);
+/// assert_eq!(sum_to::<IOKind>(100_000).run(), 5_000_050_000);
+/// ```
+///
+/// Nothing is built until it's needed:
+///
+/// ```rust
+/// # use std::cell::Cell;
+/// # use std::rc::Rc;
+/// # use rust_hkt::data::{State, StateKind};
+/// # use rust_hkt::part3::Defer;
+/// let built = Rc::new(Cell::new(false));
+/// let state = StateKind::defer({
+///     let built = built.clone();
+///     move || {
+///         built.set(true);
+///         State::<i32, _>::get()
+///     }
+/// });
+/// assert!(!built.get());
+/// assert_eq!(state.run(1), (1, 1));
+/// assert!(built.get());
+/// ```
+pub trait Defer<'a>: HKT {
+    fn defer<A, F>(f: F) -> Self::Apply<A>
+    where
+        A: 'a,
+        F: FnOnce() -> Self::Apply<A> + 'a;
+}
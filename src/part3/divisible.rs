@@ -0,0 +1,59 @@
+use super::Contravariant;
+
+/// The contravariant counterpart of `Apply`: a consumer of `C`s built from a consumer of `A`s
+/// and a consumer of `B`s, by splitting each `C` in two.
+///
+/// Where `map2` combines two results, `divide` hands each half of its input to one side. That's
+/// how comparators and encoders for structs are built from the ones for their fields:
+///
+/// ```rust
+/// # use rust_hkt::data::{Comparator, ComparatorKind, Encoder, EncoderKind};
+/// # use rust_hkt::part3::Divide;
+/// #[derive(Clone, Debug, PartialEq)]
+/// struct Version {
+///     major: u32,
+///     minor: u32,
+/// }
+///
+/// let by_version = ComparatorKind::divide(
+///     Comparator::<u32>::of(),
+///     Comparator::<u32>::of(),
+///     |v: Version| (v.major, v.minor),
+/// );
+/// let mut versions = vec![Version { major: 1, minor: 2 }, Version { major: 0, minor: 9 }];
+/// versions.sort_by(|a, b| by_version.compare(a.clone(), b.clone()));
+/// assert_eq!(versions, vec![Version { major: 0, minor: 9 }, Version { major: 1, minor: 2 }]);
+///
+/// let dotted = EncoderKind::divide(
+///     Encoder::<u32>::of(),
+///     EncoderKind::divide(Encoder::new(|()| ".".to_string()), Encoder::<u32>::of(), |minor| ((), minor)),
+///     |v: Version| (v.major, v.minor),
+/// );
+/// assert_eq!(dotted.encode(Version { major: 1, minor: 2 }), "1.2");
+/// ```
+pub trait Divide<'a>: Contravariant<'a> {
+    fn divide<A, B, C, F>(fa: Self::Apply<A>, fb: Self::Apply<B>, f: F) -> Self::Apply<C>
+    where
+        A: 'a,
+        B: 'a,
+        C: 'a,
+        F: Fn(C) -> (A, B) + 'a;
+}
+
+/// A `Divide` with a unit: `conquer` consumes anything and contributes nothing, so dividing with
+/// it on either side changes nothing.
+///
+/// ```rust
+/// # use rust_hkt::data::{Predicate, PredicateKind};
+/// # use rust_hkt::part3::{Divide, Divisible};
+/// let all = |predicates: Vec<Predicate<i32>>| {
+///     predicates.into_iter().fold(PredicateKind::conquer(), |acc, p| PredicateKind::divide(acc, p, |i| (i, i)))
+/// };
+/// let small_even = all(vec![Predicate::new(|i| i % 2 == 0), Predicate::new(|i| i < 10)]);
+/// assert!(small_even.test(4));
+/// assert!(!small_even.test(12));
+/// assert!(all(Vec::new()).test(7));
+/// ```
+pub trait Divisible<'a>: Divide<'a> {
+    fn conquer<A: 'a>() -> Self::Apply<A>;
+}
@@ -0,0 +1,163 @@
+use std::collections::{LinkedList, VecDeque};
+use std::ops::ControlFlow;
+
+use super::{
+    Apply, ControlFlowKind, FunctionKind, LinkedListKind, OptionKind, ResultKind, VecDequeKind,
+    VecKind,
+};
+
+/// An `Apply` which can chain a function that produces a new context, `flat_map`.
+///
+/// This is a `Monad` without `pure`, and it's there for the same reason as `Apply`: some types
+/// can sequence computations without being able to produce a value in context from nothing.
+/// A `WriterT` whose log is only a `Semigroup` is one of them:
+///
+/// ```rust
+/// # use rust_hkt::data::{Identity, IdentityKind, NonEmptyVec, Writer, WriterTKind};
+/// # use rust_hkt::part3::FlatMap;
+/// fn step(name: &'static str, i: i32) -> Writer<NonEmptyVec<&'static str>, i32> {
+///     Writer::new(Identity((NonEmptyVec::of(name), i)))
+/// }
+///
+/// let chained = WriterTKind::<_, IdentityKind>::flat_map(step("one", 1), |i| step("two", i + 1));
+/// assert_eq!(chained.run().into_inner(), (NonEmptyVec::new("one", vec!["two"]), 2));
+/// ```
+///
+/// `flat_map` has to be associative, see `laws::flat_map_associativity`.
+pub trait FlatMap<'a>: Apply<'a> {
+    fn flat_map<A, B, F>(fa: Self::Apply<A>, f: F) -> Self::Apply<B>
+    where
+        A: 'a,
+        B: 'a,
+        F: FnMut(A) -> Self::Apply<B> + 'a;
+
+    /// Collapses two layers of context into one.
+    ///
+    /// ```rust
+    /// # use rust_hkt::part3::{FlatMap, OptionKind, VecKind};
+    /// assert_eq!(OptionKind::flatten(Some(Some(1))), Some(1));
+    /// assert_eq!(OptionKind::flatten(Some(None::<i32>)), None);
+    /// assert_eq!(VecKind::flatten(vec![vec![1], vec![], vec![2, 3]]), vec![1, 2, 3]);
+    /// ```
+    fn flatten<A>(ffa: Self::Apply<Self::Apply<A>>) -> Self::Apply<A>
+    where
+        A: 'a,
+        Self::Apply<A>: 'a,
+    {
+        Self::flat_map(ffa, |fa| fa)
+    }
+}
+
+/// A `FlatMap` which calls the function given to `flat_map` at most once, so `flat_map_once` can
+/// take an `FnOnce`.
+///
+/// `Option` and `Result` hold at most one value, and an `IO` or a `State` is only run once, so
+/// they all qualify. `Vec` calls the function for every element, so it doesn't. A transformer like
+/// `StateT` needs this of the monad underneath to sequence two of its computations, since it can
+/// only run the second one once:
+///
+/// ```rust
+/// # use rust_hkt::part3::{FlatMapOnce, OptionKind};
+/// let name = String::from("ferris");
+/// let named = OptionKind::flat_map_once(Some(1), move |i| Some((name, i)));
+/// assert_eq!(named, Some(("ferris".to_string(), 1)));
+/// ```
+pub trait FlatMapOnce<'a>: FlatMap<'a> {
+    fn flat_map_once<A, B, F>(fa: Self::Apply<A>, f: F) -> Self::Apply<B>
+    where
+        A: 'a,
+        B: 'a,
+        F: FnOnce(A) -> Self::Apply<B> + 'a;
+}
+
+impl<'a> FlatMap<'a> for OptionKind {
+    fn flat_map<A, B, F>(fa: Option<A>, f: F) -> Option<B>
+    where
+        F: FnMut(A) -> Option<B>,
+    {
+        fa.and_then(f)
+    }
+}
+
+impl<'a> FlatMap<'a> for VecKind {
+    fn flat_map<A, B, F>(fa: Vec<A>, f: F) -> Vec<B>
+    where
+        F: FnMut(A) -> Vec<B>,
+    {
+        fa.into_iter().flat_map(f).collect()
+    }
+}
+
+impl<'a> FlatMap<'a> for VecDequeKind {
+    fn flat_map<A, B, F>(fa: VecDeque<A>, f: F) -> VecDeque<B>
+    where
+        F: FnMut(A) -> VecDeque<B>,
+    {
+        fa.into_iter().flat_map(f).collect()
+    }
+}
+
+impl<'a> FlatMap<'a> for LinkedListKind {
+    fn flat_map<A, B, F>(fa: LinkedList<A>, f: F) -> LinkedList<B>
+    where
+        F: FnMut(A) -> LinkedList<B>,
+    {
+        fa.into_iter().flat_map(f).collect()
+    }
+}
+
+impl<'a, B> FlatMap<'a> for ControlFlowKind<B> {
+    fn flat_map<C, D, F>(fc: ControlFlow<B, C>, mut f: F) -> ControlFlow<B, D>
+    where
+        F: FnMut(C) -> ControlFlow<B, D>,
+    {
+        f(fc?)
+    }
+}
+
+impl<'a, E> FlatMap<'a> for ResultKind<E> {
+    fn flat_map<A, B, F>(fa: Result<A, E>, f: F) -> Result<B, E>
+    where
+        F: FnMut(A) -> Result<B, E>,
+    {
+        fa.and_then(f)
+    }
+}
+
+impl<R: Clone + 'static> FlatMap<'static> for FunctionKind<R> {
+    fn flat_map<A, B, F>(mut fa: Box<dyn FnMut(R) -> A>, mut f: F) -> Box<dyn FnMut(R) -> B>
+    where
+        A: 'static,
+        B: 'static,
+        F: FnMut(A) -> Box<dyn FnMut(R) -> B> + 'static,
+    {
+        Box::new(move |r: R| f(fa(r.clone()))(r))
+    }
+}
+
+impl<'a> FlatMapOnce<'a> for OptionKind {
+    fn flat_map_once<A, B, F>(fa: Option<A>, f: F) -> Option<B>
+    where
+        F: FnOnce(A) -> Option<B>,
+    {
+        fa.and_then(f)
+    }
+}
+
+impl<'a, B> FlatMapOnce<'a> for ControlFlowKind<B> {
+    fn flat_map_once<C, D, F>(fc: ControlFlow<B, C>, f: F) -> ControlFlow<B, D>
+    where
+        F: FnOnce(C) -> ControlFlow<B, D>,
+    {
+        f(fc?)
+    }
+}
+
+impl<'a, E> FlatMapOnce<'a> for ResultKind<E> {
+    fn flat_map_once<A, B, F>(fa: Result<A, E>, f: F) -> Result<B, E>
+    where
+        F: FnOnce(A) -> Result<B, E>,
+    {
+        fa.and_then(f)
+    }
+}
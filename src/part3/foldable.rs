@@ -0,0 +1,379 @@
+use std::cell::{Cell, RefCell};
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, BTreeSet, LinkedList, VecDeque};
+use std::fmt::Display;
+use std::marker::PhantomData;
+
+use kernel::{Endo, Monoid};
+
+use super::{
+    ArrayKind, BTreeMapKind, BTreeSetKind, BoxKind, LinkedListKind, OptionKind, PhantomDataKind,
+    ResultKind, TupleKind, VecDequeKind, VecKind, HKT,
+};
+
+/// ```rust
+/// # use rust_hkt::part3::{Foldable, OptionKind, VecKind};
+/// assert_eq!(VecKind::fold_left(vec![1, 2, 3], 0, |acc, i| acc + i), 6);
+/// assert_eq!(VecKind::fold_right(vec![1, 2, 3], String::new(), |i, acc| acc + &i.to_string()), "321");
+/// assert_eq!(OptionKind::fold_map(Some(1), |i| vec![i, i]), vec![1, 1]);
+/// assert_eq!(OptionKind::fold_map(None, |i: i32| i.to_string()), "");
+/// assert!(VecKind::forall(vec![1, 2, 3], |i| *i > 0));
+/// assert!(!VecKind::exists(vec![1, 2, 3], |i| *i > 3));
+/// assert!(OptionKind::forall(None, |i: &i32| *i > 0));
+///
+/// let mut checked = 0;
+/// assert!(VecKind::exists(vec![1, 5, 2, 7], |i| { checked += 1; *i > 4 }));
+/// assert_eq!(checked, 2);
+/// assert_eq!(VecKind::mk_string(vec![1, 2, 3], ", ", "[", "]"), "[1, 2, 3]");
+/// assert_eq!(OptionKind::mk_string(None::<i32>, ", ", "[", "]"), "[]");
+/// ```
+///
+/// A `BTreeMap` is folded over its values in key order, so, unlike a `HashMap`, it gives the
+/// same answer every time:
+///
+/// ```rust
+/// # use std::collections::BTreeMap;
+/// # use rust_hkt::part3::{BTreeMapKind, Foldable};
+/// let steps = BTreeMap::from([(2, "world"), (1, "hello")]);
+/// assert_eq!(BTreeMapKind::fold_left(steps, String::new(), |acc, s| acc + s + " "), "hello world ");
+/// ```
+///
+/// The same goes for a `BTreeSet`, which is folded in ascending order. A `HashSet` is only
+/// `UnorderedFoldable`.
+///
+/// ```rust
+/// # use std::collections::BTreeSet;
+/// # use rust_hkt::part3::{BTreeSetKind, Foldable};
+/// let tags = BTreeSet::from(["rust", "fp", "hkt"]);
+/// assert_eq!(BTreeSetKind::fold_map(tags, |tag| format!("#{}", tag)), "#fp#hkt#rust");
+/// ```
+///
+/// Only `fold_left` has to be written, `fold_right` is derived from it through `Endo`. That
+/// boxes a closure per element, so every instance in this crate writes its own anyway:
+///
+/// ```rust
+/// # use rust_hkt::part3::{Foldable, HKT};
+/// struct PairKind;
+///
+/// impl HKT for PairKind {
+///     type Apply<A> = (A, A);
+/// }
+///
+/// impl Foldable for PairKind {
+///     fn fold_left<A, B, F>((x, y): (A, A), init: B, mut f: F) -> B
+///     where
+///         F: FnMut(B, A) -> B,
+///     {
+///         let acc = f(init, x);
+///         f(acc, y)
+///     }
+/// }
+///
+/// assert_eq!(PairKind::fold_right(("a", "b"), String::new(), |s, acc| acc + s), "ba");
+/// ```
+///
+/// `Endo` runs its functions in a loop, so the derived `fold_right` doesn't grow the stack on a
+/// long structure either:
+///
+/// ```rust
+/// # use rust_hkt::part3::{Foldable, HKT};
+/// struct BagKind;
+///
+/// impl HKT for BagKind {
+///     type Apply<A> = Vec<A>;
+/// }
+///
+/// impl Foldable for BagKind {
+///     fn fold_left<A, B, F>(fa: Vec<A>, init: B, f: F) -> B
+///     where
+///         F: FnMut(B, A) -> B,
+///     {
+///         fa.into_iter().fold(init, f)
+///     }
+/// }
+///
+/// let total = BagKind::fold_right((1..=1_000_000).collect(), 0u64, |i, acc| acc + i);
+/// assert_eq!(total, 500_000_500_000);
+/// ```
+pub trait Foldable: HKT {
+    fn fold_left<A, B, F>(fa: Self::Apply<A>, init: B, f: F) -> B
+    where
+        F: FnMut(B, A) -> B;
+
+    fn fold_right<A, B, F>(fa: Self::Apply<A>, init: B, f: F) -> B
+    where
+        F: FnMut(A, B) -> B,
+    {
+        let f = &RefCell::new(f);
+        let endo = Self::fold_map(fa, |a| {
+            let a = Cell::new(Some(a));
+            Endo::new(move |b| {
+                let a = a.take().expect("fold_right runs each element once");
+                (f.borrow_mut())(a, b)
+            })
+        });
+        endo.run(init)
+    }
+
+    fn fold_map<A, M, F>(fa: Self::Apply<A>, mut f: F) -> M
+    where
+        M: Monoid,
+        F: FnMut(A) -> M,
+    {
+        Self::fold_left(fa, M::empty(), |acc, a| acc.combine(f(a)))
+    }
+
+    /// Whether `p` holds for every element, `true` for an empty structure. `p` isn't called again
+    /// once it has failed.
+    fn forall<A, P>(fa: Self::Apply<A>, mut p: P) -> bool
+    where
+        P: FnMut(&A) -> bool,
+    {
+        Self::fold_left(fa, true, |all, a| all && p(&a))
+    }
+
+    /// Whether `p` holds for some element, `false` for an empty structure. `p` isn't called again
+    /// once it has held.
+    fn exists<A, P>(fa: Self::Apply<A>, mut p: P) -> bool
+    where
+        P: FnMut(&A) -> bool,
+    {
+        Self::fold_left(fa, false, |any, a| any || p(&a))
+    }
+
+    /// Displays every element, `sep` between each pair, all between `prefix` and `suffix`.
+    fn mk_string<A>(fa: Self::Apply<A>, sep: &str, prefix: &str, suffix: &str) -> String
+    where
+        A: Display,
+    {
+        let (mut s, _) = Self::fold_left(fa, (prefix.to_string(), true), |(mut s, first), a| {
+            if !first {
+                s.push_str(sep);
+            }
+            s.push_str(&a.to_string());
+            (s, false)
+        });
+        s.push_str(suffix);
+        s
+    }
+}
+
+/// Compares `x` and `y` with each of `comparisons` in turn, the first which doesn't find them
+/// `Equal` deciding. This is `fold_map` into `Ordering`'s `Monoid`.
+///
+/// ```rust
+/// # use std::cmp::Ordering;
+/// # use rust_hkt::part3::{then_compare, VecKind};
+/// fn by_len(x: &&str, y: &&str) -> Ordering {
+///     x.len().cmp(&y.len())
+/// }
+///
+/// fn alphabetically(x: &&str, y: &&str) -> Ordering {
+///     x.cmp(y)
+/// }
+///
+/// let mut words = vec!["bb", "c", "aa"];
+/// words.sort_by(|x, y| then_compare::<VecKind, _, _>(vec![by_len, alphabetically], x, y));
+/// assert_eq!(words, vec!["c", "aa", "bb"]);
+/// ```
+pub fn then_compare<F, A, K>(comparisons: F::Apply<K>, x: &A, y: &A) -> Ordering
+where
+    F: Foldable,
+    K: FnMut(&A, &A) -> Ordering,
+{
+    F::fold_map(comparisons, |mut compare| compare(x, y))
+}
+
+impl Foldable for OptionKind {
+    fn fold_left<A, B, F>(fa: Option<A>, init: B, mut f: F) -> B
+    where
+        F: FnMut(B, A) -> B,
+    {
+        match fa {
+            Some(a) => f(init, a),
+            None => init,
+        }
+    }
+
+    fn fold_right<A, B, F>(fa: Option<A>, init: B, mut f: F) -> B
+    where
+        F: FnMut(A, B) -> B,
+    {
+        match fa {
+            Some(a) => f(a, init),
+            None => init,
+        }
+    }
+}
+
+impl Foldable for PhantomDataKind {
+    fn fold_left<A, B, F>(_: PhantomData<A>, init: B, _: F) -> B
+    where
+        F: FnMut(B, A) -> B,
+    {
+        init
+    }
+
+    fn fold_right<A, B, F>(_: PhantomData<A>, init: B, _: F) -> B
+    where
+        F: FnMut(A, B) -> B,
+    {
+        init
+    }
+}
+
+impl Foldable for BoxKind {
+    fn fold_left<A, B, F>(fa: Box<A>, init: B, mut f: F) -> B
+    where
+        F: FnMut(B, A) -> B,
+    {
+        f(init, *fa)
+    }
+
+    fn fold_right<A, B, F>(fa: Box<A>, init: B, mut f: F) -> B
+    where
+        F: FnMut(A, B) -> B,
+    {
+        f(*fa, init)
+    }
+}
+
+impl<const N: usize> Foldable for ArrayKind<N> {
+    fn fold_left<A, B, F>(fa: [A; N], init: B, f: F) -> B
+    where
+        F: FnMut(B, A) -> B,
+    {
+        IntoIterator::into_iter(fa).fold(init, f)
+    }
+
+    fn fold_right<A, B, F>(fa: [A; N], init: B, mut f: F) -> B
+    where
+        F: FnMut(A, B) -> B,
+    {
+        IntoIterator::into_iter(fa)
+            .rev()
+            .fold(init, |acc, a| f(a, acc))
+    }
+}
+
+impl Foldable for VecKind {
+    fn fold_left<A, B, F>(fa: Vec<A>, init: B, f: F) -> B
+    where
+        F: FnMut(B, A) -> B,
+    {
+        fa.into_iter().fold(init, f)
+    }
+
+    fn fold_right<A, B, F>(fa: Vec<A>, init: B, mut f: F) -> B
+    where
+        F: FnMut(A, B) -> B,
+    {
+        fa.into_iter().rev().fold(init, |acc, a| f(a, acc))
+    }
+}
+
+/// Folds from front to back.
+impl Foldable for VecDequeKind {
+    fn fold_left<A, B, F>(fa: VecDeque<A>, init: B, f: F) -> B
+    where
+        F: FnMut(B, A) -> B,
+    {
+        fa.into_iter().fold(init, f)
+    }
+
+    fn fold_right<A, B, F>(fa: VecDeque<A>, init: B, mut f: F) -> B
+    where
+        F: FnMut(A, B) -> B,
+    {
+        fa.into_iter().rev().fold(init, |acc, a| f(a, acc))
+    }
+}
+
+/// Folds from front to back.
+impl Foldable for LinkedListKind {
+    fn fold_left<A, B, F>(fa: LinkedList<A>, init: B, f: F) -> B
+    where
+        F: FnMut(B, A) -> B,
+    {
+        fa.into_iter().fold(init, f)
+    }
+
+    fn fold_right<A, B, F>(fa: LinkedList<A>, init: B, mut f: F) -> B
+    where
+        F: FnMut(A, B) -> B,
+    {
+        fa.into_iter().rev().fold(init, |acc, a| f(a, acc))
+    }
+}
+
+/// Folds in ascending order.
+impl Foldable for BTreeSetKind {
+    fn fold_left<A, B, F>(fa: BTreeSet<A>, init: B, f: F) -> B
+    where
+        F: FnMut(B, A) -> B,
+    {
+        fa.into_iter().fold(init, f)
+    }
+
+    fn fold_right<A, B, F>(fa: BTreeSet<A>, init: B, mut f: F) -> B
+    where
+        F: FnMut(A, B) -> B,
+    {
+        fa.into_iter().rev().fold(init, |acc, a| f(a, acc))
+    }
+}
+
+impl<E> Foldable for ResultKind<E> {
+    fn fold_left<A, B, F>(fa: Result<A, E>, init: B, mut f: F) -> B
+    where
+        F: FnMut(B, A) -> B,
+    {
+        match fa {
+            Ok(a) => f(init, a),
+            Err(_) => init,
+        }
+    }
+
+    fn fold_right<A, B, F>(fa: Result<A, E>, init: B, mut f: F) -> B
+    where
+        F: FnMut(A, B) -> B,
+    {
+        match fa {
+            Ok(a) => f(a, init),
+            Err(_) => init,
+        }
+    }
+}
+
+/// Folds over the values in key order.
+impl<K: Ord> Foldable for BTreeMapKind<K> {
+    fn fold_left<A, B, F>(fa: BTreeMap<K, A>, init: B, mut f: F) -> B
+    where
+        F: FnMut(B, A) -> B,
+    {
+        fa.into_iter().fold(init, |acc, (_, a)| f(acc, a))
+    }
+
+    fn fold_right<A, B, F>(fa: BTreeMap<K, A>, init: B, mut f: F) -> B
+    where
+        F: FnMut(A, B) -> B,
+    {
+        fa.into_iter().rev().fold(init, |acc, (_, a)| f(a, acc))
+    }
+}
+
+impl<W> Foldable for TupleKind<W> {
+    fn fold_left<A, B, F>(fa: (W, A), init: B, mut f: F) -> B
+    where
+        F: FnMut(B, A) -> B,
+    {
+        f(init, fa.1)
+    }
+
+    fn fold_right<A, B, F>(fa: (W, A), init: B, mut f: F) -> B
+    where
+        F: FnMut(A, B) -> B,
+    {
+        f(fa.1, init)
+    }
+}
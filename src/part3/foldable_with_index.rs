@@ -0,0 +1,88 @@
+use std::collections::{BTreeMap, HashMap};
+use std::hash::Hash;
+
+use kernel::Monoid;
+
+use super::{BTreeMapKind, HashMapKind, VecKind, HKT};
+
+/// A fold which is also told each element's index: its position in a `Vec`, its key in a map.
+///
+/// ```rust
+/// # use std::collections::BTreeMap;
+/// # use rust_hkt::part3::{BTreeMapKind, FoldableWithIndex, VecKind};
+/// let positions = VecKind::fold_left_with_index(vec!['a', 'b', 'a'], Vec::new(), |mut acc, i, c| {
+///     if c == 'a' {
+///         acc.push(i);
+///     }
+///     acc
+/// });
+/// assert_eq!(positions, vec![0, 2]);
+///
+/// let stock = BTreeMap::from([("apples", 3), ("pears", 0)]);
+/// let report = BTreeMapKind::fold_map_with_index(stock, |fruit, n| format!("{}: {}; ", fruit, n));
+/// assert_eq!(report, "apples: 3; pears: 0; ");
+/// ```
+///
+/// A `HashMap` has no order of its own, so it's folded in whatever order it iterates in. That's
+/// fine for a commutative fold, like summing, but not for building a string like the one above.
+///
+/// ```rust
+/// # use std::collections::HashMap;
+/// # use std::num::Wrapping;
+/// # use rust_hkt::part3::{FoldableWithIndex, HashMapKind};
+/// let prices = HashMap::from([("apples", 2), ("pears", 3)]);
+/// let quantities = HashMap::from([("apples", 5), ("pears", 1)]);
+/// let total = HashMapKind::fold_map_with_index(prices, |fruit, price| Wrapping(price * quantities[fruit]));
+/// assert_eq!(total, Wrapping(13));
+/// ```
+pub trait FoldableWithIndex: HKT {
+    type Index;
+
+    fn fold_left_with_index<A, B, F>(fa: Self::Apply<A>, init: B, f: F) -> B
+    where
+        F: FnMut(B, Self::Index, A) -> B;
+
+    fn fold_map_with_index<A, M, F>(fa: Self::Apply<A>, mut f: F) -> M
+    where
+        M: Monoid,
+        F: FnMut(Self::Index, A) -> M,
+    {
+        Self::fold_left_with_index(fa, M::empty(), |acc, i, a| acc.combine(f(i, a)))
+    }
+}
+
+impl FoldableWithIndex for VecKind {
+    type Index = usize;
+
+    fn fold_left_with_index<A, B, F>(fa: Vec<A>, init: B, mut f: F) -> B
+    where
+        F: FnMut(B, usize, A) -> B,
+    {
+        fa.into_iter()
+            .enumerate()
+            .fold(init, |acc, (i, a)| f(acc, i, a))
+    }
+}
+
+impl<K: Eq + Hash> FoldableWithIndex for HashMapKind<K> {
+    type Index = K;
+
+    fn fold_left_with_index<A, B, F>(fa: HashMap<K, A>, init: B, mut f: F) -> B
+    where
+        F: FnMut(B, K, A) -> B,
+    {
+        fa.into_iter().fold(init, |acc, (k, a)| f(acc, k, a))
+    }
+}
+
+/// Folds in key order.
+impl<K: Ord> FoldableWithIndex for BTreeMapKind<K> {
+    type Index = K;
+
+    fn fold_left_with_index<A, B, F>(fa: BTreeMap<K, A>, init: B, mut f: F) -> B
+    where
+        F: FnMut(B, K, A) -> B,
+    {
+        fa.into_iter().fold(init, |acc, (k, a)| f(acc, k, a))
+    }
+}
@@ -0,0 +1,66 @@
+use std::marker::PhantomData;
+
+use super::HKT;
+
+/// A natural transformation, `F ~> G`: a way of turning any `F<A>` into a `G<A>` without knowing
+/// anything about the `A`.
+///
+/// Rust has no polymorphic closures, so a transformation is a type which implements this trait,
+/// named where it's used, just like a kind. That goes for the combinators too: `IdentityK` does
+/// nothing, and `AndThen<N, M, G>` runs `N: F ~> G` and then `M: G ~> H`.
+///
+/// ```rust
+/// # use rust_hkt::part3::{AndThen, FunctionK, IdentityK, OptionKind, VecKind};
+/// struct OptionToVec;
+///
+/// impl<'a> FunctionK<'a, OptionKind, VecKind> for OptionToVec {
+///     fn apply<A>(fa: Option<A>) -> Vec<A> {
+///         fa.into_iter().collect()
+///     }
+/// }
+///
+/// struct Head;
+///
+/// impl<'a> FunctionK<'a, VecKind, OptionKind> for Head {
+///     fn apply<A>(fa: Vec<A>) -> Option<A> {
+///         fa.into_iter().next()
+///     }
+/// }
+///
+/// assert_eq!(OptionToVec::apply(Some(1)), vec![1]);
+/// assert_eq!(OptionToVec::apply(None::<&str>), Vec::<&str>::new());
+///
+/// assert_eq!(<IdentityK as FunctionK<VecKind, VecKind>>::apply(vec![1, 2]), vec![1, 2]);
+///
+/// type RoundTrip = AndThen<OptionToVec, Head, VecKind>;
+/// assert_eq!(RoundTrip::apply(Some("a")), Some("a"));
+/// assert_eq!(<AndThen<Head, OptionToVec, OptionKind>>::apply(vec![1, 2]), vec![1]);
+/// ```
+pub trait FunctionK<'a, F: HKT, G: HKT> {
+    fn apply<A: 'a>(fa: F::Apply<A>) -> G::Apply<A>;
+}
+
+/// The transformation `F ~> F` which leaves everything as it is.
+pub struct IdentityK;
+
+impl<'a, F: HKT> FunctionK<'a, F, F> for IdentityK {
+    fn apply<A: 'a>(fa: F::Apply<A>) -> F::Apply<A> {
+        fa
+    }
+}
+
+/// `N` followed by `M`, going through `G` in the middle.
+pub struct AndThen<N, M, G>(PhantomData<(N, M, G)>);
+
+impl<'a, F, G, H, N, M> FunctionK<'a, F, H> for AndThen<N, M, G>
+where
+    F: HKT,
+    G: HKT,
+    H: HKT,
+    N: FunctionK<'a, F, G>,
+    M: FunctionK<'a, G, H>,
+{
+    fn apply<A: 'a>(fa: F::Apply<A>) -> H::Apply<A> {
+        M::apply(N::apply(fa))
+    }
+}
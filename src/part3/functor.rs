@@ -0,0 +1,405 @@
+use std::collections::{BTreeMap, HashMap, LinkedList, VecDeque};
+use std::hash::Hash;
+use std::marker::PhantomData;
+use std::mem::{self, ManuallyDrop, MaybeUninit};
+use std::ops::ControlFlow;
+use std::ptr;
+use std::rc::Rc;
+use std::sync::Arc;
+use std::task::Poll;
+
+use super::{
+    ArcKind, ArrayKind, BTreeMapKind, BoxKind, ControlFlowKind, FunctionKind, HashMapKind,
+    LinkedListKind, OptionKind, PhantomDataKind, PollKind, PollResultKind, RcKind, ResultKind,
+    TupleKind, VecDequeKind, VecKind, HKT,
+};
+
+/// ```rust
+/// # use rust_hkt::part3::{Functor, OptionKind, ResultKind, VecKind};
+/// assert_eq!(OptionKind::fmap(Some(1), |i| i * 2), Some(2));
+/// assert_eq!(VecKind::fmap(vec![1, 2], |i| i * 2), vec![2, 4]);
+/// assert_eq!(ResultKind::<()>::fmap(Ok(1), |i| i * 2), Ok(2));
+/// ```
+///
+/// As in part 2, the function is `FnMut`, so it can capture mutable state:
+///
+/// ```rust
+/// # use rust_hkt::part3::{Functor, OptionKind, VecKind};
+/// let mut seen = Vec::new();
+/// let lengths = VecKind::fmap(vec!["a", "bc"], |s| {
+///     seen.push(s);
+///     s.len()
+/// });
+/// assert_eq!(lengths, vec![1, 2]);
+/// assert_eq!(seen, vec!["a", "bc"]);
+///
+/// let mut total = 0;
+/// assert_eq!(OptionKind::fmap(Some(2), |i| { total += i; i }), Some(2));
+/// assert_eq!(total, 2);
+/// ```
+pub trait Functor<'a>: HKT {
+    fn fmap<A, B, F>(fa: Self::Apply<A>, f: F) -> Self::Apply<B>
+    where
+        A: 'a,
+        B: 'a,
+        F: FnMut(A) -> B + 'a;
+}
+
+impl<'a> Functor<'a> for OptionKind {
+    fn fmap<A, B, F>(fa: Option<A>, f: F) -> Option<B>
+    where
+        F: FnMut(A) -> B,
+    {
+        fa.map(f)
+    }
+}
+
+/// `fmap` consumes the `Vec`, so when `B` has the same size and alignment as `A`, each result is
+/// written back over the element it came from, and the `Vec` keeps its allocation:
+///
+/// ```rust
+/// # use rust_hkt::part3::{Functor, VecKind};
+/// let xs: Vec<u32> = (0..1000).collect();
+/// let buffer = xs.as_ptr() as usize;
+/// let ys = VecKind::fmap(xs, |x| x as i32 - 500);
+/// assert_eq!(ys[0], -500);
+/// assert_eq!(ys.as_ptr() as usize, buffer);
+/// ```
+///
+/// If `f` panics partway, the results so far and the elements not yet mapped are each dropped
+/// once, and the allocation is freed:
+///
+/// ```rust
+/// # use std::panic;
+/// # use std::rc::Rc;
+/// # use rust_hkt::part3::{Functor, VecKind};
+/// let counted = Rc::new(());
+/// let xs = vec![counted.clone(), counted.clone(), counted.clone()];
+/// # panic::set_hook(Box::new(|_| {}));
+/// let panicked = panic::catch_unwind(panic::AssertUnwindSafe(move || {
+///     let mut seen = 0;
+///     VecKind::fmap(xs, |rc| {
+///         seen += 1;
+///         if seen == 2 {
+///             panic!("second element");
+///         }
+///         Some(rc)
+///     })
+/// }));
+/// assert!(panicked.is_err());
+/// assert_eq!(Rc::strong_count(&counted), 1);
+/// ```
+///
+/// See `benches/vec_fmap.rs` for the allocations it saves.
+impl<'a> Functor<'a> for VecKind {
+    fn fmap<A, B, F>(fa: Vec<A>, f: F) -> Vec<B>
+    where
+        F: FnMut(A) -> B,
+    {
+        if same_layout::<A, B>() && mem::size_of::<A>() != 0 {
+            map_in_place(fa, f)
+        } else {
+            fa.into_iter().map(f).collect()
+        }
+    }
+}
+
+/// Maps each element of `fa` and writes the result over it. `A` and `B` must have the same size
+/// and alignment.
+fn map_in_place<A, B, F>(fa: Vec<A>, mut f: F) -> Vec<B>
+where
+    F: FnMut(A) -> B,
+{
+    let mut fa = ManuallyDrop::new(fa);
+    let mut slots = InPlace::<A, B> {
+        ptr: fa.as_mut_ptr(),
+        len: fa.len(),
+        cap: fa.capacity(),
+        mapped: 0,
+        marker: PhantomData,
+    };
+    while slots.mapped < slots.len {
+        // The slot is moved out of before `f` runs, and `mapped` only covers it once it's been
+        // written back, so if `f` panics the guard skips it.
+        unsafe {
+            let slot = slots.ptr.add(slots.mapped);
+            let b = f(ptr::read(slot));
+            ptr::write(slot as *mut B, b);
+        }
+        slots.mapped += 1;
+    }
+    let slots = ManuallyDrop::new(slots);
+    // Every slot holds a `B` now, and the allocation's layout is the same for `B` as for `A`.
+    unsafe { Vec::from_raw_parts(slots.ptr as *mut B, slots.len, slots.cap) }
+}
+
+/// A `Vec<A>`'s buffer partway through being mapped into `B`s: the first `mapped` slots hold
+/// `B`s, the slot after them has been moved out of, and the rest still hold `A`s. Dropping it
+/// drops whichever each slot holds, and frees the buffer.
+struct InPlace<A, B> {
+    ptr: *mut A,
+    len: usize,
+    cap: usize,
+    mapped: usize,
+    marker: PhantomData<B>,
+}
+
+impl<A, B> Drop for InPlace<A, B> {
+    fn drop(&mut self) {
+        unsafe {
+            ptr::drop_in_place(ptr::slice_from_raw_parts_mut(
+                self.ptr as *mut B,
+                self.mapped,
+            ));
+            let rest = self.ptr.add(self.mapped + 1);
+            let unmapped = self.len.saturating_sub(self.mapped + 1);
+            ptr::drop_in_place(ptr::slice_from_raw_parts_mut(rest, unmapped));
+            drop(Vec::from_raw_parts(self.ptr, 0, self.cap));
+        }
+    }
+}
+
+/// Whether a `B` fits exactly where an `A` was.
+fn same_layout<A, B>() -> bool {
+    mem::size_of::<A>() == mem::size_of::<B>() && mem::align_of::<A>() == mem::align_of::<B>()
+}
+
+/// Like a `Vec`, a `Box` keeps its allocation when `B` has the same size and alignment as `A`:
+///
+/// ```rust
+/// # use rust_hkt::part3::{BoxKind, Functor};
+/// let boxed = Box::new(41u64);
+/// let slot = &*boxed as *const u64 as usize;
+/// let mapped = BoxKind::fmap(boxed, |i| i as i64 + 1);
+/// assert_eq!(*mapped, 42);
+/// assert_eq!(&*mapped as *const i64 as usize, slot);
+/// ```
+impl<'a> Functor<'a> for BoxKind {
+    fn fmap<A, B, F>(fa: Box<A>, mut f: F) -> Box<B>
+    where
+        F: FnMut(A) -> B,
+    {
+        if same_layout::<A, B>() {
+            // `Box::into_raw` gives up the allocation, and it's laid out the same for `B`.
+            unsafe { Box::from_raw(map_slot(Box::into_raw(fa), f, free_box::<A>)) }
+        } else {
+            Box::new(f(*fa))
+        }
+    }
+}
+
+impl RcKind {
+    /// Maps the value in an `Rc`, moving it out if this is the only reference and cloning it
+    /// otherwise. When it is moved out and `B` has the same size and alignment as `A`, the
+    /// result is written back into the same allocation:
+    ///
+    /// ```rust
+    /// # use std::rc::Rc;
+    /// # use rust_hkt::part3::RcKind;
+    /// let shared = Rc::new(vec![1, 2, 3]);
+    /// let lengths = RcKind::fmap_owned(shared.clone(), |xs| xs.len());
+    /// assert_eq!((*lengths, shared.len()), (3, 3));
+    ///
+    /// let unique = Rc::new(vec![1, 2, 3]);
+    /// let (slot, buffer) = (Rc::as_ptr(&unique) as usize, unique.as_ptr());
+    /// let reversed = RcKind::fmap_owned(unique, |mut xs| {
+    ///     xs.reverse();
+    ///     xs
+    /// });
+    /// assert_eq!(*reversed, vec![3, 2, 1]);
+    /// assert_eq!((Rc::as_ptr(&reversed) as usize, reversed.as_ptr()), (slot, buffer));
+    /// ```
+    pub fn fmap_owned<A, B, F>(mut fa: Rc<A>, f: F) -> Rc<B>
+    where
+        A: Clone,
+        F: FnOnce(A) -> B,
+    {
+        if same_layout::<A, B>() && Rc::get_mut(&mut fa).is_some() {
+            // With no other references, the value can be moved out of the allocation, and
+            // `Rc::from_raw` takes it back for a `B` of the same size and alignment.
+            unsafe { Rc::from_raw(map_slot(Rc::into_raw(fa) as *mut A, f, free_rc::<A>)) }
+        } else {
+            Rc::new(f(Rc::unwrap_or_clone(fa)))
+        }
+    }
+}
+
+impl ArcKind {
+    /// The same as `RcKind::fmap_owned`, for an `Arc`.
+    ///
+    /// ```rust
+    /// # use std::sync::Arc;
+    /// # use rust_hkt::part3::ArcKind;
+    /// let unique = Arc::new(String::from("hkt"));
+    /// let slot = Arc::as_ptr(&unique) as usize;
+    /// let shouted = ArcKind::fmap_owned(unique, |s| s.to_uppercase());
+    /// assert_eq!(*shouted, "HKT");
+    /// assert_eq!(Arc::as_ptr(&shouted) as usize, slot);
+    /// ```
+    pub fn fmap_owned<A, B, F>(mut fa: Arc<A>, f: F) -> Arc<B>
+    where
+        A: Clone,
+        F: FnOnce(A) -> B,
+    {
+        if same_layout::<A, B>() && Arc::get_mut(&mut fa).is_some() {
+            unsafe { Arc::from_raw(map_slot(Arc::into_raw(fa) as *mut A, f, free_arc::<A>)) }
+        } else {
+            Arc::new(f(Arc::unwrap_or_clone(fa)))
+        }
+    }
+}
+
+/// Moves the value out of `slot`, maps it with `f` and writes the result back in its place. `B`
+/// has to fit where an `A` was, and if `f` panics, the empty slot is given to `free`.
+unsafe fn map_slot<A, B, F>(slot: *mut A, f: F, free: unsafe fn(*mut A)) -> *mut B
+where
+    F: FnOnce(A) -> B,
+{
+    let empty = EmptySlot { slot, free };
+    let b = f(ptr::read(slot));
+    mem::forget(empty);
+    let slot = slot as *mut B;
+    ptr::write(slot, b);
+    slot
+}
+
+/// A slot whose value has been moved out, which is freed without dropping anything if it's
+/// dropped before a new value is written in.
+struct EmptySlot<A> {
+    slot: *mut A,
+    free: unsafe fn(*mut A),
+}
+
+impl<A> Drop for EmptySlot<A> {
+    fn drop(&mut self) {
+        unsafe { (self.free)(self.slot) }
+    }
+}
+
+unsafe fn free_box<A>(slot: *mut A) {
+    drop(Box::from_raw(slot as *mut MaybeUninit<A>));
+}
+
+unsafe fn free_rc<A>(slot: *mut A) {
+    drop(Rc::from_raw(slot as *const MaybeUninit<A>));
+}
+
+unsafe fn free_arc<A>(slot: *mut A) {
+    drop(Arc::from_raw(slot as *const MaybeUninit<A>));
+}
+
+impl<'a, E> Functor<'a> for ResultKind<E> {
+    fn fmap<A, B, F>(fa: Result<A, E>, f: F) -> Result<B, E>
+    where
+        F: FnMut(A) -> B,
+    {
+        fa.map(f)
+    }
+}
+
+impl<'a, B> Functor<'a> for ControlFlowKind<B> {
+    fn fmap<C, D, F>(fc: ControlFlow<B, C>, mut f: F) -> ControlFlow<B, D>
+    where
+        F: FnMut(C) -> D,
+    {
+        match fc {
+            ControlFlow::Continue(c) => ControlFlow::Continue(f(c)),
+            ControlFlow::Break(b) => ControlFlow::Break(b),
+        }
+    }
+}
+
+impl<'a> Functor<'a> for PhantomDataKind {
+    fn fmap<A, B, F>(_: PhantomData<A>, _: F) -> PhantomData<B>
+    where
+        F: FnMut(A) -> B,
+    {
+        PhantomData
+    }
+}
+
+impl<'a> Functor<'a> for PollKind {
+    fn fmap<A, B, F>(fa: Poll<A>, f: F) -> Poll<B>
+    where
+        F: FnMut(A) -> B,
+    {
+        fa.map(f)
+    }
+}
+
+impl<'a, E> Functor<'a> for PollResultKind<E> {
+    fn fmap<A, B, F>(fa: Poll<Result<A, E>>, f: F) -> Poll<Result<B, E>>
+    where
+        F: FnMut(A) -> B,
+    {
+        fa.map_ok(f)
+    }
+}
+
+impl<'a, const N: usize> Functor<'a> for ArrayKind<N> {
+    fn fmap<A, B, F>(fa: [A; N], f: F) -> [B; N]
+    where
+        F: FnMut(A) -> B,
+    {
+        fa.map(f)
+    }
+}
+
+impl<'a, K> Functor<'a> for HashMapKind<K>
+where
+    K: Eq + Hash,
+{
+    fn fmap<A, B, F>(fa: HashMap<K, A>, mut f: F) -> HashMap<K, B>
+    where
+        F: FnMut(A) -> B,
+    {
+        fa.into_iter().map(|(k, a)| (k, f(a))).collect()
+    }
+}
+
+impl<'a, K: Ord> Functor<'a> for BTreeMapKind<K> {
+    fn fmap<A, B, F>(fa: BTreeMap<K, A>, mut f: F) -> BTreeMap<K, B>
+    where
+        F: FnMut(A) -> B,
+    {
+        fa.into_iter().map(|(k, a)| (k, f(a))).collect()
+    }
+}
+
+impl<'a> Functor<'a> for VecDequeKind {
+    fn fmap<A, B, F>(fa: VecDeque<A>, f: F) -> VecDeque<B>
+    where
+        F: FnMut(A) -> B,
+    {
+        fa.into_iter().map(f).collect()
+    }
+}
+
+impl<'a> Functor<'a> for LinkedListKind {
+    fn fmap<A, B, F>(fa: LinkedList<A>, f: F) -> LinkedList<B>
+    where
+        F: FnMut(A) -> B,
+    {
+        fa.into_iter().map(f).collect()
+    }
+}
+
+impl<R: 'static> Functor<'static> for FunctionKind<R> {
+    fn fmap<A, B, F>(mut fa: Box<dyn FnMut(R) -> A>, mut f: F) -> Box<dyn FnMut(R) -> B>
+    where
+        A: 'static,
+        B: 'static,
+        F: FnMut(A) -> B + 'static,
+    {
+        Box::new(move |r| f(fa(r)))
+    }
+}
+
+impl<'a, W> Functor<'a> for TupleKind<W> {
+    fn fmap<A, B, F>(fa: (W, A), mut f: F) -> (W, B)
+    where
+        F: FnMut(A) -> B,
+    {
+        (fa.0, f(fa.1))
+    }
+}
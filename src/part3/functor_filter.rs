@@ -0,0 +1,87 @@
+use std::collections::{HashMap, LinkedList};
+use std::hash::Hash;
+
+use super::{Functor, HashMapKind, LinkedListKind, OptionKind, VecKind};
+
+/// A `Functor` which can also drop elements: `map_filter` keeps the `Some`s that `f` returns.
+///
+/// ```rust
+/// # use std::collections::HashMap;
+/// # use rust_hkt::part3::{FunctorFilter, HashMapKind, OptionKind, VecKind};
+/// assert_eq!(VecKind::map_filter(vec!["1", "x", "3"], |s| s.parse::<i32>().ok()), vec![1, 3]);
+/// assert_eq!(VecKind::filter(vec![1, 2, 3, 4], |i| i % 2 == 0), vec![2, 4]);
+/// assert_eq!(OptionKind::filter_not(Some(1), |i| *i > 0), None);
+///
+/// let stock = HashMap::from([("apples", 3), ("pears", 0)]);
+/// let in_stock = HashMapKind::map_filter(stock, |n| if n > 0 { Some(n * 10) } else { None });
+/// assert_eq!(in_stock, HashMap::from([("apples", 30)]));
+/// ```
+///
+/// A `HashMap` keeps the key of every entry it keeps, just like `fmap`. Its values can't be
+/// folded in order, see `UnorderedFoldable`.
+pub trait FunctorFilter<'a>: Functor<'a> {
+    fn map_filter<A, B, F>(fa: Self::Apply<A>, f: F) -> Self::Apply<B>
+    where
+        A: 'a,
+        B: 'a,
+        F: FnMut(A) -> Option<B> + 'a;
+
+    /// Keeps the elements which satisfy `p`.
+    fn filter<A, P>(fa: Self::Apply<A>, mut p: P) -> Self::Apply<A>
+    where
+        A: 'a,
+        P: FnMut(&A) -> bool + 'a,
+    {
+        Self::map_filter(fa, move |a| if p(&a) { Some(a) } else { None })
+    }
+
+    /// Drops the elements which satisfy `p`.
+    fn filter_not<A, P>(fa: Self::Apply<A>, mut p: P) -> Self::Apply<A>
+    where
+        A: 'a,
+        P: FnMut(&A) -> bool + 'a,
+    {
+        Self::filter(fa, move |a| !p(a))
+    }
+}
+
+impl<'a> FunctorFilter<'a> for OptionKind {
+    fn map_filter<A, B, F>(fa: Option<A>, f: F) -> Option<B>
+    where
+        F: FnMut(A) -> Option<B>,
+    {
+        fa.and_then(f)
+    }
+}
+
+impl<'a> FunctorFilter<'a> for VecKind {
+    fn map_filter<A, B, F>(fa: Vec<A>, f: F) -> Vec<B>
+    where
+        F: FnMut(A) -> Option<B>,
+    {
+        fa.into_iter().filter_map(f).collect()
+    }
+}
+
+impl<'a> FunctorFilter<'a> for LinkedListKind {
+    fn map_filter<A, B, F>(fa: LinkedList<A>, f: F) -> LinkedList<B>
+    where
+        F: FnMut(A) -> Option<B>,
+    {
+        fa.into_iter().filter_map(f).collect()
+    }
+}
+
+impl<'a, K> FunctorFilter<'a> for HashMapKind<K>
+where
+    K: Eq + Hash,
+{
+    fn map_filter<A, B, F>(fa: HashMap<K, A>, mut f: F) -> HashMap<K, B>
+    where
+        F: FnMut(A) -> Option<B>,
+    {
+        fa.into_iter()
+            .filter_map(|(k, a)| f(a).map(|b| (k, b)))
+            .collect()
+    }
+}
@@ -0,0 +1,65 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use super::{Functor, HashMapKind, VecKind};
+
+/// A `Functor` whose elements each have an index: a position in a `Vec`, a key in a `HashMap`.
+///
+/// `map_with_index` hands the index to `f` alongside the element, so generic code can tell where
+/// it is without zipping in the positions itself:
+///
+/// ```rust
+/// # use std::collections::HashMap;
+/// # use rust_hkt::part3::{FunctorWithIndex, HashMapKind, VecKind};
+/// fn label<'a, F>(fa: F::Apply<i32>) -> F::Apply<String>
+/// where
+///     F: FunctorWithIndex<'a>,
+///     F::Index: std::fmt::Debug,
+/// {
+///     F::map_with_index(fa, |i, a| format!("{:?}: {}", i, a))
+/// }
+///
+/// assert_eq!(label::<VecKind>(vec![10, 20]), vec!["0: 10", "1: 20"]);
+///
+/// let labelled = label::<HashMapKind<&str>>(HashMap::from([("a", 1)]));
+/// assert_eq!(labelled, HashMap::from([("a", "\"a\": 1".to_string())]));
+/// ```
+pub trait FunctorWithIndex<'a>: Functor<'a> {
+    type Index;
+
+    fn map_with_index<A, B, F>(fa: Self::Apply<A>, f: F) -> Self::Apply<B>
+    where
+        A: 'a,
+        B: 'a,
+        F: FnMut(Self::Index, A) -> B + 'a;
+}
+
+impl<'a> FunctorWithIndex<'a> for VecKind {
+    type Index = usize;
+
+    fn map_with_index<A, B, F>(fa: Vec<A>, mut f: F) -> Vec<B>
+    where
+        F: FnMut(usize, A) -> B,
+    {
+        fa.into_iter().enumerate().map(|(i, a)| f(i, a)).collect()
+    }
+}
+
+impl<'a, K> FunctorWithIndex<'a> for HashMapKind<K>
+where
+    K: Eq + Hash + Clone,
+{
+    type Index = K;
+
+    fn map_with_index<A, B, F>(fa: HashMap<K, A>, mut f: F) -> HashMap<K, B>
+    where
+        F: FnMut(K, A) -> B,
+    {
+        fa.into_iter()
+            .map(|(k, a)| {
+                let b = f(k.clone(), a);
+                (k, b)
+            })
+            .collect()
+    }
+}
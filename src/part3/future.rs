@@ -0,0 +1,395 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::task::Poll;
+
+use futures::future::{self, FutureExt, LocalBoxFuture};
+use futures::stream::{self, StreamExt};
+
+use data::Either;
+
+use super::{Applicative, Apply, FlatMap, FlatMapOnce, Functor, Monad, Semigroupal, Traverse, HKT};
+
+/// The kind of futures, behind the `futures` feature.
+///
+/// `Future` is a trait rather than a type, so the kind has to pick one: a boxed future, which
+/// every future can be turned into with `boxed_local`. A future holds on to the closures it's
+/// built from until it runs, so like `EvalKind`, `FutureKind` only implements the typeclasses
+/// for `'static`.
+///
+/// ```rust
+/// # extern crate futures;
+/// # extern crate rust_hkt;
+/// # use futures::executor::block_on;
+/// # use futures::future::{self, FutureExt};
+/// # use rust_hkt::part3::{Applicative, Apply, FlatMap, Functor, FutureKind};
+/// # fn main() {
+/// let length = FutureKind::fmap(future::ready("hello").boxed_local(), str::len);
+/// assert_eq!(block_on(length), 5);
+///
+/// let sum = FutureKind::map2(FutureKind::pure(1), future::lazy(|_| 2).boxed_local(), |a, b| a + b);
+/// assert_eq!(block_on(sum), 3);
+///
+/// let chained = FutureKind::flat_map(FutureKind::pure(2), |i| FutureKind::pure(i * 10));
+/// assert_eq!(block_on(chained), 20);
+/// # }
+/// ```
+///
+/// `map2` joins its two futures, so they make progress concurrently rather than one after the
+/// other, and `tail_rec_m` polls each step in a loop rather than nesting them:
+///
+/// ```rust
+/// # extern crate futures;
+/// # extern crate rust_hkt;
+/// # use futures::executor::block_on;
+/// # use rust_hkt::data::Either;
+/// # use rust_hkt::part3::{Applicative, FutureKind, Monad};
+/// # fn main() {
+/// let countdown = FutureKind::tail_rec_m(1_000_000, |i| {
+///     FutureKind::pure(if i == 0 { Either::Right("done") } else { Either::Left(i - 1) })
+/// });
+/// assert_eq!(block_on(countdown), "done");
+/// # }
+/// ```
+pub struct FutureKind;
+
+impl HKT for FutureKind {
+    type Apply<A> = LocalBoxFuture<'static, A>;
+}
+
+impl Functor<'static> for FutureKind {
+    fn fmap<A, B, F>(fa: LocalBoxFuture<'static, A>, f: F) -> LocalBoxFuture<'static, B>
+    where
+        A: 'static,
+        B: 'static,
+        F: FnMut(A) -> B + 'static,
+    {
+        fa.map(f).boxed_local()
+    }
+}
+
+impl Semigroupal<'static> for FutureKind {
+    fn product<A, B>(
+        fa: LocalBoxFuture<'static, A>,
+        fb: LocalBoxFuture<'static, B>,
+    ) -> LocalBoxFuture<'static, (A, B)>
+    where
+        A: Clone + 'static,
+        B: Clone + 'static,
+    {
+        future::join(fa, fb).boxed_local()
+    }
+}
+
+impl Apply<'static> for FutureKind {
+    fn map2<A, B, C, F>(
+        fa: LocalBoxFuture<'static, A>,
+        fb: LocalBoxFuture<'static, B>,
+        mut f: F,
+    ) -> LocalBoxFuture<'static, C>
+    where
+        A: Clone + 'static,
+        B: Clone + 'static,
+        C: 'static,
+        F: FnMut(A, B) -> C + 'static,
+    {
+        future::join(fa, fb)
+            .map(move |(a, b)| f(a, b))
+            .boxed_local()
+    }
+}
+
+impl Applicative<'static> for FutureKind {
+    fn pure<A: 'static>(a: A) -> LocalBoxFuture<'static, A> {
+        future::ready(a).boxed_local()
+    }
+}
+
+impl FlatMap<'static> for FutureKind {
+    fn flat_map<A, B, F>(fa: LocalBoxFuture<'static, A>, f: F) -> LocalBoxFuture<'static, B>
+    where
+        A: 'static,
+        B: 'static,
+        F: FnMut(A) -> LocalBoxFuture<'static, B> + 'static,
+    {
+        fa.then(f).boxed_local()
+    }
+}
+
+impl FlatMapOnce<'static> for FutureKind {
+    fn flat_map_once<A, B, F>(fa: LocalBoxFuture<'static, A>, f: F) -> LocalBoxFuture<'static, B>
+    where
+        A: 'static,
+        B: 'static,
+        F: FnOnce(A) -> LocalBoxFuture<'static, B> + 'static,
+    {
+        fa.then(f).boxed_local()
+    }
+}
+
+impl Monad<'static> for FutureKind {
+    fn tail_rec_m<A, B, F>(a: A, mut f: F) -> LocalBoxFuture<'static, B>
+    where
+        A: 'static,
+        B: 'static,
+        F: FnMut(A) -> LocalBoxFuture<'static, Either<A, B>> + 'static,
+    {
+        let mut step = f(a);
+        future::poll_fn(move |cx| loop {
+            match step.poll_unpin(cx) {
+                Poll::Pending => return Poll::Pending,
+                Poll::Ready(Either::Left(a)) => step = f(a),
+                Poll::Ready(Either::Right(b)) => return Poll::Ready(b),
+            }
+        })
+        .boxed_local()
+    }
+}
+
+/// Like `FutureKind`, except that `map2` waits for its first future to finish before it starts
+/// polling the second, so traversing with it runs one future at a time.
+///
+/// Both kinds apply to the same boxed future, so the choice can be made per call. See
+/// `traverse_async_sequential`.
+pub struct SequentialFutureKind;
+
+impl HKT for SequentialFutureKind {
+    type Apply<A> = LocalBoxFuture<'static, A>;
+}
+
+impl Functor<'static> for SequentialFutureKind {
+    fn fmap<A, B, F>(fa: LocalBoxFuture<'static, A>, f: F) -> LocalBoxFuture<'static, B>
+    where
+        A: 'static,
+        B: 'static,
+        F: FnMut(A) -> B + 'static,
+    {
+        FutureKind::fmap(fa, f)
+    }
+}
+
+impl Semigroupal<'static> for SequentialFutureKind {
+    fn product<A, B>(
+        fa: LocalBoxFuture<'static, A>,
+        fb: LocalBoxFuture<'static, B>,
+    ) -> LocalBoxFuture<'static, (A, B)>
+    where
+        A: Clone + 'static,
+        B: Clone + 'static,
+    {
+        fa.then(move |a| fb.map(move |b| (a, b))).boxed_local()
+    }
+}
+
+impl Apply<'static> for SequentialFutureKind {
+    fn map2<A, B, C, F>(
+        fa: LocalBoxFuture<'static, A>,
+        fb: LocalBoxFuture<'static, B>,
+        mut f: F,
+    ) -> LocalBoxFuture<'static, C>
+    where
+        A: Clone + 'static,
+        B: Clone + 'static,
+        C: 'static,
+        F: FnMut(A, B) -> C + 'static,
+    {
+        fa.then(move |a| fb.map(move |b| f(a, b))).boxed_local()
+    }
+}
+
+impl Applicative<'static> for SequentialFutureKind {
+    fn pure<A: 'static>(a: A) -> LocalBoxFuture<'static, A> {
+        FutureKind::pure(a)
+    }
+}
+
+impl FlatMap<'static> for SequentialFutureKind {
+    fn flat_map<A, B, F>(fa: LocalBoxFuture<'static, A>, f: F) -> LocalBoxFuture<'static, B>
+    where
+        A: 'static,
+        B: 'static,
+        F: FnMut(A) -> LocalBoxFuture<'static, B> + 'static,
+    {
+        FutureKind::flat_map(fa, f)
+    }
+}
+
+impl FlatMapOnce<'static> for SequentialFutureKind {
+    fn flat_map_once<A, B, F>(fa: LocalBoxFuture<'static, A>, f: F) -> LocalBoxFuture<'static, B>
+    where
+        A: 'static,
+        B: 'static,
+        F: FnOnce(A) -> LocalBoxFuture<'static, B> + 'static,
+    {
+        FutureKind::flat_map_once(fa, f)
+    }
+}
+
+impl Monad<'static> for SequentialFutureKind {
+    fn tail_rec_m<A, B, F>(a: A, f: F) -> LocalBoxFuture<'static, B>
+    where
+        A: 'static,
+        B: 'static,
+        F: FnMut(A) -> LocalBoxFuture<'static, Either<A, B>> + 'static,
+    {
+        FutureKind::tail_rec_m(a, f)
+    }
+}
+
+/// Turns every element of `fa` into a future with `f`, and gives back a future of all their
+/// results, in order.
+///
+/// The futures are run concurrently with `join_all`: each one is polled in turn, and while one
+/// is waiting the others can make progress. `traverse_async_sequential` runs them one after the
+/// other instead.
+///
+/// This gives the same result as `traverse` into `FutureKind`, but that builds a `map2` per
+/// element, nested as deep as the collection is long, which overflows the stack on large ones.
+/// Here the futures are pulled out into a flat `Vec` and the results put back afterwards.
+///
+/// ```rust
+/// # extern crate futures;
+/// # extern crate rust_hkt;
+/// # use std::cell::RefCell;
+/// # use std::rc::Rc;
+/// # use std::task::Poll;
+/// # use futures::executor::block_on;
+/// # use futures::future::{self, FutureExt, LocalBoxFuture};
+/// # use rust_hkt::part3::{traverse_async, traverse_async_sequential, VecKind};
+/// # fn main() {
+/// // A future which logs when it starts and finishes, and waits once in between.
+/// fn step(log: Rc<RefCell<Vec<String>>>, name: &'static str) -> LocalBoxFuture<'static, usize> {
+///     let mut started = false;
+///     future::poll_fn(move |cx| {
+///         if started {
+///             log.borrow_mut().push(format!("{} done", name));
+///             return Poll::Ready(name.len());
+///         }
+///         log.borrow_mut().push(format!("{} started", name));
+///         started = true;
+///         cx.waker().wake_by_ref();
+///         Poll::Pending
+///     })
+///     .boxed_local()
+/// }
+///
+/// let log = Rc::new(RefCell::new(Vec::new()));
+/// let steps = log.clone();
+/// let lengths = traverse_async::<VecKind, _, _, _>(vec!["a", "bb"], move |name| step(steps.clone(), name));
+/// assert_eq!(block_on(lengths), vec![1, 2]);
+/// assert_eq!(*log.borrow(), ["a started", "bb started", "a done", "bb done"]);
+///
+/// log.borrow_mut().clear();
+/// let steps = log.clone();
+/// let lengths =
+///     traverse_async_sequential::<VecKind, _, _, _>(vec!["a", "bb"], move |name| step(steps.clone(), name));
+/// assert_eq!(block_on(lengths), vec![1, 2]);
+/// assert_eq!(*log.borrow(), ["a started", "a done", "bb started", "bb done"]);
+/// # }
+/// ```
+pub fn traverse_async<T, A, B, F>(fa: T::Apply<A>, f: F) -> LocalBoxFuture<'static, T::Apply<B>>
+where
+    T: Traverse<'static> + 'static,
+    A: 'static,
+    B: 'static,
+    F: FnMut(A) -> LocalBoxFuture<'static, B> + 'static,
+{
+    let (shape, futures) = split::<T, _, _, _>(fa, f);
+    future::join_all(futures)
+        .map(move |bs| fill::<T, _>(shape, bs))
+        .boxed_local()
+}
+
+/// Turns a collection of futures into a future of a collection, running them concurrently.
+///
+/// ```rust
+/// # extern crate futures;
+/// # extern crate rust_hkt;
+/// # use futures::executor::block_on;
+/// # use futures::future::{self, FutureExt};
+/// # use rust_hkt::part3::{sequence_async, OptionKind, VecKind};
+/// # fn main() {
+/// let all = sequence_async::<VecKind, _>(vec![future::ready(1).boxed_local(), future::ready(2).boxed_local()]);
+/// assert_eq!(block_on(all), vec![1, 2]);
+///
+/// assert_eq!(block_on(sequence_async::<OptionKind, i32>(None)), None);
+/// # }
+/// ```
+pub fn sequence_async<T, A>(
+    fga: T::Apply<LocalBoxFuture<'static, A>>,
+) -> LocalBoxFuture<'static, T::Apply<A>>
+where
+    T: Traverse<'static> + 'static,
+    A: 'static,
+{
+    traverse_async::<T, _, _, _>(fga, |fa| fa)
+}
+
+/// `traverse_async`, except that each future only starts once the one before it has finished.
+///
+/// `f` is still called on every element up front, but a future does nothing until it's polled,
+/// so this is the one to use when the futures mustn't overlap, e.g. because they share a
+/// connection.
+pub fn traverse_async_sequential<T, A, B, F>(
+    fa: T::Apply<A>,
+    f: F,
+) -> LocalBoxFuture<'static, T::Apply<B>>
+where
+    T: Traverse<'static> + 'static,
+    A: 'static,
+    B: 'static,
+    F: FnMut(A) -> LocalBoxFuture<'static, B> + 'static,
+{
+    let (shape, futures) = split::<T, _, _, _>(fa, f);
+    stream::iter(futures)
+        .then(|fb| fb)
+        .collect()
+        .map(move |bs| fill::<T, _>(shape, bs))
+        .boxed_local()
+}
+
+/// `sequence_async`, except that each future only starts once the one before it has finished.
+pub fn sequence_async_sequential<T, A>(
+    fga: T::Apply<LocalBoxFuture<'static, A>>,
+) -> LocalBoxFuture<'static, T::Apply<A>>
+where
+    T: Traverse<'static> + 'static,
+    A: 'static,
+{
+    traverse_async_sequential::<T, _, _, _>(fga, |fa| fa)
+}
+
+/// Calls `f` on every element, and gives back the futures in order along with `fa`'s shape,
+/// each element replaced by the index of its future.
+fn split<T, A, B, F>(
+    fa: T::Apply<A>,
+    mut f: F,
+) -> (T::Apply<usize>, Vec<LocalBoxFuture<'static, B>>)
+where
+    T: Traverse<'static>,
+    A: 'static,
+    B: 'static,
+    F: FnMut(A) -> LocalBoxFuture<'static, B> + 'static,
+{
+    let futures = Rc::new(RefCell::new(Vec::new()));
+    let pushed = futures.clone();
+    let shape = T::fmap(fa, move |a| {
+        let fb = f(a);
+        let mut futures = pushed.borrow_mut();
+        futures.push(fb);
+        futures.len() - 1
+    });
+    let futures = futures.take();
+    (shape, futures)
+}
+
+/// Puts each of the futures' results back where its index is in `shape`.
+fn fill<T, B>(shape: T::Apply<usize>, bs: Vec<B>) -> T::Apply<B>
+where
+    T: Traverse<'static>,
+    B: 'static,
+{
+    let mut slots: Vec<Option<B>> = bs.into_iter().map(Some).collect();
+    T::fmap(shape, move |i| {
+        slots[i].take().expect("each future has a single result")
+    })
+}
@@ -0,0 +1,95 @@
+use std::cell::{Cell, RefCell};
+use std::sync::Mutex;
+
+use super::{CellKind, Functor, MutexKind, RefCellKind, HKT};
+
+/// The weakest kind of mapping: `imap` needs a function each way, `A -> B` and `B -> A`.
+///
+/// Anything which both produces and consumes its type parameter can't be a `Functor`, since
+/// turning a consumer of `A`s into a consumer of `B`s means going back from `B` to `A`. With
+/// both directions to hand, it can still be mapped over, which is enough to derive, say, a
+/// `Semigroup` for a newtype from the one for its contents:
+///
+/// ```rust
+/// # use rust_hkt::data::{SemigroupDict, SemigroupDictKind};
+/// # use rust_hkt::part3::Invariant;
+/// #[derive(Debug, PartialEq)]
+/// struct Tags(Vec<&'static str>);
+///
+/// let tags = SemigroupDictKind::imap(SemigroupDict::<Vec<&'static str>>::of(), Tags, |tags: Tags| tags.0);
+/// assert_eq!(tags.combine(Tags(vec!["a"]), Tags(vec!["b"])), Tags(vec!["a", "b"]));
+/// ```
+///
+/// A dictionary or a codec is shared, and one of its functions may be called again while it's
+/// already running, so `f` and `g` have to be `Fn`s rather than `FnMut`s.
+///
+/// Every `Functor` is `Invariant`, by ignoring `g`. So are `Cell`, `RefCell` and `Mutex`, which
+/// are invariant in Rust's own sense: they hand out their contents to be both read and written.
+///
+/// ```rust
+/// # use std::cell::Cell;
+/// # use std::sync::Mutex;
+/// # use rust_hkt::part3::{CellKind, Invariant, MutexKind, OptionKind};
+/// let celsius = Cell::new(100.0);
+/// let fahrenheit = CellKind::imap(celsius, |c| c * 9.0 / 5.0 + 32.0, |f| (f - 32.0) * 5.0 / 9.0);
+/// assert_eq!(fahrenheit.get(), 212.0);
+///
+/// let count = MutexKind::imap(Mutex::new(3u8), u32::from, |n| n as u8);
+/// assert_eq!(*count.lock().unwrap(), 3u32);
+///
+/// assert_eq!(OptionKind::imap(Some(1), |i| i + 1, |i| i - 1), Some(2));
+/// ```
+pub trait Invariant<'a>: HKT {
+    fn imap<A, B, F, G>(fa: Self::Apply<A>, f: F, g: G) -> Self::Apply<B>
+    where
+        A: 'a,
+        B: 'a,
+        F: Fn(A) -> B + 'a,
+        G: Fn(B) -> A + 'a;
+}
+
+impl<'a, K> Invariant<'a> for K
+where
+    K: Functor<'a>,
+{
+    fn imap<A, B, F, G>(fa: K::Apply<A>, f: F, _: G) -> K::Apply<B>
+    where
+        A: 'a,
+        B: 'a,
+        F: Fn(A) -> B + 'a,
+        G: Fn(B) -> A + 'a,
+    {
+        K::fmap(fa, f)
+    }
+}
+
+impl<'a> Invariant<'a> for CellKind {
+    fn imap<A, B, F, G>(fa: Cell<A>, f: F, _: G) -> Cell<B>
+    where
+        F: Fn(A) -> B,
+    {
+        Cell::new(f(fa.into_inner()))
+    }
+}
+
+impl<'a> Invariant<'a> for RefCellKind {
+    fn imap<A, B, F, G>(fa: RefCell<A>, f: F, _: G) -> RefCell<B>
+    where
+        F: Fn(A) -> B,
+    {
+        RefCell::new(f(fa.into_inner()))
+    }
+}
+
+/// A poisoned `Mutex` is recovered, its value mapped like any other.
+impl<'a> Invariant<'a> for MutexKind {
+    fn imap<A, B, F, G>(fa: Mutex<A>, f: F, _: G) -> Mutex<B>
+    where
+        F: Fn(A) -> B,
+    {
+        let a = fa
+            .into_inner()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        Mutex::new(f(a))
+    }
+}
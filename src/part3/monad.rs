@@ -0,0 +1,286 @@
+use std::cell::RefCell;
+use std::collections::{LinkedList, VecDeque};
+use std::ops::ControlFlow;
+use std::rc::Rc;
+
+use data::Either;
+
+use super::{
+    Applicative, ControlFlowKind, FlatMap, LinkedListKind, OptionKind, ResultKind, VecDequeKind,
+    VecKind,
+};
+
+/// Unlike part 2, the result of `flat_map` is known to be a `Monad` again, so there's nothing
+/// stopping us from chaining:
+///
+/// ```rust
+/// # use std::collections::LinkedList;
+/// # use rust_hkt::part3::{FlatMap, Functor, LinkedListKind, Monad, OptionKind, ResultKind, VecKind};
+/// fn half_then_double<'a, M, H>(ma: M::Apply<i32>, half: H) -> M::Apply<i32>
+/// where
+///     M: Monad<'a>,
+///     H: Fn(i32) -> M::Apply<i32> + 'a,
+/// {
+///     M::fmap(M::flat_map(ma, half), |i| i * 2)
+/// }
+///
+/// let half = |i: i32| if i % 2 == 0 { Some(i / 2) } else { None };
+/// assert_eq!(half_then_double::<OptionKind, _>(Some(4), half), Some(4));
+/// assert_eq!(half_then_double::<OptionKind, _>(Some(3), half), None);
+///
+/// let half = |i: i32| if i % 2 == 0 { Ok(i / 2) } else { Err(i) };
+/// assert_eq!(half_then_double::<ResultKind<i32>, _>(Ok(3), half), Err(3));
+///
+/// let half = |i: i32| if i % 2 == 0 { vec![i / 2] } else { vec![] };
+/// assert_eq!(half_then_double::<VecKind, _>(vec![1, 2, 3, 4], half), vec![2, 4]);
+///
+/// let half = |i: i32| if i % 2 == 0 { LinkedList::from([i / 2]) } else { LinkedList::new() };
+/// let halved = half_then_double::<LinkedListKind, _>(LinkedList::from([1, 2, 3, 4]), half);
+/// assert_eq!(halved, LinkedList::from([2, 4]));
+/// ```
+pub trait Monad<'a>: FlatMap<'a> + Applicative<'a> {
+    /// Runs `f` on `a`, and then on every `Left` it produces, until it produces a `Right`.
+    ///
+    /// This is how to loop in a monad. Writing the loop as a recursive `flat_map` grows the stack
+    /// with every iteration for a strict monad like `Option`, so instances override this with
+    /// an actual loop where they can, and transformers with the `tail_rec_m` of the monad
+    /// underneath. The default is a recursive `flat_map`, which is only stack-safe for monads like
+    /// `EvalKind` and `IOKind` which trampoline their `flat_map`s anyway.
+    ///
+    /// ```rust
+    /// # use rust_hkt::data::{Either, Eval, EvalKind, IO, IOKind};
+    /// # use rust_hkt::part3::{Monad, OptionKind, ResultKind, VecKind};
+    /// let countdown = OptionKind::tail_rec_m(1_000_000, |i| {
+    ///     if i == 0 { Some(Either::Right("done")) } else { Some(Either::Left(i - 1)) }
+    /// });
+    /// assert_eq!(countdown, Some("done"));
+    ///
+    /// let collatz = |n: u64| ResultKind::tail_rec_m((n, 0), |(n, steps)| match n {
+    ///     0 => Err("zero never reaches 1"),
+    ///     1 => Ok(Either::Right(steps)),
+    ///     n if n % 2 == 0 => Ok(Either::Left((n / 2, steps + 1))),
+    ///     n => Ok(Either::Left((3 * n + 1, steps + 1))),
+    /// });
+    /// assert_eq!(collatz(27), Ok(111));
+    /// assert_eq!(collatz(0), Err("zero never reaches 1"));
+    ///
+    /// let splits = VecKind::tail_rec_m(3, |i| {
+    ///     if i == 0 { vec![Either::Right(i)] } else { vec![Either::Left(i - 1), Either::Right(i)] }
+    /// });
+    /// assert_eq!(splits, vec![0, 1, 2, 3]);
+    ///
+    /// let sum = EvalKind::tail_rec_m((1_000_000u64, 0u64), |(i, total)| {
+    ///     Eval::later(move || if i == 0 { Either::Right(total) } else { Either::Left((i - 1, total + i)) })
+    /// });
+    /// assert_eq!(sum.value(), 500_000_500_000);
+    ///
+    /// let io = IOKind::tail_rec_m(100_000, |i| IO::pure(if i == 0 { Either::Right(()) } else { Either::Left(i - 1) }));
+    /// assert_eq!(io.run(), ());
+    /// ```
+    fn tail_rec_m<A, B, F>(a: A, f: F) -> Self::Apply<B>
+    where
+        A: 'a,
+        B: 'a,
+        F: FnMut(A) -> Self::Apply<Either<A, B>> + 'a,
+    {
+        tail_rec_m_shared::<Self, _, _, _>(a, Rc::new(RefCell::new(f)))
+    }
+}
+
+/// `tail_rec_m` as a recursive `flat_map`. Every step needs the same `f`, so it's shared.
+fn tail_rec_m_shared<'a, M, A, B, F>(a: A, f: Rc<RefCell<F>>) -> M::Apply<B>
+where
+    M: Monad<'a> + ?Sized,
+    A: 'a,
+    B: 'a,
+    F: FnMut(A) -> M::Apply<Either<A, B>> + 'a,
+{
+    let step = (f.borrow_mut())(a);
+    M::flat_map(step, move |either| match either {
+        Either::Left(a) => tail_rec_m_shared::<M, _, _, _>(a, f.clone()),
+        Either::Right(b) => M::pure(b),
+    })
+}
+
+impl<'a> Monad<'a> for OptionKind {
+    fn tail_rec_m<A, B, F>(mut a: A, mut f: F) -> Option<B>
+    where
+        F: FnMut(A) -> Option<Either<A, B>>,
+    {
+        loop {
+            match f(a)? {
+                Either::Left(next) => a = next,
+                Either::Right(b) => return Some(b),
+            }
+        }
+    }
+}
+
+impl<'a> Monad<'a> for VecKind {
+    fn tail_rec_m<A, B, F>(a: A, mut f: F) -> Vec<B>
+    where
+        F: FnMut(A) -> Vec<Either<A, B>>,
+    {
+        // Depth first, like `flat_map`: each `Left` is expanded before the rest of its siblings.
+        let mut out = Vec::new();
+        let mut stack = vec![f(a).into_iter()];
+        while let Some(step) = stack.last_mut() {
+            match step.next() {
+                Some(Either::Left(a)) => stack.push(f(a).into_iter()),
+                Some(Either::Right(b)) => out.push(b),
+                None => {
+                    stack.pop();
+                }
+            }
+        }
+        out
+    }
+}
+
+impl<'a> Monad<'a> for VecDequeKind {
+    fn tail_rec_m<A, B, F>(a: A, mut f: F) -> VecDeque<B>
+    where
+        F: FnMut(A) -> VecDeque<Either<A, B>>,
+    {
+        // The same depth first order as `VecKind`'s.
+        let mut out = VecDeque::new();
+        let mut stack = vec![f(a).into_iter()];
+        while let Some(step) = stack.last_mut() {
+            match step.next() {
+                Some(Either::Left(a)) => stack.push(f(a).into_iter()),
+                Some(Either::Right(b)) => out.push_back(b),
+                None => {
+                    stack.pop();
+                }
+            }
+        }
+        out
+    }
+}
+
+impl<'a> Monad<'a> for LinkedListKind {
+    fn tail_rec_m<A, B, F>(a: A, mut f: F) -> LinkedList<B>
+    where
+        F: FnMut(A) -> LinkedList<Either<A, B>>,
+    {
+        // The same depth first order as `VecKind`'s.
+        let mut out = LinkedList::new();
+        let mut stack = vec![f(a).into_iter()];
+        while let Some(step) = stack.last_mut() {
+            match step.next() {
+                Some(Either::Left(a)) => stack.push(f(a).into_iter()),
+                Some(Either::Right(b)) => out.push_back(b),
+                None => {
+                    stack.pop();
+                }
+            }
+        }
+        out
+    }
+}
+
+impl<'a, B> Monad<'a> for ControlFlowKind<B> {
+    fn tail_rec_m<A, C, F>(mut a: A, mut f: F) -> ControlFlow<B, C>
+    where
+        F: FnMut(A) -> ControlFlow<B, Either<A, C>>,
+    {
+        loop {
+            match f(a)? {
+                Either::Left(next) => a = next,
+                Either::Right(c) => return ControlFlow::Continue(c),
+            }
+        }
+    }
+}
+
+impl<'a, E> Monad<'a> for ResultKind<E> {
+    fn tail_rec_m<A, B, F>(mut a: A, mut f: F) -> Result<B, E>
+    where
+        F: FnMut(A) -> Result<Either<A, B>, E>,
+    {
+        loop {
+            match f(a)? {
+                Either::Left(next) => a = next,
+                Either::Right(b) => return Ok(b),
+            }
+        }
+    }
+}
+
+/// Do-notation: a block of monadic steps, written top to bottom instead of as nested `flat_map`s.
+///
+/// As with the typeclass methods, the kind has to be named. Each step is one of
+///
+/// - `x <- ma;`, `(x, y) <- ma;` or `_ <- ma;`, which `flat_map`s over `ma`,
+/// - `let pattern = expr;`, an ordinary binding,
+///
+/// and the block ends with either `ret expr`, which is `pure(expr)`, or a final `ma`.
+///
+/// ```rust
+/// # #[macro_use] extern crate rust_hkt;
+/// # use rust_hkt::part3::{OptionKind, ResultKind, VecKind};
+/// # fn main() {
+/// let sum = mdo! { OptionKind =>
+///     x <- Some(1);
+///     y <- Some(2);
+///     ret x + y
+/// };
+/// assert_eq!(sum, Some(3));
+///
+/// let parse = |s: &str| s.parse::<i32>().map_err(|_| format!("{} isn't a number", s));
+/// let half = |i: i32| if i % 2 == 0 { Ok(i / 2) } else { Err(format!("{} is odd", i)) };
+/// let parse_half = |s| mdo! { ResultKind<String> =>
+///     i <- parse(s);
+///     let doubled = i * 2;
+///     (q, r) <- Ok((doubled / 3, doubled % 3));
+///     _ <- half(q + r);
+///     half(i)
+/// };
+/// assert_eq!(parse_half("4"), Ok(2));
+/// assert_eq!(parse_half("3"), Err("3 is odd".to_string()));
+/// assert_eq!(parse_half("x"), Err("x isn't a number".to_string()));
+///
+/// let pairs = mdo! { VecKind =>
+///     x <- vec![1, 2, 3];
+///     y <- (x..4).collect();
+///     ret (x, y)
+/// };
+/// assert_eq!(pairs, vec![(1, 1), (1, 2), (1, 3), (2, 2), (2, 3), (3, 3)]);
+/// # }
+/// ```
+///
+/// Every step is a `move` closure, so that the steps work for kinds like `EvalKind` which store
+/// them. A non-`Copy` value bound in one step and used again after a step in a kind which runs
+/// its continuation more than once, like `VecKind`, has to be cloned.
+#[macro_export]
+macro_rules! mdo {
+    (@steps $kind:ty; ret $a:expr) => {
+        <$kind as $crate::part3::Applicative>::pure($a)
+    };
+    (@steps $kind:ty; let $p:pat = $e:expr; $($rest:tt)+) => {{
+        let $p = $e;
+        $crate::mdo!(@steps $kind; $($rest)+)
+    }};
+    (@steps $kind:ty; _ <- $ma:expr; $($rest:tt)+) => {
+        <$kind as $crate::part3::FlatMap>::flat_map($ma, move |_| {
+            $crate::mdo!(@steps $kind; $($rest)+)
+        })
+    };
+    (@steps $kind:ty; $x:ident <- $ma:expr; $($rest:tt)+) => {
+        <$kind as $crate::part3::FlatMap>::flat_map($ma, move |$x| {
+            $crate::mdo!(@steps $kind; $($rest)+)
+        })
+    };
+    (@steps $kind:ty; ($($p:pat),+) <- $ma:expr; $($rest:tt)+) => {
+        <$kind as $crate::part3::FlatMap>::flat_map($ma, move |($($p),+)| {
+            $crate::mdo!(@steps $kind; $($rest)+)
+        })
+    };
+    (@steps $kind:ty; $ma:expr) => {
+        $ma
+    };
+
+    ($kind:ty => $($steps:tt)+) => {
+        $crate::mdo!(@steps $kind; $($steps)+)
+    };
+}
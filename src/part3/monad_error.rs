@@ -0,0 +1,48 @@
+use data::Either;
+
+use super::{ApplicativeError, Monad, OptionKind, ResultKind};
+
+/// An `ApplicativeError` which is also a `Monad`, so that a failure can depend on a value
+/// computed earlier.
+///
+/// ```rust
+/// # use rust_hkt::data::Either;
+/// # use rust_hkt::part3::{MonadError, OptionKind, ResultKind};
+/// let adult = ResultKind::ensure(Ok(17), || "too young", |age| *age >= 18);
+/// assert_eq!(adult, Err("too young"));
+///
+/// let parsed = Ok::<_, String>(Either::Left("x isn't a number".to_string()));
+/// assert_eq!(ResultKind::rethrow::<i32>(parsed), Err("x isn't a number".to_string()));
+///
+/// assert_eq!(OptionKind::ensure(Some(2), || (), |i| i % 2 == 0), Some(2));
+/// ```
+pub trait MonadError<'a, E>: ApplicativeError<'a, E> + Monad<'a> {
+    /// Fails with `error()` unless `predicate` holds for the value.
+    fn ensure<A, G, P>(fa: Self::Apply<A>, mut error: G, mut predicate: P) -> Self::Apply<A>
+    where
+        A: 'a,
+        G: FnMut() -> E + 'a,
+        P: FnMut(&A) -> bool + 'a,
+    {
+        Self::flat_map(fa, move |a| {
+            if predicate(&a) {
+                Self::pure(a)
+            } else {
+                Self::raise_error(error())
+            }
+        })
+    }
+
+    /// The inverse of `attempt`: turns a `Left` back into a failure.
+    fn rethrow<A>(fa: Self::Apply<Either<E, A>>) -> Self::Apply<A>
+    where
+        A: 'a,
+        E: 'a,
+    {
+        Self::flat_map(fa, |either| Self::from_either(either))
+    }
+}
+
+impl<'a> MonadError<'a, ()> for OptionKind {}
+
+impl<'a, E> MonadError<'a, E> for ResultKind<E> {}
@@ -0,0 +1,59 @@
+use super::{Applicative, Monad};
+
+/// An `Applicative` which can read an environment `R`.
+pub trait Ask<'a, R>: Applicative<'a> {
+    fn ask() -> Self::Apply<R>;
+
+    /// Reads the environment and applies `f` to it.
+    fn reader<A, F>(f: F) -> Self::Apply<A>
+    where
+        R: 'a,
+        A: 'a,
+        F: FnMut(R) -> A + 'a,
+    {
+        Self::fmap(Self::ask(), f)
+    }
+}
+
+/// A `Monad` which can read an environment `R`, and run a computation in a modified one.
+///
+/// Like `MonadState`, it lets code ask for an environment without fixing where in the stack the
+/// `ReaderT` is. Layers on top of a `ReaderT` pass `ask` and `local` through to it:
+///
+/// ```rust
+/// # use rust_hkt::data::{Identity, IdentityKind, ReaderTKind, StateTKind, WriterT, WriterTKind};
+/// # use rust_hkt::part3::{Ask, FlatMap, Functor, MonadReader};
+/// fn greeting<M>() -> M::Apply<String>
+/// where
+///     M: MonadReader<'static, String>,
+/// {
+///     M::reader(|name| format!("hello, {}", name))
+/// }
+///
+/// fn shouted<M>() -> M::Apply<String>
+/// where
+///     M: MonadReader<'static, String>,
+/// {
+///     M::local(greeting::<M>(), |name: String| name.to_uppercase())
+/// }
+///
+/// type App = ReaderTKind<String, IdentityKind>;
+/// assert_eq!(shouted::<App>().run("ada".to_string()), Identity("hello, ADA".to_string()));
+///
+/// type Stack = StateTKind<u32, App>;
+/// let both = Stack::flat_map(greeting::<Stack>(), |a| Stack::fmap(shouted::<Stack>(), move |b| format!("{} / {}", a, b)));
+/// let Identity((count, message)) = both.run(0).run("ada".to_string());
+/// assert_eq!((count, message.as_str()), (0, "hello, ada / hello, ADA"));
+///
+/// type Logged = WriterTKind<Vec<&'static str>, App>;
+/// let logged = Logged::flat_map(greeting::<Logged>(), |g| Logged::fmap(WriterT::tell(vec!["greeted"]), move |()| g.clone()));
+/// assert_eq!(logged.run().run("ada".to_string()).0, (vec!["greeted"], "hello, ada".to_string()));
+/// ```
+///
+/// `local` should only change what `ask` sees: `local(ask(), f)` is `fmap(ask(), f)`.
+pub trait MonadReader<'a, R>: Monad<'a> + Ask<'a, R> {
+    fn local<A, F>(fa: Self::Apply<A>, f: F) -> Self::Apply<A>
+    where
+        A: 'a,
+        F: FnMut(R) -> R + 'a;
+}
@@ -0,0 +1,54 @@
+use super::Monad;
+
+/// A `Monad` which can read and replace a state `S`.
+///
+/// Code written against `MonadState` asks for the capability rather than for `State` itself, so
+/// it runs just as well in a bigger stack with a `StateT` somewhere inside, without having to
+/// `lift` anything by hand:
+///
+/// ```rust
+/// # use rust_hkt::data::{ReaderT, ReaderTKind, State, StateKind, StateT, StateTKind};
+/// # use rust_hkt::part3::{FlatMap, MonadState, OptionKind};
+/// fn next_id<M>() -> M::Apply<u32>
+/// where
+///     M: MonadState<'static, u32>,
+/// {
+///     M::flat_map(M::get(), |id| M::fmap(M::set(id + 1), move |()| id))
+/// }
+///
+/// assert_eq!(next_id::<StateKind<u32>>().run(7), (8, 7));
+/// assert_eq!(next_id::<StateTKind<u32, OptionKind>>().run(7), Some((8, 7)));
+///
+/// // The same function, in a stack with an environment on top.
+/// type App = ReaderTKind<&'static str, StateKind<u32>>;
+/// let labelled = App::flat_map(next_id::<App>(), |id| ReaderT::ask().map(move |prefix| format!("{}-{}", prefix, id)));
+/// assert_eq!(labelled.run("user").run(41), (42, "user-41".to_string()));
+///
+/// assert_eq!(StateKind::inspect(|n: u32| n * 2).run(3), (3, 6));
+/// assert_eq!(StateKind::modify(|n: u32| n + 1).run(3), (4, ()));
+/// ```
+///
+/// `set` followed by `get` should give back what was set, and `get` followed by `set` of the same
+/// value should change nothing.
+pub trait MonadState<'a, S>: Monad<'a> {
+    fn get() -> Self::Apply<S>;
+
+    fn set(s: S) -> Self::Apply<()>;
+
+    fn modify<F>(mut f: F) -> Self::Apply<()>
+    where
+        S: 'a,
+        F: FnMut(S) -> S + 'a,
+    {
+        Self::flat_map(Self::get(), move |s| Self::set(f(s)))
+    }
+
+    fn inspect<A, F>(f: F) -> Self::Apply<A>
+    where
+        S: 'a,
+        A: 'a,
+        F: FnMut(S) -> A + 'a,
+    {
+        Self::fmap(Self::get(), f)
+    }
+}
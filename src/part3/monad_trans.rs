@@ -0,0 +1,39 @@
+use super::{Monad, HKT};
+
+/// A monad transformer: a monad built on top of another monad, `Inner`, which can run any
+/// `Inner` computation as one of its own.
+///
+/// The kind of a transformer is applied to the kind of the monad underneath, e.g.
+/// `ReaderTKind<R, IOKind>`, so `Inner` is determined by `Self` and `lift` is always just
+/// `T::lift`. In a stack, each layer lifts from the one directly beneath it; getting an `IO`
+/// through a `ReaderT` over a `WriterT` over `IO` takes two lifts:
+///
+/// ```rust
+/// # use rust_hkt::data::{IO, IOKind, ReaderT, ReaderTKind, WriterT, WriterTKind};
+/// # use rust_hkt::part3::MonadTrans;
+/// type Log = Vec<String>;
+/// type Logged = WriterTKind<Log, IOKind>;
+/// type App = ReaderTKind<usize, Logged>;
+///
+/// fn read_input() -> IO<String> {
+///     IO::delay(|| "ab".to_string())
+/// }
+///
+/// let program = ReaderT::ask()
+///     .flat_map(|times: usize| App::lift(Logged::lift(read_input().map(move |s| s.repeat(times)))))
+///     .flat_map(|s: String| {
+///         let length = s.len();
+///         App::lift(WriterT::tell(vec![format!("read {}", s)])).map(move |()| length)
+///     });
+///
+/// assert_eq!(program.run(3).run().run(), (vec!["read ababab".to_string()], 6));
+/// ```
+///
+/// There's deliberately no blanket impl: what lifting means depends on the transformer (`ReaderT`
+/// ignores its environment, `WriterT` adds an empty log), so each one implements it next to its
+/// `Monad` instance.
+pub trait MonadTrans<'a>: HKT {
+    type Inner: Monad<'a>;
+
+    fn lift<A: 'a>(ma: <Self::Inner as HKT>::Apply<A>) -> Self::Apply<A>;
+}
@@ -0,0 +1,55 @@
+use super::{Applicative, Monad};
+
+/// An `Applicative` which can append to a log `W`.
+pub trait Tell<'a, W>: Applicative<'a> {
+    fn tell(w: W) -> Self::Apply<()>;
+}
+
+/// A `Monad` which can append to a log `W`, look at what's been logged, and rewrite it.
+///
+/// Logging written against `MonadWriter` doesn't care whether the `WriterT` is at the top of the
+/// stack or under a `ReaderT` or a `StateT`, which pass the log through:
+///
+/// ```rust
+/// # use rust_hkt::data::{Identity, IdentityKind, ReaderT, ReaderTKind, StateTKind, Writer, WriterTKind};
+/// # use rust_hkt::part3::{FlatMap, Functor, MonadWriter, Tell};
+/// fn step<M>(name: &'static str, n: i32) -> M::Apply<i32>
+/// where
+///     M: MonadWriter<'static, Vec<String>>,
+/// {
+///     M::fmap(M::tell(vec![format!("{}: {}", name, n)]), move |()| n)
+/// }
+///
+/// fn add<M>() -> M::Apply<i32>
+/// where
+///     M: MonadWriter<'static, Vec<String>>,
+/// {
+///     M::flat_map(step::<M>("a", 1), |a| M::fmap(step::<M>("b", 2), move |b| a + b))
+/// }
+///
+/// type Logged = WriterTKind<Vec<String>, IdentityKind>;
+/// assert_eq!(add::<Logged>().run(), Identity((vec!["a: 1".to_string(), "b: 2".to_string()], 3)));
+///
+/// // `listen` shows what's been logged, `censor` rewrites it.
+/// let counted = Logged::fmap(Logged::listen(add::<Logged>()), |(log, sum)| (log.len(), sum));
+/// assert_eq!(counted.run().0 .1, (2, 3));
+/// let quiet: Writer<Vec<String>, i32> = Logged::censor(add::<Logged>(), |log| log.into_iter().take(1).collect());
+/// assert_eq!(quiet.run().0 .0, vec!["a: 1"]);
+///
+/// type App = ReaderTKind<i32, StateTKind<u8, Logged>>;
+/// let scaled = App::flat_map(add::<App>(), |sum| ReaderT::ask().map(move |scale| sum * scale));
+/// let Identity((log, (_, total))) = scaled.run(10).run(0).run();
+/// assert_eq!((log.len(), total), (2, 30));
+/// ```
+pub trait MonadWriter<'a, W>: Monad<'a> + Tell<'a, W> {
+    /// Makes the log written by `fa` available as part of its result.
+    fn listen<A>(fa: Self::Apply<A>) -> Self::Apply<(W, A)>
+    where
+        A: 'a;
+
+    /// Rewrites the log written by `fa`.
+    fn censor<A, F>(fa: Self::Apply<A>, f: F) -> Self::Apply<A>
+    where
+        A: 'a,
+        F: FnMut(W) -> W + 'a;
+}
@@ -0,0 +1,39 @@
+use std::collections::LinkedList;
+
+use super::{LinkedListKind, OptionKind, SemigroupK, VecKind};
+
+/// A `SemigroupK` with an identity element, `empty_k`:
+///
+/// `combine_k(empty_k(), fa) == fa == combine_k(fa, empty_k())`
+///
+/// ```rust
+/// # use rust_hkt::part3::{MonoidK, OptionKind, SemigroupK, VecKind};
+/// assert_eq!(OptionKind::empty_k::<i32>(), None);
+/// assert_eq!(VecKind::combine_k(VecKind::empty_k(), vec![1]), vec![1]);
+///
+/// let first = vec![None, Some(2), Some(3)].into_iter().fold(OptionKind::empty_k(), OptionKind::combine_k);
+/// assert_eq!(first, Some(2));
+/// ```
+///
+/// `Result` has no instance, since there's no `Err` to start from without an `E` in hand.
+pub trait MonoidK: SemigroupK {
+    fn empty_k<A>() -> Self::Apply<A>;
+}
+
+impl MonoidK for OptionKind {
+    fn empty_k<A>() -> Option<A> {
+        None
+    }
+}
+
+impl MonoidK for VecKind {
+    fn empty_k<A>() -> Vec<A> {
+        Vec::new()
+    }
+}
+
+impl MonoidK for LinkedListKind {
+    fn empty_k<A>() -> LinkedList<A> {
+        LinkedList::new()
+    }
+}
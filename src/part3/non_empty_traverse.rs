@@ -0,0 +1,40 @@
+use super::{Apply, Reducible, Traverse};
+
+/// `Traverse` for structures which are never empty, into any `Apply`.
+///
+/// `traverse` needs `pure` for one reason: to have something to return when there's nothing to
+/// traverse. With at least one element there's always a first effect to start from, so
+/// `non_empty_traverse` gets by with `map2`. That opens it up to effects which have no `pure`,
+/// like `Const` over a `Semigroup` which isn't a `Monoid`:
+///
+/// ```rust
+/// # use rust_hkt::data::{Const, ConstKind, NonEmptyVec, NonEmptyVecKind};
+/// # use rust_hkt::part3::{NonEmptyTraverse, OptionKind};
+/// let lengths = NonEmptyVecKind::non_empty_traverse::<ConstKind<NonEmptyVec<usize>>, _, (), _>(
+///     NonEmptyVec::new("a", vec!["bc", "def"]),
+///     |s: &str| Const::new(NonEmptyVec::of(s.len())),
+/// );
+/// assert_eq!(lengths.get(), NonEmptyVec::new(1, vec![2, 3]));
+///
+/// let parsed = NonEmptyVecKind::non_empty_sequence::<OptionKind, _>(NonEmptyVec::new(Some(1), vec![Some(2)]));
+/// assert_eq!(parsed, Some(NonEmptyVec::new(1, vec![2])));
+/// ```
+pub trait NonEmptyTraverse<'a>: Traverse<'a> + Reducible {
+    fn non_empty_traverse<G, A, B, F>(fa: Self::Apply<A>, f: F) -> G::Apply<Self::Apply<B>>
+    where
+        G: Apply<'a>,
+        A: 'a,
+        B: Clone + 'a,
+        Self::Apply<B>: Clone + 'a,
+        F: FnMut(A) -> G::Apply<B> + 'a;
+
+    fn non_empty_sequence<G, A>(fga: Self::Apply<G::Apply<A>>) -> G::Apply<Self::Apply<A>>
+    where
+        G: Apply<'a>,
+        A: Clone + 'a,
+        G::Apply<A>: 'a,
+        Self::Apply<A>: Clone + 'a,
+    {
+        Self::non_empty_traverse::<G, _, _, _>(fga, |ga| ga)
+    }
+}
@@ -0,0 +1,84 @@
+use data::{Validated, ValidatedKind};
+use kernel::Semigroup;
+
+use super::{Applicative, Apply, Monad, ResultKind, Traverse, HKT};
+
+/// A `Monad` paired with an `Applicative` which runs the same effects side by side.
+///
+/// `map2` on a monad has to agree with `flat_map`, so for `Result` it stops at the first error:
+/// it can't look at the second argument without the first one's value. `Validated` has no
+/// `flat_map` to agree with, which is what lets it keep going and collect every error. `Parallel`
+/// links the two, so the same code can be run either way by picking `traverse` or `par_traverse`:
+///
+/// ```rust
+/// # use rust_hkt::part3::{Parallel, ResultKind, Traverse, VecKind};
+/// fn parse(s: &str) -> Result<i32, Vec<String>> {
+///     s.parse().map_err(|_| vec![format!("{} isn't a number", s)])
+/// }
+///
+/// let first_error = VecKind::traverse::<ResultKind<_>, _, _, _>(vec!["1", "x", "y"], parse);
+/// assert_eq!(first_error, Err(vec!["x isn't a number".to_string()]));
+///
+/// let every_error = ResultKind::par_traverse::<VecKind, _, _, _>(vec!["1", "x", "y"], parse);
+/// assert_eq!(every_error, Err(vec!["x isn't a number".to_string(), "y isn't a number".to_string()]));
+///
+/// assert_eq!(ResultKind::par_map2(parse("1"), parse("2"), |a, b| a + b), Ok(3));
+/// ```
+///
+/// `parallel` and `sequential` should be inverses, and `par_map2` should give the same answer as
+/// `map2` whenever there's nothing for the two to disagree about, like when both succeed.
+pub trait Parallel<'a>: Monad<'a> {
+    type Par: Applicative<'a>;
+
+    fn parallel<A>(ma: Self::Apply<A>) -> <Self::Par as HKT>::Apply<A>;
+
+    fn sequential<A>(fa: <Self::Par as HKT>::Apply<A>) -> Self::Apply<A>;
+
+    fn par_map2<A, B, C, F>(ma: Self::Apply<A>, mb: Self::Apply<B>, f: F) -> Self::Apply<C>
+    where
+        A: Clone + 'a,
+        B: Clone + 'a,
+        C: 'a,
+        F: FnMut(A, B) -> C + 'a,
+    {
+        let fc = Self::Par::map2(Self::parallel(ma), Self::parallel(mb), f);
+        Self::sequential(fc)
+    }
+
+    fn par_traverse<T, A, B, F>(ta: T::Apply<A>, mut f: F) -> Self::Apply<T::Apply<B>>
+    where
+        T: Traverse<'a>,
+        A: 'a,
+        B: Clone + 'a,
+        T::Apply<B>: Clone + 'a,
+        F: FnMut(A) -> Self::Apply<B> + 'a,
+    {
+        let ftb = T::traverse::<Self::Par, _, _, _>(ta, move |a| Self::parallel(f(a)));
+        Self::sequential(ftb)
+    }
+
+    fn par_sequence<T, A>(tma: T::Apply<Self::Apply<A>>) -> Self::Apply<T::Apply<A>>
+    where
+        T: Traverse<'a>,
+        A: Clone + 'a,
+        Self::Apply<A>: 'a,
+        T::Apply<A>: Clone + 'a,
+    {
+        Self::par_traverse::<T, _, _, _>(tma, |ma| ma)
+    }
+}
+
+impl<'a, E> Parallel<'a> for ResultKind<E>
+where
+    E: Semigroup + 'a,
+{
+    type Par = ValidatedKind<E>;
+
+    fn parallel<A>(ma: Result<A, E>) -> Validated<E, A> {
+        Validated::from(ma)
+    }
+
+    fn sequential<A>(fa: Validated<E, A>) -> Result<A, E> {
+        Result::from(fa)
+    }
+}
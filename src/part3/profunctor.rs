@@ -0,0 +1,72 @@
+use super::{FunctionKind2, HKT2};
+
+/// A type constructor with two type parameters, contravariant in the first and covariant in the
+/// second: something which consumes `A`s and produces `B`s, like a function `A -> B`.
+///
+/// `dimap(fab, f, g)` adapts both ends at once, running `f` on the way in and `g` on the way out:
+///
+/// ```rust
+/// # use rust_hkt::part3::{FunctionKind2, Profunctor};
+/// let length: Box<dyn FnMut(&'static str) -> usize> = Box::new(|s| s.len());
+/// let sign = |i: i32| if i > 0 { "positive" } else { "not positive" };
+///
+/// let mut describe = FunctionKind2::dimap(length, sign, |n| n * 2);
+/// assert_eq!(describe(1), 16);
+/// assert_eq!(describe(-1), 24);
+///
+/// let exclaim: Box<dyn FnMut(String) -> String> = Box::new(|s| s + "!");
+/// let mut shout = FunctionKind2::rmap(exclaim, |s| s.to_uppercase());
+/// assert_eq!(shout("hi".to_string()), "HI!");
+/// ```
+///
+/// A `Kleisli` is a profunctor too, see `KleisliKind`.
+pub trait Profunctor<'a>: HKT2 {
+    fn dimap<A, B, C, D, F, G>(fab: Self::Apply2<A, B>, f: F, g: G) -> Self::Apply2<C, D>
+    where
+        A: 'a,
+        B: 'a,
+        C: 'a,
+        D: 'a,
+        F: FnMut(C) -> A + 'a,
+        G: FnMut(B) -> D + 'a;
+
+    /// Adapts the input only.
+    fn lmap<A, B, C, F>(fab: Self::Apply2<A, B>, f: F) -> Self::Apply2<C, B>
+    where
+        A: 'a,
+        B: 'a,
+        C: 'a,
+        F: FnMut(C) -> A + 'a,
+    {
+        Self::dimap(fab, f, |b| b)
+    }
+
+    /// Adapts the output only.
+    fn rmap<A, B, D, G>(fab: Self::Apply2<A, B>, g: G) -> Self::Apply2<A, D>
+    where
+        A: 'a,
+        B: 'a,
+        D: 'a,
+        G: FnMut(B) -> D + 'a,
+    {
+        Self::dimap(fab, |a| a, g)
+    }
+}
+
+impl Profunctor<'static> for FunctionKind2 {
+    fn dimap<A, B, C, D, F, G>(
+        mut fab: Box<dyn FnMut(A) -> B>,
+        mut f: F,
+        mut g: G,
+    ) -> Box<dyn FnMut(C) -> D>
+    where
+        A: 'static,
+        B: 'static,
+        C: 'static,
+        D: 'static,
+        F: FnMut(C) -> A + 'static,
+        G: FnMut(B) -> D + 'static,
+    {
+        Box::new(move |c| g(fab(f(c))))
+    }
+}
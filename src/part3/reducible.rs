@@ -0,0 +1,71 @@
+use kernel::Semigroup;
+
+use super::Foldable;
+
+/// A `Foldable` which is never empty, so it can be folded without a starting value.
+///
+/// `Foldable` needs an `init` or a `Monoid` because there might be nothing to fold, and getting
+/// the first or the largest element of a `Vec` has to return an `Option`. A `Reducible` always
+/// has at least one element to start from, so `reduce_left` and `reduce_map` need no `empty`
+/// and don't have to make anything up:
+///
+/// ```rust
+/// # use rust_hkt::data::{Identity, IdentityKind, NonEmptyVec, NonEmptyVecKind};
+/// # use rust_hkt::part3::Reducible;
+/// let nev = NonEmptyVec::new(3, vec![1, 4, 1, 5]);
+/// assert_eq!(NonEmptyVecKind::reduce_left(nev.clone(), |a, b| a.max(b)), 5);
+/// assert_eq!(NonEmptyVecKind::reduce_map(nev.clone(), NonEmptyVec::of), nev);
+///
+/// let words = NonEmptyVec::new("a", vec!["b", "c"]);
+/// assert_eq!(NonEmptyVecKind::reduce_right_to(words, String::from, |w, acc| format!("({} {})", w, acc)), "(a (b c))");
+///
+/// assert_eq!(IdentityKind::reduce(Identity("only".to_string())), "only");
+/// ```
+pub trait Reducible: Foldable {
+    /// Folds from the left, starting with `f` applied to the first element.
+    fn reduce_left_to<A, B, F, G>(fa: Self::Apply<A>, f: F, g: G) -> B
+    where
+        F: FnOnce(A) -> B,
+        G: FnMut(B, A) -> B;
+
+    /// Folds from the right, starting with `f` applied to the last element.
+    fn reduce_right_to<A, B, F, G>(fa: Self::Apply<A>, f: F, g: G) -> B
+    where
+        F: FnOnce(A) -> B,
+        G: FnMut(A, B) -> B;
+
+    fn reduce_left<A, F>(fa: Self::Apply<A>, f: F) -> A
+    where
+        F: FnMut(A, A) -> A,
+    {
+        Self::reduce_left_to(fa, |a| a, f)
+    }
+
+    fn reduce_right<A, F>(fa: Self::Apply<A>, f: F) -> A
+    where
+        F: FnMut(A, A) -> A,
+    {
+        Self::reduce_right_to(fa, |a| a, f)
+    }
+
+    fn reduce_map<A, S, F>(fa: Self::Apply<A>, mut f: F) -> S
+    where
+        S: Semigroup,
+        F: FnMut(A) -> S,
+    {
+        // Both ends of `reduce_left_to` would need `f`, so fold with the first element
+        // missing instead. It's only missing until the first step.
+        let reduced = Self::fold_left(fa, None, |acc: Option<S>, a| match acc {
+            Some(acc) => Some(acc.combine(f(a))),
+            None => Some(f(a)),
+        });
+        reduced.expect("a Reducible can't be empty")
+    }
+
+    fn reduce<A>(fa: Self::Apply<A>) -> A
+    where
+        A: Semigroup,
+    {
+        Self::reduce_left(fa, Semigroup::combine)
+    }
+}
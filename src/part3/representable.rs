@@ -0,0 +1,110 @@
+use std::array;
+
+use super::{ArrayKind, Functor};
+
+/// A `Functor` which is just a function in disguise: an `F<A>` holds exactly one `A` for every
+/// value of its index type, `Rep`, no more and no fewer.
+///
+/// `tabulate` builds an `F<A>` from a function `Rep -> A`, and `index` looks a value up again.
+/// For a data structure like an array, tabulating calls the function once for every index and
+/// keeps the results, so it memoizes the function:
+///
+/// ```rust
+/// # use rust_hkt::part3::{ArrayKind, Representable};
+/// let mut calls = 0;
+/// let squares = ArrayKind::<5>::tabulate(|i| { calls += 1; i * i });
+/// assert_eq!(squares, [0, 1, 4, 9, 16]);
+/// assert_eq!(ArrayKind::<5>::index(squares, 3), 9);
+/// assert_eq!(ArrayKind::<5>::index_ref(&squares, 4), 16);
+/// assert_eq!(calls, 5);
+/// ```
+///
+/// Since every position can be reached through `index`, `map` and `zip` come for free. Each
+/// result is computed once, through `tabulate`, however many times it's used afterwards. They
+/// look the inputs up with `index_ref`, which only clones the element it finds:
+///
+/// ```rust
+/// # use rust_hkt::data::{Identity, IdentityKind, Reader, ReaderTKind};
+/// # use rust_hkt::part3::{ArrayKind, Representable};
+/// let sums = ArrayKind::<3>::zip_with_rep([1, 2, 3], [10, 20, 30], |a, b| a + b);
+/// assert_eq!(sums, [11, 22, 33]);
+/// assert_eq!(ArrayKind::<3>::fmap_rep(sums, |i| i * 2), [22, 44, 66]);
+///
+/// assert_eq!(IdentityKind::index(IdentityKind::tabulate(|()| "only"), ()), "only");
+///
+/// let greeting: Reader<&str, String> = ReaderTKind::tabulate(|name| format!("hello, {}", name));
+/// assert_eq!(ReaderTKind::index(greeting, "world"), "hello, world");
+/// ```
+pub trait Representable<'a>: Functor<'a> {
+    /// The index type, e.g. `usize` for arrays and `R` for `Reader<R, _>`.
+    type Rep;
+
+    fn tabulate<A, F>(f: F) -> Self::Apply<A>
+    where
+        A: 'a,
+        F: FnMut(Self::Rep) -> A + 'a;
+
+    fn index<A: 'a>(fa: Self::Apply<A>, rep: Self::Rep) -> A;
+
+    /// `index` without giving up `fa`.
+    ///
+    /// By default this clones all of `fa` to `index` it. Data structures override it to clone
+    /// just the element, while a `Reader` is a closure which can only be run once, so it has to
+    /// keep the default.
+    fn index_ref<A>(fa: &Self::Apply<A>, rep: Self::Rep) -> A
+    where
+        A: Clone + 'a,
+        Self::Apply<A>: Clone,
+    {
+        Self::index(fa.clone(), rep)
+    }
+
+    /// `fmap` by way of `tabulate` and `index_ref`.
+    fn fmap_rep<A, B, F>(fa: Self::Apply<A>, mut f: F) -> Self::Apply<B>
+    where
+        A: Clone + 'a,
+        B: 'a,
+        Self::Apply<A>: Clone + 'a,
+        F: FnMut(A) -> B + 'a,
+    {
+        Self::tabulate(move |rep| f(Self::index_ref(&fa, rep)))
+    }
+
+    /// Combines the values at the same index of `fa` and `fb`.
+    fn zip_with_rep<A, B, C, F>(fa: Self::Apply<A>, fb: Self::Apply<B>, mut f: F) -> Self::Apply<C>
+    where
+        A: Clone + 'a,
+        B: Clone + 'a,
+        C: 'a,
+        Self::Rep: Clone,
+        Self::Apply<A>: Clone + 'a,
+        Self::Apply<B>: Clone + 'a,
+        F: FnMut(A, B) -> C + 'a,
+    {
+        Self::tabulate(move |rep: Self::Rep| {
+            f(Self::index_ref(&fa, rep.clone()), Self::index_ref(&fb, rep))
+        })
+    }
+}
+
+/// `index` panics if the index is out of bounds, like indexing the array itself.
+impl<'a, const N: usize> Representable<'a> for ArrayKind<N> {
+    type Rep = usize;
+
+    fn tabulate<A, F>(f: F) -> [A; N]
+    where
+        F: FnMut(usize) -> A,
+    {
+        array::from_fn(f)
+    }
+
+    fn index<A>(fa: [A; N], rep: usize) -> A {
+        IntoIterator::into_iter(fa)
+            .nth(rep)
+            .unwrap_or_else(|| panic!("index {} out of bounds for an array of {}", rep, N))
+    }
+
+    fn index_ref<A: Clone>(fa: &[A; N], rep: usize) -> A {
+        fa[rep].clone()
+    }
+}
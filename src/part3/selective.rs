@@ -0,0 +1,125 @@
+use data::Either;
+
+use super::{Applicative, OptionKind};
+
+/// An `Applicative` whose effects can depend on an earlier result, but only in ways which are
+/// known up front.
+///
+/// `select` looks at the `Either` in its first argument: a `Left` needs the function from the
+/// second effect, a `Right` is already done. An instance is free to skip the second effect when
+/// it isn't needed, which `Applicative` can't express, but unlike `flat_map` both effects are
+/// there to inspect before anything runs. `IO` skips it:
+///
+/// ```rust
+/// # use std::cell::Cell;
+/// # use std::rc::Rc;
+/// # use rust_hkt::data::{IO, IOKind};
+/// # use rust_hkt::part3::Selective;
+/// let launched = Rc::new(Cell::new(false));
+/// let launch = {
+///     let launched = launched.clone();
+///     IO::delay(move || launched.set(true))
+/// };
+///
+/// IOKind::when_s(IO::pure(false), launch).run();
+/// assert!(!launched.get());
+/// ```
+///
+/// `Validated` doesn't, and runs both sides to report every error which *could* happen, the
+/// static analysis reading of a selective computation:
+///
+/// ```rust
+/// # use rust_hkt::data::{Validated, ValidatedKind};
+/// # use rust_hkt::part3::Selective;
+/// let checked = ValidatedKind::if_s(
+///     Validated::Valid(true),
+///     Validated::Valid(1),
+///     Validated::<Vec<&str>, i32>::Invalid(vec!["no else branch"]),
+/// );
+/// assert_eq!(checked, Validated::Invalid(vec!["no else branch"]));
+/// ```
+///
+/// `Option` and `Either` only need the second effect for a `Left`, and don't have any effects to
+/// skip besides failing:
+///
+/// ```rust
+/// # use rust_hkt::data::Either;
+/// # use rust_hkt::part3::{OptionKind, Selective};
+/// let half = |i: i32| i / 2;
+/// assert_eq!(OptionKind::select(Some(Either::Left(4)), Some(half)), Some(2));
+/// assert_eq!(OptionKind::select(Some(Either::Right(4)), None::<fn(i32) -> i32>), Some(4));
+/// ```
+pub trait Selective<'a>: Applicative<'a> {
+    fn select<A, B, F>(fab: Self::Apply<Either<A, B>>, ff: Self::Apply<F>) -> Self::Apply<B>
+    where
+        A: Clone + 'a,
+        B: Clone + 'a,
+        F: FnOnce(A) -> B + Clone + 'a;
+
+    /// Runs `fl` for a `Left` and `fr` for a `Right`.
+    fn branch<A, B, C, F, G>(
+        fab: Self::Apply<Either<A, B>>,
+        fl: Self::Apply<F>,
+        fr: Self::Apply<G>,
+    ) -> Self::Apply<C>
+    where
+        A: Clone + 'a,
+        B: Clone + 'a,
+        C: Clone + 'a,
+        F: FnOnce(A) -> C + Clone + 'a,
+        G: FnOnce(B) -> C + Clone + 'a,
+    {
+        let nested = Self::fmap(fab, |ab| match ab {
+            Either::Left(a) => Either::Left(a),
+            Either::Right(b) => Either::Right(Either::Left(b)),
+        });
+        let left = Self::fmap(fl, |f| move |a| Either::Right(f(a)));
+        Self::select(Self::select(nested, left), fr)
+    }
+
+    /// Runs `fa` if `cond` is true.
+    fn when_s(cond: Self::Apply<bool>, fa: Self::Apply<()>) -> Self::Apply<()> {
+        let cond = Self::fmap(cond, |b| {
+            if b {
+                Either::Left(())
+            } else {
+                Either::Right(())
+            }
+        });
+        Self::select(cond, Self::fmap(fa, |()| |()| ()))
+    }
+
+    /// `then` if `cond` is true, `otherwise` if it isn't.
+    fn if_s<A: Clone + 'a>(
+        cond: Self::Apply<bool>,
+        then: Self::Apply<A>,
+        otherwise: Self::Apply<A>,
+    ) -> Self::Apply<A> {
+        let cond = Self::fmap(cond, |b| {
+            if b {
+                Either::Left(())
+            } else {
+                Either::Right(())
+            }
+        });
+        Self::branch(
+            cond,
+            Self::fmap(then, |a: A| move |()| a),
+            Self::fmap(otherwise, |a: A| move |()| a),
+        )
+    }
+}
+
+impl<'a> Selective<'a> for OptionKind {
+    fn select<A, B, F>(fab: Option<Either<A, B>>, ff: Option<F>) -> Option<B>
+    where
+        A: Clone + 'a,
+        B: Clone + 'a,
+        F: FnOnce(A) -> B + Clone + 'a,
+    {
+        match fab? {
+            Either::Left(a) => ff.map(|f| f(a)),
+            Either::Right(b) => Some(b),
+        }
+    }
+}
@@ -0,0 +1,52 @@
+use std::collections::LinkedList;
+
+use super::{LinkedListKind, OptionKind, ResultKind, VecKind, HKT};
+
+/// A `Semigroup` for every `F<A>` at once, whatever `A` is.
+///
+/// Where `Semigroup` for `Option<A>` needs `A: Semigroup` to combine two `Some`s, `combine_k`
+/// can't look inside the values, so it has to pick one side or keep both. That makes it the
+/// home of "first success wins", as with `Option` and `Result`, and of concatenation, as with
+/// `Vec`:
+///
+/// ```rust
+/// # use rust_hkt::part3::{OptionKind, ResultKind, SemigroupK, VecKind};
+/// assert_eq!(OptionKind::combine_k(None, Some(2)), Some(2));
+/// assert_eq!(OptionKind::combine_k(Some(1), Some(2)), Some(1));
+/// assert_eq!(VecKind::combine_k(vec![1], vec![2, 3]), vec![1, 2, 3]);
+/// assert_eq!(ResultKind::combine_k(Err("nope"), Ok(2)), Ok(2));
+/// assert_eq!(ResultKind::combine_k(Err("nope"), Err::<i32, _>("still no")), Err("still no"));
+/// ```
+///
+/// `combine_k` has to be associative, just like `combine`. Nothing is stored, so unlike the
+/// typeclasses which take a function there's no lifetime.
+pub trait SemigroupK: HKT {
+    fn combine_k<A>(x: Self::Apply<A>, y: Self::Apply<A>) -> Self::Apply<A>;
+}
+
+impl SemigroupK for OptionKind {
+    fn combine_k<A>(x: Option<A>, y: Option<A>) -> Option<A> {
+        x.or(y)
+    }
+}
+
+impl SemigroupK for VecKind {
+    fn combine_k<A>(mut x: Vec<A>, y: Vec<A>) -> Vec<A> {
+        x.extend(y);
+        x
+    }
+}
+
+impl SemigroupK for LinkedListKind {
+    fn combine_k<A>(mut x: LinkedList<A>, mut y: LinkedList<A>) -> LinkedList<A> {
+        x.append(&mut y);
+        x
+    }
+}
+
+/// The first `Ok`, or the last `Err` if neither is.
+impl<E> SemigroupK for ResultKind<E> {
+    fn combine_k<A>(x: Result<A, E>, y: Result<A, E>) -> Result<A, E> {
+        x.or(y)
+    }
+}
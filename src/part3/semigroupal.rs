@@ -0,0 +1,182 @@
+use std::collections::{LinkedList, VecDeque};
+use std::marker::PhantomData;
+use std::ops::ControlFlow;
+use std::task::Poll;
+
+use kernel::Semigroup;
+
+use super::{
+    ControlFlowKind, FunctionKind, Functor, LinkedListKind, OptionKind, PhantomDataKind, PollKind,
+    PollResultKind, ResultKind, TupleKind, VecDequeKind, VecKind, HKT,
+};
+
+/// Pairs up two values in context: `product(F<A>, F<B>) -> F<(A, B)>`.
+///
+/// `product` is `map2` with the function left out, and the two are interchangeable given
+/// `fmap`: `map2(fa, fb, f) == fmap(product(fa, fb), |(a, b)| f(a, b))`. `Semigroupal` is a
+/// supertrait of `Apply`, so `Option`, `Result`, `Vec` and `Validated` all have one:
+///
+/// ```rust
+/// # use rust_hkt::data::{Validated, ValidatedKind};
+/// # use rust_hkt::part3::{OptionKind, ResultKind, Semigroupal, VecKind};
+/// assert_eq!(OptionKind::product(Some(1), Some("a")), Some((1, "a")));
+/// assert_eq!(OptionKind::product(Some(1), None::<&str>), None);
+/// assert_eq!(ResultKind::product(Err::<i32, _>("e"), Ok::<_, &str>('a')), Err("e"));
+/// assert_eq!(VecKind::product(vec![1, 2], vec!['a', 'b']), vec![(1, 'a'), (1, 'b'), (2, 'a'), (2, 'b')]);
+///
+/// let both = ValidatedKind::product(Validated::<_, i32>::Invalid(vec!["a"]), Validated::<_, i32>::Invalid(vec!["b"]));
+/// assert_eq!(both, Validated::Invalid(vec!["a", "b"]));
+/// ```
+///
+/// `product3` and `product4` tuple up more values at once, without the nesting:
+///
+/// ```rust
+/// # use rust_hkt::part3::{OptionKind, Semigroupal};
+/// assert_eq!(OptionKind::product3(Some(1), Some('a'), Some("b")), Some((1, 'a', "b")));
+/// assert_eq!(OptionKind::product4(Some(1), Some(2), None::<i32>, Some(4)), None);
+/// ```
+pub trait Semigroupal<'a>: HKT {
+    fn product<A, B>(fa: Self::Apply<A>, fb: Self::Apply<B>) -> Self::Apply<(A, B)>
+    where
+        A: Clone + 'a,
+        B: Clone + 'a;
+
+    fn product3<A, B, C>(
+        fa: Self::Apply<A>,
+        fb: Self::Apply<B>,
+        fc: Self::Apply<C>,
+    ) -> Self::Apply<(A, B, C)>
+    where
+        Self: Functor<'a>,
+        A: Clone + 'a,
+        B: Clone + 'a,
+        C: Clone + 'a,
+    {
+        let fabc = Self::product(Self::product(fa, fb), fc);
+        Self::fmap(fabc, |((a, b), c)| (a, b, c))
+    }
+
+    fn product4<A, B, C, D>(
+        fa: Self::Apply<A>,
+        fb: Self::Apply<B>,
+        fc: Self::Apply<C>,
+        fd: Self::Apply<D>,
+    ) -> Self::Apply<(A, B, C, D)>
+    where
+        Self: Functor<'a>,
+        A: Clone + 'a,
+        B: Clone + 'a,
+        C: Clone + 'a,
+        D: Clone + 'a,
+    {
+        let fabcd = Self::product(Self::product3(fa, fb, fc), fd);
+        Self::fmap(fabcd, |((a, b, c), d)| (a, b, c, d))
+    }
+}
+
+impl<'a> Semigroupal<'a> for OptionKind {
+    fn product<A, B>(fa: Option<A>, fb: Option<B>) -> Option<(A, B)> {
+        fa.zip(fb)
+    }
+}
+
+impl<'a> Semigroupal<'a> for VecKind {
+    fn product<A, B>(fa: Vec<A>, fb: Vec<B>) -> Vec<(A, B)>
+    where
+        A: Clone,
+        B: Clone,
+    {
+        let mut out = Vec::with_capacity(fa.len() * fb.len());
+        for a in fa {
+            out.extend(fb.iter().map(|b| (a.clone(), b.clone())));
+        }
+        out
+    }
+}
+
+impl<'a> Semigroupal<'a> for VecDequeKind {
+    fn product<A, B>(fa: VecDeque<A>, fb: VecDeque<B>) -> VecDeque<(A, B)>
+    where
+        A: Clone,
+        B: Clone,
+    {
+        let mut out = VecDeque::with_capacity(fa.len() * fb.len());
+        for a in fa {
+            out.extend(fb.iter().map(|b| (a.clone(), b.clone())));
+        }
+        out
+    }
+}
+
+impl<'a> Semigroupal<'a> for LinkedListKind {
+    fn product<A, B>(fa: LinkedList<A>, fb: LinkedList<B>) -> LinkedList<(A, B)>
+    where
+        A: Clone,
+        B: Clone,
+    {
+        let mut out = LinkedList::new();
+        for a in fa {
+            out.extend(fb.iter().map(|b| (a.clone(), b.clone())));
+        }
+        out
+    }
+}
+
+impl<'a, B> Semigroupal<'a> for ControlFlowKind<B> {
+    fn product<C, D>(fc: ControlFlow<B, C>, fd: ControlFlow<B, D>) -> ControlFlow<B, (C, D)> {
+        match (fc, fd) {
+            (ControlFlow::Continue(c), ControlFlow::Continue(d)) => ControlFlow::Continue((c, d)),
+            (ControlFlow::Break(b), _) | (_, ControlFlow::Break(b)) => ControlFlow::Break(b),
+        }
+    }
+}
+
+impl<'a> Semigroupal<'a> for PhantomDataKind {
+    fn product<A, B>(_: PhantomData<A>, _: PhantomData<B>) -> PhantomData<(A, B)> {
+        PhantomData
+    }
+}
+
+impl<'a> Semigroupal<'a> for PollKind {
+    fn product<A, B>(fa: Poll<A>, fb: Poll<B>) -> Poll<(A, B)> {
+        match (fa, fb) {
+            (Poll::Ready(a), Poll::Ready(b)) => Poll::Ready((a, b)),
+            _ => Poll::Pending,
+        }
+    }
+}
+
+impl<'a, E> Semigroupal<'a> for PollResultKind<E> {
+    fn product<A, B>(fa: Poll<Result<A, E>>, fb: Poll<Result<B, E>>) -> Poll<Result<(A, B), E>> {
+        match (fa, fb) {
+            (Poll::Ready(Err(e)), _) | (_, Poll::Ready(Err(e))) => Poll::Ready(Err(e)),
+            (Poll::Ready(Ok(a)), Poll::Ready(Ok(b))) => Poll::Ready(Ok((a, b))),
+            _ => Poll::Pending,
+        }
+    }
+}
+
+impl<'a, E> Semigroupal<'a> for ResultKind<E> {
+    fn product<A, B>(fa: Result<A, E>, fb: Result<B, E>) -> Result<(A, B), E> {
+        Ok((fa?, fb?))
+    }
+}
+
+impl<R: Clone + 'static> Semigroupal<'static> for FunctionKind<R> {
+    fn product<A, B>(
+        mut fa: Box<dyn FnMut(R) -> A>,
+        mut fb: Box<dyn FnMut(R) -> B>,
+    ) -> Box<dyn FnMut(R) -> (A, B)>
+    where
+        A: Clone + 'static,
+        B: Clone + 'static,
+    {
+        Box::new(move |r: R| (fa(r.clone()), fb(r)))
+    }
+}
+
+impl<'a, W: Semigroup> Semigroupal<'a> for TupleKind<W> {
+    fn product<A, B>(fa: (W, A), fb: (W, B)) -> (W, (A, B)) {
+        (fa.0.combine(fb.0), (fa.1, fb.1))
+    }
+}
@@ -0,0 +1,344 @@
+use std::array;
+use std::collections::{BTreeMap, LinkedList, VecDeque};
+use std::marker::PhantomData;
+use std::mem::{ManuallyDrop, MaybeUninit};
+use std::ptr;
+
+use super::{
+    Applicative, ArrayKind, BTreeMapKind, BoxKind, Foldable, Functor, LinkedListKind, OptionKind,
+    PhantomDataKind, ResultKind, TupleKind, VecDequeKind, VecKind,
+};
+
+/// ```rust
+/// # use rust_hkt::part3::{OptionKind, ResultKind, Traverse, VecKind};
+/// let all = VecKind::sequence::<OptionKind, _>(vec![Some(1), Some(2)]);
+/// assert_eq!(all, Some(vec![1, 2]));
+///
+/// let all = OptionKind::sequence::<VecKind, _>(Some(vec![1, 2]));
+/// assert_eq!(all, vec![Some(1), Some(2)]);
+///
+/// let checked = VecKind::traverse::<ResultKind<String>, _, _, _>(vec![1, -2], |i| {
+///     if i > 0 { Ok(i) } else { Err(format!("{} is negative", i)) }
+/// });
+/// assert_eq!(checked, Err("-2 is negative".to_string()));
+/// ```
+///
+/// Effects are combined with `map2_lazy`, so once traversing into something like `Option` or
+/// `Result` has failed, the function isn't called on the rest of the elements:
+///
+/// ```rust
+/// # use rust_hkt::part3::{OptionKind, Traverse, VecKind};
+/// let mut calls = 0;
+/// let parsed = VecKind::traverse::<OptionKind, _, _, _>(vec!["1", "x", "3", "4"], |s| {
+///     calls += 1;
+///     s.parse::<i32>().ok()
+/// });
+/// assert_eq!(parsed, None);
+/// assert_eq!(calls, 2);
+/// ```
+///
+/// Arrays keep their length in their type, so traversing one gives back an array of the same
+/// length:
+///
+/// ```rust
+/// # use rust_hkt::part3::{ArrayKind, OptionKind, Traverse};
+/// let parsed = ArrayKind::<3>::traverse::<OptionKind, _, _, _>(["1", "2", "3"], |s| s.parse::<u8>().ok());
+/// assert_eq!(parsed, Some([1, 2, 3]));
+/// ```
+///
+/// A `VecDeque` keeps its elements front to back, and a `BTreeMap` is traversed in key order,
+/// keeping every key:
+///
+/// ```rust
+/// # use std::collections::{BTreeMap, VecDeque};
+/// # use rust_hkt::part3::{BTreeMapKind, OptionKind, Traverse, VecDequeKind};
+/// let queue = VecDeque::from(["1", "2"]);
+/// let parsed = VecDequeKind::traverse::<OptionKind, _, _, _>(queue, |s| s.parse::<i32>().ok());
+/// assert_eq!(parsed, Some(VecDeque::from([1, 2])));
+///
+/// let ports = BTreeMap::from([("http", "80"), ("https", "443")]);
+/// let parsed = BTreeMapKind::traverse::<OptionKind, _, _, _>(ports, |s| s.parse::<u16>().ok());
+/// assert_eq!(parsed, Some(BTreeMap::from([("http", 80), ("https", 443)])));
+/// ```
+pub trait Traverse<'a>: Functor<'a> + Foldable {
+    fn traverse<G, A, B, F>(fa: Self::Apply<A>, f: F) -> G::Apply<Self::Apply<B>>
+    where
+        G: Applicative<'a>,
+        A: 'a,
+        B: Clone + 'a,
+        Self::Apply<B>: Clone + 'a,
+        F: FnMut(A) -> G::Apply<B> + 'a;
+
+    fn sequence<G, A>(fga: Self::Apply<G::Apply<A>>) -> G::Apply<Self::Apply<A>>
+    where
+        G: Applicative<'a>,
+        A: Clone + 'a,
+        G::Apply<A>: 'a,
+        Self::Apply<A>: Clone + 'a,
+    {
+        Self::traverse::<G, _, _, _>(fga, |ga| ga)
+    }
+}
+
+impl<'a> Traverse<'a> for OptionKind {
+    fn traverse<G, A, B, F>(fa: Option<A>, mut f: F) -> G::Apply<Option<B>>
+    where
+        G: Applicative<'a>,
+        B: 'a,
+        F: FnMut(A) -> G::Apply<B>,
+    {
+        match fa {
+            Some(a) => G::fmap(f(a), Some),
+            None => G::pure(None),
+        }
+    }
+}
+
+impl<'a> Traverse<'a> for PhantomDataKind {
+    fn traverse<G, A, B, F>(_: PhantomData<A>, _: F) -> G::Apply<PhantomData<B>>
+    where
+        G: Applicative<'a>,
+        B: 'a,
+        F: FnMut(A) -> G::Apply<B>,
+    {
+        G::pure(PhantomData)
+    }
+}
+
+impl<'a> Traverse<'a> for BoxKind {
+    fn traverse<G, A, B, F>(fa: Box<A>, mut f: F) -> G::Apply<Box<B>>
+    where
+        G: Applicative<'a>,
+        B: 'a,
+        F: FnMut(A) -> G::Apply<B>,
+    {
+        G::fmap(f(*fa), Box::new)
+    }
+}
+
+/// The results are filled into an uninitialized array one at a time, so nothing goes on the
+/// heap. If `f` panics partway, the results so far are dropped exactly once:
+///
+/// ```rust
+/// # use std::panic;
+/// # use std::sync::atomic::{AtomicUsize, Ordering};
+/// # use rust_hkt::part3::{ArrayKind, OptionKind, Traverse};
+/// static LIVE: AtomicUsize = AtomicUsize::new(0);
+///
+/// struct Tracked;
+///
+/// impl Tracked {
+///     fn new() -> Tracked {
+///         LIVE.fetch_add(1, Ordering::SeqCst);
+///         Tracked
+///     }
+/// }
+///
+/// impl Clone for Tracked {
+///     fn clone(&self) -> Tracked {
+///         Tracked::new()
+///     }
+/// }
+///
+/// impl Drop for Tracked {
+///     fn drop(&mut self) {
+///         LIVE.fetch_sub(1, Ordering::SeqCst);
+///     }
+/// }
+///
+/// let all = ArrayKind::<4>::traverse::<OptionKind, _, _, _>([1, 2, 3, 4], |_| Some(Tracked::new()));
+/// assert_eq!(LIVE.load(Ordering::SeqCst), 4);
+/// drop(all);
+/// assert_eq!(LIVE.load(Ordering::SeqCst), 0);
+///
+/// # panic::set_hook(Box::new(|_| {}));
+/// let panicked = panic::catch_unwind(|| {
+///     ArrayKind::<4>::traverse::<OptionKind, _, _, _>([1, 2, 3, 4], |i| {
+///         if i == 3 {
+///             panic!("no threes");
+///         }
+///         Some(Tracked::new())
+///     })
+/// });
+/// assert!(panicked.is_err());
+/// assert_eq!(LIVE.load(Ordering::SeqCst), 0);
+/// ```
+impl<'a, const N: usize> Traverse<'a> for ArrayKind<N> {
+    fn traverse<G, A, B, F>(fa: [A; N], mut f: F) -> G::Apply<[B; N]>
+    where
+        G: Applicative<'a>,
+        B: Clone + 'a,
+        F: FnMut(A) -> G::Apply<B>,
+    {
+        let init = G::pure(PartialArray::new());
+        let bs = IntoIterator::into_iter(fa).fold(init, |acc, a| {
+            G::map2_lazy(
+                acc,
+                || f(a),
+                |mut bs, b| {
+                    bs.push(b);
+                    bs
+                },
+            )
+        });
+        G::fmap(bs, PartialArray::finish)
+    }
+}
+
+/// An array being filled in from the front, which drops the elements filled in so far if it's
+/// dropped before it's full.
+struct PartialArray<B, const N: usize> {
+    slots: [MaybeUninit<B>; N],
+    len: usize,
+}
+
+impl<B, const N: usize> PartialArray<B, N> {
+    fn new() -> PartialArray<B, N> {
+        PartialArray {
+            slots: array::from_fn(|_| MaybeUninit::uninit()),
+            len: 0,
+        }
+    }
+
+    fn push(&mut self, b: B) {
+        self.slots[self.len].write(b);
+        self.len += 1;
+    }
+
+    fn finish(self) -> [B; N] {
+        assert_eq!(self.len, N, "every slot has been filled");
+        let this = ManuallyDrop::new(self);
+        // Every slot is initialized, and `this` won't drop them, so they can be moved out.
+        unsafe { ptr::read(&this.slots as *const [MaybeUninit<B>; N] as *const [B; N]) }
+    }
+}
+
+impl<B: Clone, const N: usize> Clone for PartialArray<B, N> {
+    fn clone(&self) -> PartialArray<B, N> {
+        let mut copy = PartialArray::new();
+        for slot in &self.slots[..self.len] {
+            // The first `len` slots are initialized.
+            copy.push(unsafe { slot.assume_init_ref() }.clone());
+        }
+        copy
+    }
+}
+
+impl<B, const N: usize> Drop for PartialArray<B, N> {
+    fn drop(&mut self) {
+        for slot in &mut self.slots[..self.len] {
+            // The first `len` slots are initialized, and nothing else drops them.
+            unsafe { slot.assume_init_drop() };
+        }
+    }
+}
+
+impl<'a> Traverse<'a> for VecKind {
+    fn traverse<G, A, B, F>(fa: Vec<A>, mut f: F) -> G::Apply<Vec<B>>
+    where
+        G: Applicative<'a>,
+        B: Clone + 'a,
+        F: FnMut(A) -> G::Apply<B>,
+    {
+        let init = G::pure(Vec::with_capacity(fa.len()));
+        fa.into_iter().fold(init, |acc, a| {
+            G::map2_lazy(
+                acc,
+                || f(a),
+                |mut bs, b| {
+                    bs.push(b);
+                    bs
+                },
+            )
+        })
+    }
+}
+
+impl<'a> Traverse<'a> for VecDequeKind {
+    fn traverse<G, A, B, F>(fa: VecDeque<A>, mut f: F) -> G::Apply<VecDeque<B>>
+    where
+        G: Applicative<'a>,
+        B: Clone + 'a,
+        F: FnMut(A) -> G::Apply<B>,
+    {
+        let init = G::pure(VecDeque::with_capacity(fa.len()));
+        fa.into_iter().fold(init, |acc, a| {
+            G::map2_lazy(
+                acc,
+                || f(a),
+                |mut bs, b| {
+                    bs.push_back(b);
+                    bs
+                },
+            )
+        })
+    }
+}
+
+impl<'a> Traverse<'a> for LinkedListKind {
+    fn traverse<G, A, B, F>(fa: LinkedList<A>, mut f: F) -> G::Apply<LinkedList<B>>
+    where
+        G: Applicative<'a>,
+        B: Clone + 'a,
+        F: FnMut(A) -> G::Apply<B>,
+    {
+        fa.into_iter().fold(G::pure(LinkedList::new()), |acc, a| {
+            G::map2_lazy(
+                acc,
+                || f(a),
+                |mut bs, b| {
+                    bs.push_back(b);
+                    bs
+                },
+            )
+        })
+    }
+}
+
+impl<'a, E: 'a> Traverse<'a> for ResultKind<E> {
+    fn traverse<G, A, B, F>(fa: Result<A, E>, mut f: F) -> G::Apply<Result<B, E>>
+    where
+        G: Applicative<'a>,
+        B: 'a,
+        F: FnMut(A) -> G::Apply<B>,
+    {
+        match fa {
+            Ok(a) => G::fmap(f(a), Ok),
+            Err(e) => G::pure(Err(e)),
+        }
+    }
+}
+
+/// Runs the effects in key order.
+impl<'a, K: Ord + Clone + 'a> Traverse<'a> for BTreeMapKind<K> {
+    fn traverse<G, A, B, F>(fa: BTreeMap<K, A>, mut f: F) -> G::Apply<BTreeMap<K, B>>
+    where
+        G: Applicative<'a>,
+        B: Clone + 'a,
+        F: FnMut(A) -> G::Apply<B>,
+    {
+        fa.into_iter()
+            .fold(G::pure(BTreeMap::new()), |acc, (k, a)| {
+                G::map2_lazy(
+                    acc,
+                    || f(a),
+                    move |mut bs, b| {
+                        bs.insert(k.clone(), b);
+                        bs
+                    },
+                )
+            })
+    }
+}
+
+impl<'a, W: Clone + 'a> Traverse<'a> for TupleKind<W> {
+    fn traverse<G, A, B, F>(fa: (W, A), mut f: F) -> G::Apply<(W, B)>
+    where
+        G: Applicative<'a>,
+        B: 'a,
+        F: FnMut(A) -> G::Apply<B>,
+    {
+        let (w, a) = fa;
+        G::fmap(f(a), move |b| (w.clone(), b))
+    }
+}
@@ -0,0 +1,90 @@
+use super::{Applicative, OptionKind, Traverse, VecKind};
+
+/// `Traverse` which can drop elements on the way through.
+///
+/// `traverse_filter` is `traverse` followed by throwing away the `None`s, done in one pass.
+/// `filter_a` is the common case: filtering by a predicate which has an effect of its own, like
+/// a lookup which might fail, or one which can come out several ways.
+///
+/// ```rust
+/// # use rust_hkt::part3::{OptionKind, ResultKind, TraverseFilter, VecKind};
+/// let evens = VecKind::traverse_filter::<OptionKind, _, _, _>(vec!["1", "2", "4"], |s| {
+///     s.parse::<i32>().ok().map(|i| if i % 2 == 0 { Some(i) } else { None })
+/// });
+/// assert_eq!(evens, Some(vec![2, 4]));
+///
+/// let allowed = |name: &&str| match *name {
+///     "ada" | "grace" => Ok(true),
+///     "alan" => Ok(false),
+///     other => Err(format!("who is {}?", other)),
+/// };
+/// let known = VecKind::filter_a::<ResultKind<String>, _, _>(vec!["ada", "alan", "grace"], allowed);
+/// assert_eq!(known, Ok(vec!["ada", "grace"]));
+/// let unknown = VecKind::filter_a::<ResultKind<String>, _, _>(vec!["ada", "edsger"], allowed);
+/// assert_eq!(unknown, Err("who is edsger?".to_string()));
+/// ```
+///
+/// A predicate into `Vec` answers every way at once, which makes the power set:
+///
+/// ```rust
+/// # use rust_hkt::part3::{TraverseFilter, VecKind};
+/// let subsets = VecKind::filter_a::<VecKind, _, _>(vec![1, 2], |_| vec![true, false]);
+/// assert_eq!(subsets, vec![vec![1, 2], vec![1], vec![2], vec![]]);
+/// ```
+pub trait TraverseFilter<'a>: Traverse<'a> {
+    fn traverse_filter<G, A, B, F>(fa: Self::Apply<A>, f: F) -> G::Apply<Self::Apply<B>>
+    where
+        G: Applicative<'a>,
+        A: 'a,
+        B: Clone + 'a,
+        Self::Apply<B>: Clone + 'a,
+        F: FnMut(A) -> G::Apply<Option<B>> + 'a;
+
+    fn filter_a<G, A, F>(fa: Self::Apply<A>, mut f: F) -> G::Apply<Self::Apply<A>>
+    where
+        G: Applicative<'a>,
+        A: Clone + 'a,
+        Self::Apply<A>: Clone + 'a,
+        F: FnMut(&A) -> G::Apply<bool> + 'a,
+    {
+        Self::traverse_filter::<G, _, _, _>(fa, move |a| {
+            let keep = f(&a);
+            G::fmap(keep, move |keep| if keep { Some(a.clone()) } else { None })
+        })
+    }
+}
+
+impl<'a> TraverseFilter<'a> for OptionKind {
+    fn traverse_filter<G, A, B, F>(fa: Option<A>, mut f: F) -> G::Apply<Option<B>>
+    where
+        G: Applicative<'a>,
+        B: Clone + 'a,
+        F: FnMut(A) -> G::Apply<Option<B>>,
+    {
+        match fa {
+            Some(a) => f(a),
+            None => G::pure(None),
+        }
+    }
+}
+
+impl<'a> TraverseFilter<'a> for VecKind {
+    fn traverse_filter<G, A, B, F>(fa: Vec<A>, mut f: F) -> G::Apply<Vec<B>>
+    where
+        G: Applicative<'a>,
+        B: Clone + 'a,
+        F: FnMut(A) -> G::Apply<Option<B>>,
+    {
+        let init = G::pure(Vec::with_capacity(fa.len()));
+        fa.into_iter().fold(init, |acc, a| {
+            G::map2_lazy(
+                acc,
+                || f(a),
+                |mut bs, b| {
+                    bs.extend(b);
+                    bs
+                },
+            )
+        })
+    }
+}
@@ -0,0 +1,41 @@
+use super::{Applicative, FunctorWithIndex, Traverse, VecKind};
+
+/// A `Traverse` which also tells `f` each element's index, so that an effect can record where
+/// it happened:
+///
+/// ```rust
+/// # use rust_hkt::data::{Validated, ValidatedKind};
+/// # use rust_hkt::part3::{TraverseWithIndex, VecKind};
+/// let parse = |i, s: &str| match s.parse::<i32>() {
+///     Ok(n) => Validated::Valid(n),
+///     Err(e) => Validated::Invalid(vec![(i, e.to_string())]),
+/// };
+///
+/// let parsed = VecKind::traverse_with_index::<ValidatedKind<_>, _, _, _>(vec!["1", "2"], parse);
+/// assert_eq!(parsed, Validated::Valid(vec![1, 2]));
+///
+/// let parsed = VecKind::traverse_with_index::<ValidatedKind<_>, _, _, _>(vec!["x", "2", ""], parse);
+/// assert_eq!(parsed, Validated::Invalid(vec![
+///     (0, "invalid digit found in string".to_string()),
+///     (2, "cannot parse integer from empty string".to_string()),
+/// ]));
+/// ```
+///
+/// It's `traverse` after pairing each element with its index, which is how it's implemented by
+/// default.
+pub trait TraverseWithIndex<'a>: Traverse<'a> + FunctorWithIndex<'a> {
+    fn traverse_with_index<G, A, B, F>(fa: Self::Apply<A>, mut f: F) -> G::Apply<Self::Apply<B>>
+    where
+        G: Applicative<'a>,
+        A: 'a,
+        B: Clone + 'a,
+        Self::Index: 'a,
+        Self::Apply<B>: Clone + 'a,
+        F: FnMut(Self::Index, A) -> G::Apply<B> + 'a,
+    {
+        let indexed = Self::map_with_index(fa, |i, a| (i, a));
+        Self::traverse::<G, _, _, _>(indexed, move |(i, a)| f(i, a))
+    }
+}
+
+impl<'a> TraverseWithIndex<'a> for VecKind {}
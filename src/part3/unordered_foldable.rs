@@ -0,0 +1,78 @@
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+
+use kernel::CommutativeMonoid;
+
+use super::{Foldable, HashMapKind, HashSetKind, HKT};
+
+/// Folding for structures which don't keep their elements in any particular order.
+///
+/// A `HashMap` or a `HashSet` can't be `Foldable`: `fold_left` would hand out its elements in
+/// whatever order the hashes happened to put them, and the answer could change from one run to
+/// the next. Asking for a `CommutativeMonoid` makes the order irrelevant, so
+/// `unordered_fold_map` is well-defined where `fold_map` wouldn't be:
+///
+/// ```rust
+/// # use std::collections::{HashMap, HashSet};
+/// # use std::num::Wrapping;
+/// # use rust_hkt::part3::{HashMapKind, HashSetKind, UnorderedFoldable, VecKind};
+/// let scores: HashMap<_, _> = vec![("ada", 3), ("alan", 4)].into_iter().collect();
+/// assert_eq!(HashMapKind::unordered_fold_map(scores, |i| Wrapping(i * 10)), Wrapping(70));
+///
+/// assert_eq!(VecKind::unordered_fold(vec![Wrapping(1), Wrapping(2)]), Wrapping(3));
+///
+/// let seen: HashSet<_> = vec![3, 4, 3].into_iter().collect();
+/// assert_eq!(HashSetKind::unordered_fold_map(seen, Wrapping), Wrapping(7));
+/// ```
+///
+/// Every `Foldable` is unordered foldable too, it just has an order which goes unused.
+pub trait UnorderedFoldable: HKT {
+    fn unordered_fold_map<A, M, F>(fa: Self::Apply<A>, f: F) -> M
+    where
+        M: CommutativeMonoid,
+        F: FnMut(A) -> M;
+
+    fn unordered_fold<A>(fa: Self::Apply<A>) -> A
+    where
+        A: CommutativeMonoid,
+    {
+        Self::unordered_fold_map(fa, |a| a)
+    }
+}
+
+impl<F> UnorderedFoldable for F
+where
+    F: Foldable,
+{
+    fn unordered_fold_map<A, M, G>(fa: F::Apply<A>, f: G) -> M
+    where
+        M: CommutativeMonoid,
+        G: FnMut(A) -> M,
+    {
+        F::fold_map(fa, f)
+    }
+}
+
+impl<K> UnorderedFoldable for HashMapKind<K>
+where
+    K: Eq + Hash,
+{
+    fn unordered_fold_map<A, M, F>(fa: HashMap<K, A>, mut f: F) -> M
+    where
+        M: CommutativeMonoid,
+        F: FnMut(A) -> M,
+    {
+        fa.into_iter()
+            .fold(M::empty(), |acc, (_, a)| acc.combine(f(a)))
+    }
+}
+
+impl UnorderedFoldable for HashSetKind {
+    fn unordered_fold_map<A, M, F>(fa: HashSet<A>, mut f: F) -> M
+    where
+        M: CommutativeMonoid,
+        F: FnMut(A) -> M,
+    {
+        fa.into_iter().fold(M::empty(), |acc, a| acc.combine(f(a)))
+    }
+}
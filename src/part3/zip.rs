@@ -0,0 +1,53 @@
+use super::{ArrayKind, Functor, OptionKind, VecKind};
+
+/// Pairing up elements by position, dropping any which don't have a partner.
+///
+/// This is `Align` without the leftovers: `zip_with` only sees the positions where both sides
+/// have an element. For arrays, which always have the same shape, nothing is ever dropped:
+///
+/// ```rust
+/// # use rust_hkt::part3::{ArrayKind, OptionKind, VecKind, Zip};
+/// assert_eq!(ArrayKind::<3>::zip_with([1, 2, 3], [10, 20, 30], |a, b| a * b), [10, 40, 90]);
+/// assert_eq!(VecKind::zip(vec![1, 2, 3], vec!["a", "b"]), vec![(1, "a"), (2, "b")]);
+/// assert_eq!(OptionKind::zip(Some(1), None::<&str>), None);
+/// ```
+pub trait Zip<'a>: Functor<'a> {
+    fn zip_with<A, B, C, F>(fa: Self::Apply<A>, fb: Self::Apply<B>, f: F) -> Self::Apply<C>
+    where
+        A: 'a,
+        B: 'a,
+        C: 'a,
+        F: FnMut(A, B) -> C + 'a;
+
+    fn zip<A: 'a, B: 'a>(fa: Self::Apply<A>, fb: Self::Apply<B>) -> Self::Apply<(A, B)> {
+        Self::zip_with(fa, fb, |a, b| (a, b))
+    }
+}
+
+impl<'a> Zip<'a> for OptionKind {
+    fn zip_with<A, B, C, F>(fa: Option<A>, fb: Option<B>, mut f: F) -> Option<C>
+    where
+        F: FnMut(A, B) -> C,
+    {
+        Some(f(fa?, fb?))
+    }
+}
+
+impl<'a> Zip<'a> for VecKind {
+    fn zip_with<A, B, C, F>(fa: Vec<A>, fb: Vec<B>, mut f: F) -> Vec<C>
+    where
+        F: FnMut(A, B) -> C,
+    {
+        fa.into_iter().zip(fb).map(|(a, b)| f(a, b)).collect()
+    }
+}
+
+impl<'a, const N: usize> Zip<'a> for ArrayKind<N> {
+    fn zip_with<A, B, C, F>(fa: [A; N], fb: [B; N], mut f: F) -> [C; N]
+    where
+        F: FnMut(A, B) -> C,
+    {
+        let mut fb = IntoIterator::into_iter(fb);
+        fa.map(|a| f(a, fb.next().expect("both arrays have N elements")))
+    }
+}
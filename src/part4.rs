@@ -0,0 +1,205 @@
+//! # Lightweight higher-kinded polymorphism
+//!
+//! Before GATs, there was another way out of the part 2 mess, which doesn't need any special
+//! language support at all: the defunctionalization trick from Yallop and White's
+//! [Lightweight higher-kinded polymorphism](https://www.cl.cam.ac.uk/~jdy22/papers/lightweight-higher-kinded-polymorphism.pdf).
+//!
+//! The idea is to never write `F<A>` at all. Instead, every type constructor gets a *brand*, a
+//! zero-sized type standing in for the unapplied constructor, and `F<A>` is represented by a single
+//! type, `App<F, A>`. Since `F` and `A` are ordinary type parameters of `App`, the compiler can
+//! see them, and infer them, everywhere:
+//!
+//! ```rust
+//! # use rust_hkt::part4::{App, Functor, OptionBrand, VecBrand};
+//! fn double_and_convert_to_string_in_context<F>(fa: App<F, i32>) -> App<F, String>
+//! where
+//!     F: Functor,
+//! {
+//!     F::fmap(F::fmap(fa, |i| i * 2), |i| format!("{}", i))
+//! }
+//!
+//! let doubled = double_and_convert_to_string_in_context(OptionBrand::inj(Some(1)));
+//! assert_eq!(OptionBrand::prj(doubled), Some("2".to_string()));
+//!
+//! let doubled = double_and_convert_to_string_in_context(VecBrand::inj(vec![1, 2]));
+//! assert_eq!(VecBrand::prj(doubled), vec!["2", "4"]);
+//! ```
+//!
+//! Getting in and out of `App` is the job of `inj` and `prj`, which every brand provides. The
+//! catch is that Rust has no way to say "`App<OptionBrand, A>` is just another name for
+//! `Option<A>`", so `App` has to erase the value it holds and `prj` has to recover it at runtime.
+//! That means:
+//!
+//! - every `inj` is a heap allocation, and every `prj` is a dynamic type check,
+//! - everything we put in an `App` has to be `'static`,
+//! - and user code is littered with `inj` and `prj` calls at every boundary.
+//!
+//! `prj` can't actually fail: `App<F, A>` can only be built by `F::inj`, so whatever is inside
+//! is always an `F<A>`. But that's an invariant we maintain by hand, not something the compiler
+//! checks for us.
+//!
+//! `Monad` goes the same way, and chains just as happily as `Functor`:
+//!
+//! ```rust
+//! # use rust_hkt::part4::{App, Monad, OptionBrand, VecBrand};
+//! fn half_then_double<M>(ma: App<M, i32>) -> App<M, i32>
+//! where
+//!     M: Monad,
+//! {
+//!     M::fmap(M::flat_map(ma, |i| M::pure(i / 2)), |i| i * 2)
+//! }
+//!
+//! assert_eq!(OptionBrand::prj(half_then_double(OptionBrand::inj(Some(5)))), Some(4));
+//! assert_eq!(VecBrand::prj(half_then_double(VecBrand::inj(vec![2, 4]))), vec![2, 4]);
+//! ```
+
+use std::any::Any;
+use std::marker::PhantomData;
+
+/// The type constructor branded `F`, applied to `A`.
+pub struct App<F, A> {
+    repr: Box<dyn Any>,
+    marker: PhantomData<(F, A)>,
+}
+
+impl<F, A> App<F, A> {
+    fn new<R>(repr: R) -> App<F, A>
+    where
+        R: 'static,
+    {
+        App {
+            repr: Box::new(repr),
+            marker: PhantomData,
+        }
+    }
+
+    fn into_repr<R>(self) -> R
+    where
+        R: 'static,
+    {
+        *self
+            .repr
+            .downcast()
+            .expect("App<F, A> should only ever be constructed by F::inj")
+    }
+}
+
+/// The brand of `Option`.
+pub struct OptionBrand;
+
+impl OptionBrand {
+    pub fn inj<A>(fa: Option<A>) -> App<OptionBrand, A>
+    where
+        A: 'static,
+    {
+        App::new(fa)
+    }
+
+    pub fn prj<A>(app: App<OptionBrand, A>) -> Option<A>
+    where
+        A: 'static,
+    {
+        app.into_repr()
+    }
+}
+
+/// The brand of `Vec`.
+pub struct VecBrand;
+
+impl VecBrand {
+    pub fn inj<A>(fa: Vec<A>) -> App<VecBrand, A>
+    where
+        A: 'static,
+    {
+        App::new(fa)
+    }
+
+    pub fn prj<A>(app: App<VecBrand, A>) -> Vec<A>
+    where
+        A: 'static,
+    {
+        app.into_repr()
+    }
+}
+
+pub trait Functor: Sized {
+    fn fmap<A, B, F>(fa: App<Self, A>, f: F) -> App<Self, B>
+    where
+        A: 'static,
+        B: 'static,
+        F: FnMut(A) -> B;
+}
+
+impl Functor for OptionBrand {
+    fn fmap<A, B, F>(fa: App<OptionBrand, A>, f: F) -> App<OptionBrand, B>
+    where
+        A: 'static,
+        B: 'static,
+        F: FnMut(A) -> B,
+    {
+        OptionBrand::inj(OptionBrand::prj(fa).map(f))
+    }
+}
+
+impl Functor for VecBrand {
+    fn fmap<A, B, F>(fa: App<VecBrand, A>, f: F) -> App<VecBrand, B>
+    where
+        A: 'static,
+        B: 'static,
+        F: FnMut(A) -> B,
+    {
+        VecBrand::inj(VecBrand::prj(fa).into_iter().map(f).collect())
+    }
+}
+
+pub trait Monad: Functor {
+    fn pure<A>(a: A) -> App<Self, A>
+    where
+        A: 'static;
+
+    fn flat_map<A, B, F>(fa: App<Self, A>, f: F) -> App<Self, B>
+    where
+        A: 'static,
+        B: 'static,
+        F: FnMut(A) -> App<Self, B>;
+}
+
+impl Monad for OptionBrand {
+    fn pure<A>(a: A) -> App<OptionBrand, A>
+    where
+        A: 'static,
+    {
+        OptionBrand::inj(Some(a))
+    }
+
+    fn flat_map<A, B, F>(fa: App<OptionBrand, A>, mut f: F) -> App<OptionBrand, B>
+    where
+        A: 'static,
+        B: 'static,
+        F: FnMut(A) -> App<OptionBrand, B>,
+    {
+        OptionBrand::inj(OptionBrand::prj(fa).and_then(|a| OptionBrand::prj(f(a))))
+    }
+}
+
+impl Monad for VecBrand {
+    fn pure<A>(a: A) -> App<VecBrand, A>
+    where
+        A: 'static,
+    {
+        VecBrand::inj(vec![a])
+    }
+
+    fn flat_map<A, B, F>(fa: App<VecBrand, A>, mut f: F) -> App<VecBrand, B>
+    where
+        A: 'static,
+        B: 'static,
+        F: FnMut(A) -> App<VecBrand, B>,
+    {
+        let fb = VecBrand::prj(fa)
+            .into_iter()
+            .flat_map(|a| VecBrand::prj(f(a)))
+            .collect();
+        VecBrand::inj(fb)
+    }
+}
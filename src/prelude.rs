@@ -0,0 +1,71 @@
+//! # Prelude
+//!
+//! The blog posts build the library up part by part, which is a fine way to read it and an
+//! awkward way to use it. This module gathers the typeclasses, the kinds and data types they're
+//! implemented for, the syntax extensions and the macros, so that one glob import is enough:
+//!
+//! ```rust
+//! # extern crate rust_hkt;
+//! use rust_hkt::prelude::*;
+//!
+//! # fn main() {
+//! let parsed = VecKind::traverse::<OptionKind, _, _, _>(vec!["1", "2"], |s| s.parse::<i32>().ok());
+//! assert_eq!(parsed, Some(vec![1, 2]));
+//!
+//! let validated = ValidatedKind::<Vec<&str>>::map2(
+//!     Validated::Invalid(vec!["no name"]),
+//!     Validated::<_, i32>::Invalid(vec!["no age"]),
+//!     |name: &str, age| format!("{} is {}", name, age),
+//! );
+//! assert_eq!(validated, Validated::Invalid(vec!["no name", "no age"]));
+//!
+//! let sum = mdo! { OptionKind =>
+//!     a <- Some(1);
+//!     b <- Some(2);
+//!     ret a + b
+//! };
+//! assert_eq!(sum, Some(3));
+//! # }
+//! ```
+//!
+//! Only `part3`'s encoding is included: the earlier parts are stepping stones, and their traits
+//! share names with the ones here.
+
+pub use kernel::{
+    combine_all, All, Any, CommutativeMonoid, CommutativeSemigroup, Dual, Endo, First, Group, Last,
+    Monoid, Product, Semigroup, Sum,
+};
+pub use part3::{
+    Align, Alternative, Applicative, ApplicativeError, Apply, Ask, Bifoldable, Bifunctor,
+    Bitraverse, BorrowedFoldable, BorrowedFunctor, BorrowedHKT, Bracket, CommutativeApplicative,
+    Comonad, Compactable, Contravariant, Decidable, Decide, Defer, Divide, Divisible, FlatMap,
+    FlatMapOnce, Foldable, FoldableWithIndex, FunctionK, Functor, FunctorFilter, FunctorWithIndex,
+    Invariant, Monad, MonadError, MonadReader, MonadState, MonadTrans, MonadWriter, MonoidK,
+    NonEmptyTraverse, Parallel, Profunctor, Reducible, Representable, Selective, SemigroupK,
+    Semigroupal, Tell, Traverse, TraverseFilter, TraverseWithIndex, UnorderedFoldable, Zip, HKT,
+    HKT2,
+};
+pub use part3::{
+    ArcKind, ArrayKind, BTreeMapKind, BTreeSetKind, BoxKind, CellKind, ControlFlowKind,
+    ControlFlowKind2, CowSliceKind, FunctionKind, FunctionKind2, HashMapKind, HashSetKind,
+    LinkedListKind, MutexKind, OptionKind, PhantomDataKind, PollKind, PollResultKind, RcKind,
+    RefCellKind, ResultKind, ResultKind2, SliceKind, TupleKind, TupleKind2, VecDequeKind, VecKind,
+};
+
+pub use data::{
+    ApIO, ApIOKind, Comparator, ComparatorKind, Const, ConstKind, Either, EitherKind, EitherKind2,
+    EitherT, EitherTKind, Encoder, EncoderKind, Env, EnvKind, Eval, EvalKind, Free, IOKind,
+    Identity, IdentityKind, Ior, IorKind2, Iter, IterKind, Kleisli, KleisliKind, NonEmptyVec,
+    NonEmptyVecKind, Predicate, PredicateKind, Reader, ReaderT, ReaderTKind, State, StateKind,
+    StateT, StateTKind, Store, StoreKind, Validated, ValidatedKind, ValidatedKind2, ValidatedNel,
+    Writer, WriterT, WriterTKind, IO,
+};
+
+pub use syntax::{LiftSyntax, PipeSyntax};
+
+#[cfg(feature = "rayon")]
+pub use par::ParTraverse;
+#[cfg(feature = "futures")]
+pub use part3::{FutureKind, SequentialFutureKind};
+
+pub use {combine, mdo};
@@ -0,0 +1,109 @@
+//! # Syntax
+//!
+//! Extension traits which let the everyday operations read left to right. They're implemented
+//! for every type, so bringing them into scope (or using the prelude) is all it takes:
+//!
+//! ```rust
+//! # use rust_hkt::data::{Either, Validated};
+//! use rust_hkt::syntax::{LiftSyntax, PipeSyntax};
+//!
+//! let length = "hello".pipe(str::len).tap(|len| assert_eq!(*len, 5));
+//! assert_eq!(length.some(), Some(5));
+//! assert_eq!(length.right::<String>(), Either::Right(5));
+//! assert_eq!("too short".invalid::<usize>(), Validated::Invalid("too short"));
+//! ```
+
+use data::{Either, NonEmptyVec, Validated, ValidatedNel};
+
+/// Calling a function on a value as a method, so that a chain of calls doesn't have to be read
+/// inside out.
+///
+/// ```rust
+/// # use rust_hkt::syntax::PipeSyntax;
+/// let mut seen = Vec::new();
+/// let shout = "hi"
+///     .pipe(str::to_uppercase)
+///     .tap(|s| seen.push(s.clone()))
+///     .pipe(|s| format!("{}!", s));
+/// assert_eq!(shout, "HI!");
+/// assert_eq!(seen, ["HI"]);
+/// ```
+pub trait PipeSyntax: Sized {
+    /// Passes `self` to `f`, giving back its result.
+    fn pipe<B, F>(self, f: F) -> B
+    where
+        F: FnOnce(Self) -> B,
+    {
+        f(self)
+    }
+
+    /// Lets `f` look at `self`, e.g. to log it, then gives `self` back unchanged.
+    fn tap<F>(self, f: F) -> Self
+    where
+        F: FnOnce(&Self),
+    {
+        f(&self);
+        self
+    }
+}
+
+impl<A> PipeSyntax for A {}
+
+/// Postfix constructors, which wrap a value in `Option`, `Result`, `Either` or `Validated`.
+///
+/// They're most useful at the end of a chain, and when the other type parameter can be left to
+/// inference:
+///
+/// ```rust
+/// # use rust_hkt::data::{Either, NonEmptyVec, Validated, ValidatedNel};
+/// # use rust_hkt::syntax::LiftSyntax;
+/// fn positive(i: i32) -> ValidatedNel<String, i32> {
+///     if i > 0 { i.valid() } else { format!("{} isn't positive", i).invalid_nel() }
+/// }
+///
+/// assert_eq!(positive(1), Validated::Valid(1));
+/// assert_eq!(positive(0), Validated::Invalid(NonEmptyVec::new("0 isn't positive".to_string(), vec![])));
+///
+/// let parsed: Result<i32, String> = 1.ok_or_else_t();
+/// assert_eq!(parsed, Ok(1));
+/// assert_eq!("nope".err_t::<i32>(), Err("nope"));
+/// assert_eq!(1.left::<&str>(), Either::Left(1));
+/// ```
+///
+/// The `Result` constructors are `ok_or_else_t` and `err_t` rather than `ok` and `err`, which
+/// `Result` already has as methods of its own.
+pub trait LiftSyntax: Sized {
+    fn some(self) -> Option<Self> {
+        Some(self)
+    }
+
+    fn ok_or_else_t<E>(self) -> Result<Self, E> {
+        Ok(self)
+    }
+
+    fn err_t<A>(self) -> Result<A, Self> {
+        Err(self)
+    }
+
+    fn left<R>(self) -> Either<Self, R> {
+        Either::Left(self)
+    }
+
+    fn right<L>(self) -> Either<L, Self> {
+        Either::Right(self)
+    }
+
+    fn valid<E>(self) -> Validated<E, Self> {
+        Validated::Valid(self)
+    }
+
+    fn invalid<A>(self) -> Validated<Self, A> {
+        Validated::Invalid(self)
+    }
+
+    fn invalid_nel<A>(self) -> ValidatedNel<Self, A> {
+        Validated::Invalid(NonEmptyVec::of(self))
+    }
+}
+
+impl<A> LiftSyntax for A {}
@@ -0,0 +1,273 @@
+//! # Testing
+//!
+//! Property-based checks of the laws in `laws`, built on `proptest`. They're behind the `testing`
+//! feature so that nobody pays for `proptest` who isn't using it.
+//!
+//! A handful of hand-picked examples can easily miss the one case where an instance goes wrong:
+//! the empty `Vec`, the `Err` on the left rather than the right. These functions take a strategy
+//! for values in the context and check the laws against whatever it generates, panicking with
+//! the smallest failing input they can find.
+//!
+//! The data types in `data` implement `proptest`'s `Arbitrary` when this feature is on, so they
+//! can be generated with `any` like anything else, whether that's to check their own instances
+//! or to test code written against them:
+//!
+//! ```rust
+//! # extern crate proptest;
+//! # extern crate rust_hkt;
+//! # use proptest::prelude::*;
+//! # use rust_hkt::data::{Either, EitherKind, Identity, IdentityKind, NonEmptyVec, Validated, ValidatedKind, Writer, WriterTKind};
+//! # use rust_hkt::testing::{check_applicative_laws, check_semigroup_laws};
+//! # fn main() {
+//! check_applicative_laws::<EitherKind<u8>, _>(any::<Either<u8, i32>>());
+//! check_applicative_laws::<ValidatedKind<Vec<u8>>, _>(any::<Validated<Vec<u8>, i32>>());
+//! check_applicative_laws::<IdentityKind, _>(any::<Identity<i32>>());
+//! check_applicative_laws::<WriterTKind<String, IdentityKind>, _>(any::<Writer<String, i32>>());
+//! check_semigroup_laws(any::<NonEmptyVec<u8>>());
+//!
+//! proptest!(|(nev in any::<NonEmptyVec<i32>>())| {
+//!     prop_assert_eq!(nev.len(), nev.clone().into_vec().len());
+//!     prop_assert!(!nev.is_empty());
+//! });
+//! # }
+//! ```
+
+mod arbitrary;
+
+use std::fmt::Debug;
+
+use proptest::prelude::*;
+use proptest::test_runner::{TestCaseError, TestRunner};
+
+use kernel::{Band, Group, Monoid, Semigroup, Semilattice};
+use laws::{self, IsEq};
+use part3::Applicative;
+
+/// The functions the checks pick from, since `proptest` can't generate functions itself. Each one
+/// is a plain `fn` so that it's `Clone` and can be printed.
+const FUNCTIONS: [fn(i32) -> i32; 6] = [
+    |i| i,
+    |i| i.wrapping_add(1),
+    |i| i.wrapping_mul(3),
+    |i| i.wrapping_neg(),
+    |i| i / 2,
+    |i| i.wrapping_mul(i),
+];
+
+fn function(i: i32) -> fn(i32) -> i32 {
+    FUNCTIONS[i.rem_euclid(FUNCTIONS.len() as i32) as usize]
+}
+
+fn check<A: Debug + PartialEq>(law: &str, is_eq: IsEq<A>) -> Result<(), TestCaseError> {
+    if is_eq.equals() {
+        Ok(())
+    } else {
+        Err(TestCaseError::fail(format!(
+            "{} doesn't hold: {:?} != {:?}",
+            law, is_eq.lhs, is_eq.rhs
+        )))
+    }
+}
+
+/// Runs `test` against the values from `inputs`, panicking with the smallest failing input.
+fn run<S, F>(inputs: &S, test: F)
+where
+    S: Strategy,
+    S::Value: Debug,
+    F: Fn(S::Value) -> Result<(), TestCaseError>,
+{
+    if let Err(err) = TestRunner::default().run(inputs, test) {
+        panic!("{}", err);
+    }
+}
+
+/// Checks the identity, homomorphism, interchange and composition laws for `F`'s `Applicative`
+/// instance, using values from `values`.
+///
+/// Functions in the context are made by mapping generated values to functions, so they have the
+/// same shape as the generated values: a `None` or an empty `Vec` of functions is checked too.
+///
+/// ```rust
+/// # extern crate proptest;
+/// # extern crate rust_hkt;
+/// # use proptest::prelude::*;
+/// # use rust_hkt::part3::{OptionKind, ResultKind, VecKind};
+/// # use rust_hkt::testing::check_applicative_laws;
+/// # fn main() {
+/// check_applicative_laws::<OptionKind, _>(any::<Option<i32>>());
+/// check_applicative_laws::<VecKind, _>(proptest::collection::vec(any::<i32>(), 0..4));
+/// check_applicative_laws::<ResultKind<u8>, _>(any::<Result<i32, u8>>());
+/// # }
+/// ```
+pub fn check_applicative_laws<F, S>(values: S)
+where
+    F: Applicative<'static>,
+    S: Strategy<Value = F::Apply<i32>> + Clone,
+    F::Apply<i32>: Clone + Debug + PartialEq,
+    F::Apply<fn(i32) -> i32>: Clone,
+{
+    let inputs = (
+        values.clone(),
+        values.clone(),
+        values,
+        any::<i32>(),
+        any::<i32>(),
+    );
+    run(&inputs, |(fa, fg, fh, a, f)| {
+        let fg = F::fmap(fg, function);
+        let fh = F::fmap(fh, function);
+        check("identity", laws::applicative_identity::<F, _>(fa.clone()))?;
+        check(
+            "homomorphism",
+            laws::applicative_homomorphism::<F, _, _, _>(a, function(f)),
+        )?;
+        check(
+            "interchange",
+            laws::applicative_interchange::<F, _, _, _>(a, fg.clone()),
+        )?;
+        check(
+            "composition",
+            laws::applicative_composition::<F, _, _, _, _, _>(fa, fg, fh),
+        )
+    });
+}
+
+/// Checks that `Semigroup::combine` is associative for the values from `values`.
+///
+/// ```rust
+/// # extern crate proptest;
+/// # extern crate rust_hkt;
+/// # use std::num::Wrapping;
+/// # use proptest::prelude::*;
+/// # use rust_hkt::testing::check_semigroup_laws;
+/// # fn main() {
+/// check_semigroup_laws(any::<Wrapping<i8>>());
+/// check_semigroup_laws(proptest::collection::vec(any::<u8>(), 0..4));
+/// # }
+/// ```
+pub fn check_semigroup_laws<A, S>(values: S)
+where
+    A: Semigroup + Clone + Debug + PartialEq,
+    S: Strategy<Value = A> + Clone,
+{
+    let inputs = (values.clone(), values.clone(), values);
+    run(&inputs, |(a, b, c)| {
+        check("associativity", laws::semigroup_associativity(a, b, c))
+    });
+}
+
+/// Checks the semigroup law and that `Monoid::empty` is an identity on both sides, for the values
+/// from `values`.
+///
+/// ```rust
+/// # extern crate proptest;
+/// # extern crate rust_hkt;
+/// # use std::cmp::Ordering;
+/// # use std::num::Wrapping;
+/// # use proptest::prelude::*;
+/// # use rust_hkt::kernel::{
+/// #     All, Any, Dual, First, Last, Max, Min, Product, SaturatingProduct, SaturatingSum, Sum,
+/// # };
+/// # use rust_hkt::testing::check_monoid_laws;
+/// # fn main() {
+/// check_monoid_laws(any::<String>());
+/// check_monoid_laws(any::<Option<Vec<bool>>>());
+/// check_monoid_laws(any::<(Wrapping<u16>, String)>());
+/// check_monoid_laws(any::<Ordering>());
+/// check_monoid_laws(any::<(Sum<i32>, Product<u64>)>());
+/// check_monoid_laws(any::<(SaturatingSum<u8>, SaturatingProduct<u16>)>());
+/// check_monoid_laws(any::<(Min<i16>, Max<char>, Max<bool>)>());
+/// check_monoid_laws(any::<(All, Any)>());
+/// check_monoid_laws(any::<(First<u8>, Last<String>)>());
+/// check_monoid_laws(any::<(Dual<String>, Dual<Vec<u8>>)>());
+/// # }
+/// ```
+pub fn check_monoid_laws<A, S>(values: S)
+where
+    A: Monoid + Clone + Debug + PartialEq,
+    S: Strategy<Value = A> + Clone,
+{
+    check_semigroup_laws(values.clone());
+    run(&values, |a| {
+        check("left identity", laws::monoid_left_identity(a.clone()))?;
+        check("right identity", laws::monoid_right_identity(a))
+    });
+}
+
+/// Checks the monoid laws and that `Group::inverse` cancels out on both sides, for the values from
+/// `values`.
+///
+/// ```rust
+/// # extern crate proptest;
+/// # extern crate rust_hkt;
+/// # use std::num::Wrapping;
+/// # use proptest::prelude::*;
+/// # use rust_hkt::kernel::Dual;
+/// # use rust_hkt::testing::check_group_laws;
+/// # fn main() {
+/// check_group_laws(any::<Wrapping<i32>>());
+/// check_group_laws(any::<(Wrapping<u8>, Dual<Wrapping<i64>>)>());
+/// # }
+/// ```
+pub fn check_group_laws<A, S>(values: S)
+where
+    A: Group + Clone + Debug + PartialEq,
+    S: Strategy<Value = A> + Clone,
+{
+    check_monoid_laws(values.clone());
+    run(&values, |a| {
+        check("left inverse", laws::group_left_inverse(a.clone()))?;
+        check("right inverse", laws::group_right_inverse(a))
+    });
+}
+
+/// Checks the semigroup law and that `combine` is idempotent, for the values from `values`.
+///
+/// ```rust
+/// # extern crate proptest;
+/// # extern crate rust_hkt;
+/// # use std::cmp::Ordering;
+/// # use proptest::prelude::*;
+/// # use rust_hkt::kernel::{Dual, First, Max};
+/// # use rust_hkt::testing::check_band_laws;
+/// # fn main() {
+/// check_band_laws(any::<Option<Max<i8>>>());
+/// check_band_laws(any::<Ordering>());
+/// check_band_laws(any::<First<i32>>());
+/// check_band_laws(any::<Dual<First<i32>>>());
+/// # }
+/// ```
+pub fn check_band_laws<A, S>(values: S)
+where
+    A: Band + Clone + Debug + PartialEq,
+    S: Strategy<Value = A> + Clone,
+{
+    check_semigroup_laws(values.clone());
+    run(&values, |a| check("idempotence", laws::band_idempotence(a)));
+}
+
+/// Checks the band laws and that `combine` is commutative, for the values from `values`.
+///
+/// ```rust
+/// # extern crate proptest;
+/// # extern crate rust_hkt;
+/// # use proptest::prelude::*;
+/// # use rust_hkt::kernel::{All, Any, Max, Min};
+/// # use rust_hkt::testing::check_semilattice_laws;
+/// # fn main() {
+/// check_semilattice_laws(any::<(Min<u8>, Max<String>)>());
+/// check_semilattice_laws(any::<(All, Any)>());
+/// check_semilattice_laws(proptest::collection::btree_set(any::<u8>(), 0..4));
+/// check_semilattice_laws(proptest::collection::hash_set(any::<u8>(), 0..4));
+/// # }
+/// ```
+pub fn check_semilattice_laws<A, S>(values: S)
+where
+    A: Semilattice + Clone + Debug + PartialEq,
+    S: Strategy<Value = A> + Clone,
+{
+    check_band_laws(values.clone());
+    run(&(values.clone(), values), |(a, b)| {
+        check("commutativity", laws::semigroup_commutativity(a, b))
+    });
+}
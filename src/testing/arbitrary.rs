@@ -0,0 +1,221 @@
+use proptest::prelude::*;
+
+use data::{Const, Either, Identity, NonEmptyVec, Validated, WriterT};
+use kernel::{
+    All, Any, Dual, First, Last, Max, Min, Product, SaturatingProduct, SaturatingSum, Sum,
+};
+use part3::HKT;
+
+impl<L, R> Arbitrary for Either<L, R>
+where
+    L: Arbitrary + 'static,
+    R: Arbitrary + 'static,
+{
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Either<L, R>>;
+
+    fn arbitrary_with(_: ()) -> BoxedStrategy<Either<L, R>> {
+        prop_oneof![
+            any::<L>().prop_map(Either::Left),
+            any::<R>().prop_map(Either::Right),
+        ]
+        .boxed()
+    }
+}
+
+impl<E, A> Arbitrary for Validated<E, A>
+where
+    E: Arbitrary + 'static,
+    A: Arbitrary + 'static,
+{
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Validated<E, A>>;
+
+    fn arbitrary_with(_: ()) -> BoxedStrategy<Validated<E, A>> {
+        prop_oneof![
+            any::<A>().prop_map(Validated::Valid),
+            any::<E>().prop_map(Validated::Invalid),
+        ]
+        .boxed()
+    }
+}
+
+impl<A> Arbitrary for NonEmptyVec<A>
+where
+    A: Arbitrary + 'static,
+{
+    type Parameters = ();
+    type Strategy = BoxedStrategy<NonEmptyVec<A>>;
+
+    fn arbitrary_with(_: ()) -> BoxedStrategy<NonEmptyVec<A>> {
+        (any::<A>(), any::<Vec<A>>())
+            .prop_map(|(head, tail)| NonEmptyVec::new(head, tail))
+            .boxed()
+    }
+}
+
+impl<A> Arbitrary for Identity<A>
+where
+    A: Arbitrary + 'static,
+{
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Identity<A>>;
+
+    fn arbitrary_with(_: ()) -> BoxedStrategy<Identity<A>> {
+        any::<A>().prop_map(Identity).boxed()
+    }
+}
+
+impl<C, A> Arbitrary for Const<C, A>
+where
+    C: Arbitrary + 'static,
+    A: 'static,
+{
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Const<C, A>>;
+
+    fn arbitrary_with(_: ()) -> BoxedStrategy<Const<C, A>> {
+        any::<C>().prop_map(Const::new).boxed()
+    }
+}
+
+impl<W, F, A> Arbitrary for WriterT<W, F, A>
+where
+    W: 'static,
+    F: HKT + 'static,
+    A: 'static,
+    F::Apply<(W, A)>: Arbitrary + 'static,
+{
+    type Parameters = ();
+    type Strategy = BoxedStrategy<WriterT<W, F, A>>;
+
+    fn arbitrary_with(_: ()) -> BoxedStrategy<WriterT<W, F, A>> {
+        any::<F::Apply<(W, A)>>().prop_map(WriterT::new).boxed()
+    }
+}
+
+impl<M> Arbitrary for Dual<M>
+where
+    M: Arbitrary + 'static,
+{
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Dual<M>>;
+
+    fn arbitrary_with(_: ()) -> BoxedStrategy<Dual<M>> {
+        any::<M>().prop_map(Dual).boxed()
+    }
+}
+
+impl<T> Arbitrary for Min<T>
+where
+    T: Arbitrary + 'static,
+{
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Min<T>>;
+
+    fn arbitrary_with(_: ()) -> BoxedStrategy<Min<T>> {
+        any::<T>().prop_map(Min).boxed()
+    }
+}
+
+impl<T> Arbitrary for Max<T>
+where
+    T: Arbitrary + 'static,
+{
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Max<T>>;
+
+    fn arbitrary_with(_: ()) -> BoxedStrategy<Max<T>> {
+        any::<T>().prop_map(Max).boxed()
+    }
+}
+
+impl<T> Arbitrary for Sum<T>
+where
+    T: Arbitrary + 'static,
+{
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Sum<T>>;
+
+    fn arbitrary_with(_: ()) -> BoxedStrategy<Sum<T>> {
+        any::<T>().prop_map(Sum).boxed()
+    }
+}
+
+impl<T> Arbitrary for Product<T>
+where
+    T: Arbitrary + 'static,
+{
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Product<T>>;
+
+    fn arbitrary_with(_: ()) -> BoxedStrategy<Product<T>> {
+        any::<T>().prop_map(Product).boxed()
+    }
+}
+
+impl Arbitrary for All {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<All>;
+
+    fn arbitrary_with(_: ()) -> BoxedStrategy<All> {
+        any::<bool>().prop_map(All).boxed()
+    }
+}
+
+impl Arbitrary for Any {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Any>;
+
+    fn arbitrary_with(_: ()) -> BoxedStrategy<Any> {
+        any::<bool>().prop_map(Any).boxed()
+    }
+}
+
+impl<A> Arbitrary for First<A>
+where
+    A: Arbitrary + 'static,
+{
+    type Parameters = ();
+    type Strategy = BoxedStrategy<First<A>>;
+
+    fn arbitrary_with(_: ()) -> BoxedStrategy<First<A>> {
+        any::<Option<A>>().prop_map(First).boxed()
+    }
+}
+
+impl<A> Arbitrary for Last<A>
+where
+    A: Arbitrary + 'static,
+{
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Last<A>>;
+
+    fn arbitrary_with(_: ()) -> BoxedStrategy<Last<A>> {
+        any::<Option<A>>().prop_map(Last).boxed()
+    }
+}
+
+impl<T> Arbitrary for SaturatingSum<T>
+where
+    T: Arbitrary + 'static,
+{
+    type Parameters = ();
+    type Strategy = BoxedStrategy<SaturatingSum<T>>;
+
+    fn arbitrary_with(_: ()) -> BoxedStrategy<SaturatingSum<T>> {
+        any::<T>().prop_map(SaturatingSum).boxed()
+    }
+}
+
+impl<T> Arbitrary for SaturatingProduct<T>
+where
+    T: Arbitrary + 'static,
+{
+    type Parameters = ();
+    type Strategy = BoxedStrategy<SaturatingProduct<T>>;
+
+    fn arbitrary_with(_: ()) -> BoxedStrategy<SaturatingProduct<T>> {
+        any::<T>().prop_map(SaturatingProduct).boxed()
+    }
+}